@@ -16,7 +16,7 @@ use std::fs::OpenOptions;
 use std::io::{Read, Write};
 use url::Url;
 
-pub const CACHE_VERSION: usize = 3;
+pub const CACHE_VERSION: usize = 4;
 
 #[derive(Serialize, Deserialize, Debug)]
 struct ForkCacheContent {
@@ -85,7 +85,8 @@ impl ToString for ForkCacheContent {
 #[derive(Debug)]
 pub struct ForkCache {
     fork_cache_content: ForkCacheContent,
-    cache_file: Utf8PathBuf,
+    /// `None` if the cache is kept only in memory
+    cache_file: Option<Utf8PathBuf>,
 }
 
 impl Drop for ForkCache {
@@ -122,22 +123,35 @@ impl ForkCache {
 
         Ok(ForkCache {
             fork_cache_content,
-            cache_file,
+            cache_file: Some(cache_file),
         })
     }
 
+    /// Creates a cache which is neither loaded from nor saved to a file.
+    /// Values are kept only for the lifetime of the state reader, so repeated reads
+    /// during a single test return consistent results.
+    pub(crate) fn in_memory() -> Self {
+        ForkCache {
+            fork_cache_content: ForkCacheContent::default(),
+            cache_file: None,
+        }
+    }
+
     fn save(&self) {
+        let Some(cache_file) = &self.cache_file else {
+            return;
+        };
         let mut file = OpenOptions::new()
             .write(true)
             .create(true)
             .truncate(false)
-            .open(&self.cache_file)
+            .open(cache_file)
             .unwrap();
 
         file.lock_exclusive().expect("Could not lock on cache file");
 
         let cache_file_content =
-            fs::read_to_string(&self.cache_file).expect("Should have been able to read the cache");
+            fs::read_to_string(cache_file).expect("Should have been able to read the cache");
 
         let output = if cache_file_content.is_empty() {
             self.fork_cache_content.to_string()
@@ -1,7 +1,7 @@
 use crate::forking::cache::ForkCache;
 use crate::state::BlockInfoReader;
 use anyhow::{Context, Result};
-use blockifier::blockifier::block::BlockInfo;
+use blockifier::blockifier::block::{BlockInfo, GasPrices};
 use blockifier::execution::contract_class::{
     ContractClass as ContractClassBlockifier, ContractClassV0, ContractClassV1,
 };
@@ -15,8 +15,8 @@ use flate2::read::GzDecoder;
 use num_bigint::BigUint;
 use runtime::starknet::context::SerializableGasPrices;
 use starknet::core::types::{
-    BlockId, ContractClass as ContractClassStarknet, FieldElement, MaybePendingBlockWithTxHashes,
-    StarknetError,
+    BlockId, BlockTag, BlockWithTxHashes, ContractClass as ContractClassStarknet, FieldElement,
+    MaybePendingBlockWithTxHashes, StarknetError,
 };
 use starknet::providers::jsonrpc::HttpTransport;
 use starknet::providers::{JsonRpcClient, Provider, ProviderError};
@@ -30,6 +30,7 @@ use starknet_api::state::StorageKey;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::io::Read;
+use std::num::NonZeroU128;
 use tokio::runtime::Runtime;
 use universal_sierra_compiler_api::{compile_sierra, SierraType};
 use url::Url;
@@ -38,6 +39,7 @@ use url::Url;
 pub struct ForkStateReader {
     client: JsonRpcClient<HttpTransport>,
     block_number: BlockNumber,
+    live: bool,
     runtime: Runtime,
     cache: RefCell<ForkCache>,
 }
@@ -51,12 +53,31 @@ impl ForkStateReader {
             ),
             client: JsonRpcClient::new(HttpTransport::new(url)),
             block_number,
+            live: false,
             runtime: Runtime::new().expect("Could not instantiate Runtime"),
         })
     }
 
+    /// Creates a reader of the latest state of the chain.
+    /// Nothing is read from or written to the fork cache directory,
+    /// so data of a live fork is never shared with a fork pinned to the same block number.
+    #[must_use]
+    pub fn new_live(url: Url, block_number: BlockNumber) -> Self {
+        ForkStateReader {
+            cache: RefCell::new(ForkCache::in_memory()),
+            client: JsonRpcClient::new(HttpTransport::new(url)),
+            block_number,
+            live: true,
+            runtime: Runtime::new().expect("Could not instantiate Runtime"),
+        }
+    }
+
     fn block_id(&self) -> BlockId {
-        BlockId::Number(self.block_number.0)
+        if self.live {
+            BlockId::Tag(BlockTag::Latest)
+        } else {
+            BlockId::Number(self.block_number.0)
+        }
     }
 }
 
@@ -73,6 +94,36 @@ fn other_provider_error<T>(boxed: impl ToString) -> Result<T, StateError> {
     ))
 }
 
+/// Reads gas prices of the forked block, using default values for prices the node reports as zero
+fn gas_prices_from_block(block: &BlockWithTxHashes) -> GasPrices {
+    let default_gas_prices: GasPrices = SerializableGasPrices::default().into();
+    let to_gas_price = |price: FieldElement, default: NonZeroU128| {
+        u128::try_from(price)
+            .ok()
+            .and_then(NonZeroU128::new)
+            .unwrap_or(default)
+    };
+
+    GasPrices {
+        eth_l1_gas_price: to_gas_price(
+            block.l1_gas_price.price_in_wei,
+            default_gas_prices.eth_l1_gas_price,
+        ),
+        strk_l1_gas_price: to_gas_price(
+            block.l1_gas_price.price_in_fri,
+            default_gas_prices.strk_l1_gas_price,
+        ),
+        eth_l1_data_gas_price: to_gas_price(
+            block.l1_data_gas_price.price_in_wei,
+            default_gas_prices.eth_l1_data_gas_price,
+        ),
+        strk_l1_data_gas_price: to_gas_price(
+            block.l1_data_gas_price.price_in_fri,
+            default_gas_prices.strk_l1_data_gas_price,
+        ),
+    }
+}
+
 impl BlockInfoReader for ForkStateReader {
     fn get_block_info(&mut self) -> StateResult<BlockInfo> {
         if let Some(cache_hit) = self.cache.borrow().get_block_info() {
@@ -88,7 +139,7 @@ impl BlockInfoReader for ForkStateReader {
                     block_number: BlockNumber(block.block_number),
                     sequencer_address: block.sequencer_address.into_(),
                     block_timestamp: BlockTimestamp(block.timestamp),
-                    gas_prices: SerializableGasPrices::default().into(),
+                    gas_prices: gas_prices_from_block(&block),
                     use_kzg_da: true,
                 };
 
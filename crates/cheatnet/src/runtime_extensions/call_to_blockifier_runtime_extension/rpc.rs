@@ -30,6 +30,9 @@ pub struct UsedResources {
     pub l2_to_l1_payload_lengths: Vec<usize>,
     pub l1_handler_payload_lengths: Vec<usize>,
     pub events: Vec<EventContent>,
+    /// Resources used by constructors of contracts deployed in the test,
+    /// already included in `execution_resources`
+    pub deployment_resources: ExecutionResources,
 }
 
 /// Enum representing possible call execution result, along with the data
@@ -23,6 +23,7 @@ use cairo_vm::{
     },
 };
 use runtime::{ExtendedRuntime, ExtensionLogic, StarknetRuntime, SyscallHandlingResult};
+use shared::utils::format_debug_print;
 use starknet_api::hash::StarkFelt;
 
 pub type SyscallSelector = DeprecatedSyscallSelector;
@@ -36,6 +37,20 @@ pub type CheatableStarknetRuntime<'a> = ExtendedRuntime<CheatableStarknetRuntime
 impl<'a> ExtensionLogic for CheatableStarknetRuntimeExtension<'a> {
     type Runtime = StarknetRuntime<'a>;
 
+    fn handle_debug_print(
+        &mut self,
+        data: &[Felt252],
+        _extended_runtime: &mut Self::Runtime,
+    ) -> bool {
+        match &mut self.cheatnet_state.captured_output {
+            Some(captured_output) => {
+                captured_output.push_str(&format_debug_print(data));
+                true
+            }
+            None => false,
+        }
+    }
+
     fn override_system_call(
         &mut self,
         selector: DeprecatedSyscallSelector,
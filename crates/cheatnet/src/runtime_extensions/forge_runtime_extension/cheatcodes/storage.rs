@@ -1,10 +1,11 @@
+use anyhow::ensure;
 use blockifier::state::state_api::State;
 use cairo_felt::Felt252;
 use conversions::{FromConv, IntoConv};
 use num_traits::Pow;
 use starknet::core::crypto::pedersen_hash;
 use starknet::core::types::FieldElement;
-use starknet_api::core::{ContractAddress, PatriciaKey};
+use starknet_api::core::{ClassHash, ContractAddress, PatriciaKey};
 use starknet_api::hash::StarkHash;
 use starknet_api::state::StorageKey;
 
@@ -47,6 +48,20 @@ pub fn load(
         .into_())
 }
 
+/// Storage of an address without a contract can't be read by any contract, so writing or reading it is a mistake.
+/// Forked contracts are deployed as well, their class hashes are read from the fork
+pub fn ensure_deployed(
+    state: &mut dyn State,
+    target: ContractAddress,
+) -> Result<(), anyhow::Error> {
+    ensure!(
+        state.get_class_hash_at(target)? != ClassHash::default(),
+        "Contract not deployed at address: {}",
+        target.0.key()
+    );
+    Ok(())
+}
+
 /// The address after hashing with pedersen, needs to be taken with a specific modulo value (2^251 - 256)
 /// For details see:
 /// <https://docs.starknet.io/documentation/architecture_and_concepts/Smart_Contracts/contract-storage>
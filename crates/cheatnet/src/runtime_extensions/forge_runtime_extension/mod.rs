@@ -12,11 +12,11 @@ use crate::runtime_extensions::{
         deploy::{deploy, deploy_at},
         get_class_hash::get_class_hash,
         l1_handler_execute::l1_handler_execute,
-        storage::{calculate_variable_address, load, store},
+        storage::{calculate_variable_address, ensure_deployed, load, store},
         CheatcodeError,
     },
 };
-use crate::state::CallTraceNode;
+use crate::state::{CallTrace, CallTraceNode};
 use anyhow::{anyhow, Context, Result};
 use blockifier::state::errors::StateError;
 use blockifier::{
@@ -44,7 +44,10 @@ use runtime::{
 };
 use starknet::core::types::FieldElement;
 use starknet::signers::SigningKey;
-use starknet_api::{core::ClassHash, deprecated_contract_class::EntryPointType::L1Handler};
+use starknet_api::{
+    core::ClassHash,
+    deprecated_contract_class::EntryPointType::{self, L1Handler},
+};
 use std::collections::HashMap;
 
 pub mod cheatcodes;
@@ -427,7 +430,9 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
                     .state;
                 let target = input_reader.read()?;
                 let storage_address = input_reader.read()?;
-                store(*state, target, &storage_address, input_reader.read()?)
+                let value = input_reader.read()?;
+                ensure_deployed(*state, target)
+                    .and_then(|()| store(*state, target, &storage_address, value))
                     .context("Failed to store")?;
 
                 Ok(CheatcodeHandlingResult::from_serializable(()))
@@ -440,10 +445,29 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
                     .state;
                 let target = input_reader.read()?;
                 let storage_address = &input_reader.read()?;
-                let loaded = load(*state, target, storage_address).context("Failed to load")?;
+                let loaded = ensure_deployed(*state, target)
+                    .and_then(|()| load(*state, target, storage_address))
+                    .context("Failed to load")?;
 
                 Ok(CheatcodeHandlingResult::from_serializable(loaded))
             }
+            "snapshot_storage" => {
+                let target = input_reader.read()?;
+                let storage_addresses: Vec<Felt252> = input_reader.read()?;
+
+                let slots = &mut extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .storage_snapshot_slots;
+                for storage_address in storage_addresses {
+                    if !slots.contains(&(target, storage_address.clone())) {
+                        slots.push((target, storage_address));
+                    }
+                }
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
             "map_entry_address" => {
                 let map_selector = &input_reader.read()?;
                 let keys: Vec<_> = input_reader.read()?;
@@ -652,6 +676,7 @@ pub fn get_all_used_resources(
         .top();
 
     let execution_resources = top_call.borrow().used_execution_resources.clone();
+    let deployment_resources = get_constructors_resources(&top_call.borrow());
     let top_call_syscalls = top_call.borrow().used_syscalls.clone();
     let events = runtime_call_info
         .iter() // This method iterates over inner calls as well
@@ -677,5 +702,25 @@ pub fn get_all_used_resources(
         execution_resources,
         l1_handler_payload_lengths,
         l2_to_l1_payload_lengths,
+        deployment_resources,
+    }
+}
+
+/// Sums resources of the outermost constructor calls, constructors of contracts deployed
+/// by other constructors are already included in them
+fn get_constructors_resources(call_trace: &CallTrace) -> ExecutionResources {
+    let mut resources = ExecutionResources::default();
+    for nested_call in call_trace
+        .nested_calls
+        .iter()
+        .filter_map(CallTraceNode::extract_entry_point_call)
+    {
+        let nested_call = nested_call.borrow();
+        if nested_call.entry_point.entry_point_type == EntryPointType::Constructor {
+            resources += &nested_call.used_execution_resources;
+        } else {
+            resources += &get_constructors_resources(&nested_call);
+        }
     }
+    resources
 }
@@ -322,6 +322,10 @@ pub struct CheatnetState {
     pub deploy_salt_base: u32,
     pub block_info: BlockInfo,
     pub trace_data: TraceData,
+    /// Output printed during the execution, `None` if the output is not captured
+    pub captured_output: Option<String>,
+    /// Storage slots registered with `snapshot_storage`, compared with the snapshot after the test
+    pub storage_snapshot_slots: Vec<(ContractAddress, Felt252)>,
 }
 
 impl Default for CheatnetState {
@@ -345,6 +349,8 @@ impl Default for CheatnetState {
                 current_call_stack: NotEmptyCallStack::from(test_call),
                 is_vm_trace_needed: false,
             },
+            captured_output: None,
+            storage_snapshot_slots: vec![],
         }
     }
 }
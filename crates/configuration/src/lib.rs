@@ -57,12 +57,7 @@ pub fn load_global_config<T: GlobalConfig + Default>(
     path: &Option<Utf8PathBuf>,
     profile: &Option<String>,
 ) -> Result<T> {
-    let config_path = path
-        .as_ref()
-        .and_then(|p| search_config_upwards_relative_to(p).ok())
-        .or_else(|| find_config_file().ok());
-
-    match config_path {
+    match find_global_config_file(path) {
         Some(path) => {
             let raw_config_toml = fs::read_to_string(path)
                 .context("Failed to read snfoundry.toml config file")?
@@ -132,14 +127,28 @@ fn resolve_env_variable(var: &str) -> Result<serde_json::Value> {
     Ok(serde_json::Value::String(value))
 }
 
+/// snfoundry.toml used by [`load_global_config`]: the first one found in `path` or its parents,
+/// then in the current directory or its parents
+#[must_use]
+pub fn find_global_config_file(path: &Option<Utf8PathBuf>) -> Option<Utf8PathBuf> {
+    path.as_ref()
+        .and_then(|p| search_config_upwards_relative_to(p).ok())
+        .or_else(|| find_config_file().ok())
+}
+
 pub fn search_config_upwards_relative_to(current_dir: &Utf8PathBuf) -> Result<Utf8PathBuf> {
     current_dir
         .ancestors()
         .find(|path| fs::metadata(path.join(CONFIG_FILENAME)).is_ok())
         .map(|path| path.join(CONFIG_FILENAME))
         .ok_or_else(|| {
+            let searched_dirs: Vec<String> = current_dir
+                .ancestors()
+                .map(|path| format!("    {path}"))
+                .collect();
             anyhow!(
-                "Failed to find snfoundry.toml - not found in current nor any parent directories"
+                "Failed to find snfoundry.toml - not found in current nor any parent directories. Searched directories:\n{}",
+                searched_dirs.join("\n")
             )
         })
 }
@@ -236,6 +245,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn no_config_error_lists_searched_dirs() {
+        let tempdir = tempdir().expect("Failed to create a temporary directory");
+        let root = Utf8PathBuf::try_from(tempdir.path().to_path_buf()).unwrap();
+
+        let error = search_config_upwards_relative_to(&root.join("childdir"))
+            .unwrap_err()
+            .to_string();
+
+        assert!(error.starts_with(&format!(
+            "Failed to find snfoundry.toml - not found in current nor any parent directories. Searched directories:\n    {root}/childdir\n    {root}\n"
+        )));
+    }
+
     #[derive(Debug, Default, Serialize, Deserialize)]
     pub struct StubConfig {
         #[serde(default)]
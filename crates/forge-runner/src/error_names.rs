@@ -0,0 +1,108 @@
+use anyhow::{anyhow, Context, Result};
+use cairo_felt::Felt252;
+use camino::Utf8Path;
+use std::collections::HashMap;
+
+/// Names of custom errors, displayed next to the felts encoding them in the panic data of failed tests
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct ErrorNames(HashMap<Felt252, String>);
+
+impl ErrorNames {
+    /// Loads a JSON object mapping felts encoding errors, as hex or decimal numbers, to their names
+    pub fn load(path: &Utf8Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read error names from file = {path}"))?;
+        Self::from_json(&content)
+            .with_context(|| format!("Failed to parse error names from file = {path}"))
+    }
+
+    fn from_json(content: &str) -> Result<Self> {
+        let raw_names: HashMap<String, String> = serde_json::from_str(content)?;
+
+        raw_names
+            .into_iter()
+            .map(|(felt, name)| Ok((parse_felt(&felt)?, name)))
+            .collect::<Result<_>>()
+            .map(Self)
+    }
+
+    #[must_use]
+    pub fn name(&self, felt: &Felt252) -> Option<&str> {
+        self.0.get(felt).map(String::as_str)
+    }
+
+    /// Formats panic data with the names of errors following the felts encoding them,
+    /// e.g. `(0x1a2b (InsufficientBalance), 0x5)`. Returns `None` if the data contains no known error
+    #[must_use]
+    pub fn format_panic_data(&self, panic_data: &[Felt252]) -> Option<String> {
+        if !panic_data.iter().any(|felt| self.0.contains_key(felt)) {
+            return None;
+        }
+
+        let items: Vec<String> = panic_data
+            .iter()
+            .map(|felt| {
+                let hex = format!("0x{}", felt.to_str_radix(16));
+                match self.name(felt) {
+                    Some(name) => format!("{hex} ({name})"),
+                    None => hex,
+                }
+            })
+            .collect();
+
+        let data = match &items[..] {
+            [item] => item.clone(),
+            _ => format!("({})", items.join(", ")),
+        };
+        Some(format!("\n    {data}\n"))
+    }
+}
+
+fn parse_felt(value: &str) -> Result<Felt252> {
+    let felt = match value.strip_prefix("0x") {
+        Some(hex) => Felt252::parse_bytes(hex.as_bytes(), 16),
+        None => Felt252::parse_bytes(value.as_bytes(), 10),
+    };
+    felt.ok_or_else(|| anyhow!("{value} is not a valid felt"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NAMES: &str = r#"{ "0x1a2b": "InsufficientBalance", "10": "Unauthorized" }"#;
+
+    #[test]
+    fn parses_hex_and_decimal_felts() {
+        let error_names = ErrorNames::from_json(NAMES).unwrap();
+
+        assert_eq!(
+            error_names.name(&Felt252::from(0x1a2b)),
+            Some("InsufficientBalance")
+        );
+        assert_eq!(error_names.name(&Felt252::from(10)), Some("Unauthorized"));
+        assert_eq!(error_names.name(&Felt252::from(11)), None);
+    }
+
+    #[test]
+    fn rejects_invalid_felts() {
+        let error = ErrorNames::from_json(r#"{ "0xzz": "Invalid" }"#).unwrap_err();
+
+        assert_eq!(error.to_string(), "0xzz is not a valid felt");
+    }
+
+    #[test]
+    fn formats_panic_data_with_known_errors() {
+        let error_names = ErrorNames::from_json(NAMES).unwrap();
+
+        assert_eq!(
+            error_names.format_panic_data(&[Felt252::from(0x1a2b), Felt252::from(5)]),
+            Some("\n    (0x1a2b (InsufficientBalance), 0x5)\n".to_string())
+        );
+        assert_eq!(
+            error_names.format_panic_data(&[Felt252::from(10)]),
+            Some("\n    0xa (Unauthorized)\n".to_string())
+        );
+        assert_eq!(error_names.format_panic_data(&[Felt252::from(5)]), None);
+    }
+}
@@ -1,10 +1,10 @@
 // Our custom structs used to prevent name changes in structs on side of cairo compiler from breaking the test collector backwards compatibility
 use cairo_felt::Felt252;
 use cairo_lang_test_plugin::test_config::{PanicExpectation, TestExpectation};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Expectation for a panic case.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ExpectedPanicValue {
     /// Accept any panic value.
     Any,
@@ -22,7 +22,7 @@ impl From<PanicExpectation> for ExpectedPanicValue {
 }
 
 /// Expectation for a result of a test.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ExpectedTestResult {
     /// Running the test should not panic.
     Success,
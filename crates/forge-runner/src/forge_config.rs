@@ -1,9 +1,27 @@
+use crate::error_names::ErrorNames;
+use crate::vm_trace::VmTraceOutput;
+use anyhow::{anyhow, bail, Result};
+use blockifier::blockifier::block::BlockInfo;
+use blockifier::state::cached_state::GLOBAL_CONTRACT_CACHE_SIZE_FOR_TEST;
 use camino::Utf8PathBuf;
 use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
+use shared::print::print_as_warning;
+use starknet_api::block::{BlockNumber, BlockTimestamp};
+use starknet_api::core::ContractAddress;
 use std::collections::HashMap;
-use std::num::NonZeroU32;
+use std::num::NonZeroU128;
+use std::num::NonZeroUsize;
 use std::sync::Arc;
 
+/// Number of compiled contract classes kept in the cache of a single test, unless configured otherwise.
+/// A larger cache avoids recompiling classes in tests using many of them, e.g. when forking,
+/// at the cost of memory used by each concurrently run test.
+pub const DEFAULT_CONTRACT_CACHE_SIZE: NonZeroUsize =
+    match NonZeroUsize::new(GLOBAL_CONTRACT_CACHE_SIZE_FOR_TEST) {
+        Some(size) => size,
+        None => panic!("Default contract cache size must be non-zero"),
+    };
+
 #[derive(Debug, PartialEq)]
 pub struct ForgeConfig {
     pub test_runner_config: Arc<TestRunnerConfig>,
@@ -13,20 +31,173 @@ pub struct ForgeConfig {
 #[derive(Debug, PartialEq)]
 pub struct TestRunnerConfig {
     pub exit_first: bool,
-    pub fuzzer_runs: NonZeroU32,
+    pub fuzzer_runs: u32,
     pub fuzzer_seed: u64,
+    /// Number of consecutive runs of a fuzz test rejected with `assume` after which the test fails
+    pub fuzzer_max_rejects: u32,
+    /// Maximum length of arrays and byte arrays generated by the fuzzer
+    pub fuzzer_max_length: u32,
+    /// Largest number of combinations of arguments of a fuzz test for which all of them are run
+    /// instead of random ones, the number of fuzzer runs of the test if not set
+    pub fuzzer_exhaustive_threshold: Option<u32>,
     pub max_n_steps: Option<u32>,
+    /// Number of compiled contract classes kept in the cache of a single test
+    pub contract_cache_size: NonZeroUsize,
+    /// Maximum number of test targets of a package run at the same time
+    pub test_target_concurrency: NonZeroUsize,
+    /// Maximum number of test cases of a test target run at the same time, unbounded if not set.
+    /// Test cases are started in the order of declaration
+    pub max_concurrency: Option<NonZeroUsize>,
     pub is_vm_trace_needed: bool,
     pub cache_dir: Utf8PathBuf,
     pub contracts_data: ContractsData,
     pub environment_variables: HashMap<String, String>,
+    pub block_info_overrides: BlockInfoOverrides,
+    /// Directory with storage snapshots of tests using `snapshot_storage`
+    pub snapshots_dir: Utf8PathBuf,
+    /// Overwrite storage snapshots instead of comparing them
+    pub update_snapshots: bool,
+    pub vm_trace_output: Option<VmTraceOutput>,
+    /// What happens with warnings reported while compiling test targets to CASM
+    pub compile_warnings: CompileWarnings,
+    /// Names of custom errors displayed in the panic data of failed tests
+    pub error_names: ErrorNames,
+}
+
+/// Handling of non-fatal diagnostics reported while compiling tests from Sierra to CASM
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum CompileWarnings {
+    /// Warnings are not displayed
+    Ignore,
+    /// Warnings are displayed and tests are run
+    #[default]
+    Warn,
+    /// Compilation with warnings fails
+    Error,
+}
+
+impl CompileWarnings {
+    /// Displays the warnings or turns them into an error, depending on the policy
+    pub fn check(self, warnings: &[String]) -> Result<()> {
+        if warnings.is_empty() {
+            return Ok(());
+        }
+        match self {
+            CompileWarnings::Ignore => {}
+            CompileWarnings::Warn => {
+                for warning in warnings {
+                    print_as_warning(&anyhow!("{warning}"));
+                }
+            }
+            CompileWarnings::Error => bail!(
+                "Compilation to CASM reported warnings, which are treated as errors:\n{}",
+                warnings.join("\n")
+            ),
+        }
+        Ok(())
+    }
+}
+
+/// Values replacing the block info of the test environment.
+/// Without them, tests use the forked block info or the defaults when not forking.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct BlockInfoOverrides {
+    pub block_number: Option<u64>,
+    pub block_timestamp: Option<u64>,
+    pub sequencer_address: Option<ContractAddress>,
+    pub eth_l1_gas_price: Option<NonZeroU128>,
+    pub strk_l1_gas_price: Option<NonZeroU128>,
+    pub eth_l1_data_gas_price: Option<NonZeroU128>,
+    pub strk_l1_data_gas_price: Option<NonZeroU128>,
+}
+
+impl BlockInfoOverrides {
+    pub fn apply(&self, block_info: &mut BlockInfo) {
+        if let Some(block_number) = self.block_number {
+            block_info.block_number = BlockNumber(block_number);
+        }
+        if let Some(block_timestamp) = self.block_timestamp {
+            block_info.block_timestamp = BlockTimestamp(block_timestamp);
+        }
+        if let Some(sequencer_address) = self.sequencer_address {
+            block_info.sequencer_address = sequencer_address;
+        }
+
+        let gas_prices = &mut block_info.gas_prices;
+        if let Some(price) = self.eth_l1_gas_price {
+            gas_prices.eth_l1_gas_price = price;
+        }
+        if let Some(price) = self.strk_l1_gas_price {
+            gas_prices.strk_l1_gas_price = price;
+        }
+        if let Some(price) = self.eth_l1_data_gas_price {
+            gas_prices.eth_l1_data_gas_price = price;
+        }
+        if let Some(price) = self.strk_l1_data_gas_price {
+            gas_prices.strk_l1_data_gas_price = price;
+        }
+    }
 }
 
 #[derive(Debug, PartialEq)]
 pub struct OutputConfig {
     pub detailed_resources: bool,
+    /// Resources reported as the cost of passed tests
+    pub gas_unit: GasUnit,
+    /// Don't print output captured during the run of passed tests
+    pub hide_passed_output: bool,
+    /// Print statistics of arguments generated by the fuzzer for passed fuzz tests
+    pub fuzzer_stats: bool,
+    /// Print only failed test cases, without passed and ignored ones
+    pub quiet: bool,
+    pub result_format: ResultFormat,
     pub execution_data_to_save: ExecutionDataToSave,
     pub versioned_programs_dir: Utf8PathBuf,
+    pub summary_mode: TestSummaryMode,
+    /// Path to the file where results of each test case are written as JSON lines
+    pub results_jsonl_path: Option<Utf8PathBuf>,
+}
+
+/// How results of test cases are displayed while tests are running
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum ResultFormat {
+    /// A line with the result of each test case
+    #[default]
+    Detailed,
+    /// A single character for each test case, followed by details of failed test cases
+    Compact,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum GasUnit {
+    /// L1 gas estimated from resources used by the test
+    #[default]
+    L1Gas,
+    /// Number of Cairo VM steps executed by the test
+    Steps,
+    /// Both L1 gas and steps
+    All,
+}
+
+impl GasUnit {
+    #[must_use]
+    pub fn includes_l1_gas(self) -> bool {
+        matches!(self, GasUnit::L1Gas | GasUnit::All)
+    }
+
+    #[must_use]
+    pub fn includes_steps(self) -> bool {
+        matches!(self, GasUnit::Steps | GasUnit::All)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum TestSummaryMode {
+    /// Summaries of all test cases are kept until the end of the run
+    #[default]
+    Full,
+    /// Summaries are folded into counters, only failed test cases are kept
+    Streaming,
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -62,10 +233,13 @@ pub fn is_vm_trace_needed(execution_data_to_save: ExecutionDataToSave) -> bool {
 /// [`TestRunnerConfig`] to another function.
 pub struct RuntimeConfig<'a> {
     pub max_n_steps: Option<u32>,
+    pub contract_cache_size: NonZeroUsize,
     pub is_vm_trace_needed: bool,
+    pub save_vm_trace: bool,
     pub cache_dir: &'a Utf8PathBuf,
     pub contracts_data: &'a ContractsData,
     pub environment_variables: &'a HashMap<String, String>,
+    pub block_info_overrides: &'a BlockInfoOverrides,
 }
 
 impl<'a> RuntimeConfig<'a> {
@@ -73,10 +247,34 @@ impl<'a> RuntimeConfig<'a> {
     pub fn from(value: &'a TestRunnerConfig) -> RuntimeConfig<'a> {
         Self {
             max_n_steps: value.max_n_steps,
+            contract_cache_size: value.contract_cache_size,
             is_vm_trace_needed: value.is_vm_trace_needed,
+            save_vm_trace: value.vm_trace_output.is_some(),
             cache_dir: &value.cache_dir,
             contracts_data: &value.contracts_data,
             environment_variables: &value.environment_variables,
+            block_info_overrides: &value.block_info_overrides,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compile_warnings_fail_only_with_error_policy() {
+        let warnings = vec!["first warning".to_string(), "second warning".to_string()];
+
+        assert!(CompileWarnings::Ignore.check(&warnings).is_ok());
+        assert!(CompileWarnings::Warn.check(&warnings).is_ok());
+        assert!(CompileWarnings::Error.check(&[]).is_ok());
+        assert_eq!(
+            CompileWarnings::Error
+                .check(&warnings)
+                .unwrap_err()
+                .to_string(),
+            "Compilation to CASM reported warnings, which are treated as errors:\nfirst warning\nsecond warning"
+        );
+    }
+}
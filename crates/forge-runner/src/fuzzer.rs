@@ -1,14 +1,111 @@
-use crate::fuzzer::arguments::CairoType;
-use anyhow::{Ok, Result};
 use rand::rngs::StdRng;
 use rand::Rng;
 
 mod arguments;
+mod exhaustive;
 mod random;
 
+pub use arguments::CairoType;
+use cairo_felt::Felt252;
+pub use exhaustive::ExhaustiveFuzzer;
 pub use random::RandomFuzzer;
 use std::num::NonZeroU32;
 
+/// Panic data with which `assume` from `snforge_std` rejects arguments of a fuzz test run
+const ASSUME_REJECTED: &str = "snforge assume rejected";
+
+/// Whether the run panicked with the data of a rejection made by `assume`
+#[must_use]
+pub fn is_rejected_by_assume(panic_data: &[Felt252]) -> bool {
+    panic_data == [Felt252::from_bytes_be(ASSUME_REJECTED.as_bytes())]
+}
+
+/// Arguments with the minimal value of each type, used when a fuzz test is run without fuzzing
+#[must_use]
+pub fn min_args(arguments: &[CairoType]) -> Vec<Felt252> {
+    arguments.iter().copied().flat_map(CairoType::min).collect()
+}
+
+/// Generates arguments of fuzz test runs: all combinations of them if there are at most
+/// `exhaustive_threshold` of them, otherwise random ones for `total_runs` runs
+#[derive(Debug, Clone)]
+pub enum Fuzzer {
+    Random(Box<RandomFuzzer>),
+    Exhaustive(ExhaustiveFuzzer),
+}
+
+impl Fuzzer {
+    #[must_use]
+    pub fn create(
+        seed: u64,
+        total_runs: NonZeroU32,
+        arguments: &[CairoType],
+        exhaustive_threshold: u32,
+    ) -> Self {
+        match ExhaustiveFuzzer::create(arguments, exhaustive_threshold) {
+            Some(fuzzer) => Fuzzer::Exhaustive(fuzzer),
+            None => Fuzzer::Random(Box::new(RandomFuzzer::create(seed, total_runs, arguments))),
+        }
+    }
+
+    #[must_use]
+    pub fn total_runs(&self) -> NonZeroU32 {
+        match self {
+            Fuzzer::Random(fuzzer) => fuzzer.total_runs(),
+            Fuzzer::Exhaustive(fuzzer) => fuzzer.total_runs(),
+        }
+    }
+
+    pub fn next_args(&mut self) -> Vec<Felt252> {
+        match self {
+            Fuzzer::Random(fuzzer) => fuzzer.next_args(),
+            Fuzzer::Exhaustive(fuzzer) => fuzzer.next_args(),
+        }
+    }
+
+    /// Random arguments replacing the ones rejected with `assume`.
+    /// Exhaustive fuzzing runs every combination once, so its rejected runs are not replaced
+    pub fn regenerate_args(&mut self) -> Vec<Felt252> {
+        match self {
+            Fuzzer::Random(fuzzer) => fuzzer.regenerate_args(),
+            Fuzzer::Exhaustive(_) => unreachable!("Runs of exhaustive fuzzing are not replaced"),
+        }
+    }
+
+    #[must_use]
+    pub fn is_boundary_run(&self) -> bool {
+        match self {
+            Fuzzer::Random(fuzzer) => fuzzer.is_boundary_run(),
+            Fuzzer::Exhaustive(fuzzer) => fuzzer.is_boundary_run(),
+        }
+    }
+}
+
+/// Derives the seed of a single fuzz test from the global seed and the test name,
+/// so that a test gets the same arguments on every run with the same global seed,
+/// while different tests get different arguments.
+///
+/// Uses FNV-1a followed by the `SplitMix64` finalizer instead of `DefaultHasher`,
+/// whose output is not guaranteed to be stable between Rust releases.
+#[must_use]
+pub fn derive_test_seed(global_seed: u64, test_name: &str) -> u64 {
+    const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+    let hash = global_seed
+        .to_le_bytes()
+        .iter()
+        .chain(test_name.as_bytes())
+        .fold(FNV_OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(*byte)).wrapping_mul(FNV_PRIME)
+        });
+
+    let mut seed = hash;
+    seed = (seed ^ (seed >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    seed = (seed ^ (seed >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    seed ^ (seed >> 31)
+}
+
 #[derive(Debug, Clone)]
 pub struct FuzzerArg {
     cairo_type: CairoType,
@@ -27,11 +124,11 @@ pub struct RunParams {
 }
 
 impl RunParams {
-    pub fn from(rng: &mut StdRng, total_runs: NonZeroU32, arguments: &[&str]) -> Result<Self> {
+    pub fn from(rng: &mut StdRng, total_runs: NonZeroU32, arguments: &[CairoType]) -> Self {
         let arguments = arguments
             .iter()
-            .map(|arg| -> Result<FuzzerArg> {
-                let argument = CairoType::from_name(arg)?;
+            .map(|argument| {
+                let argument = *argument;
                 if total_runs.get() >= 3 {
                     let run_with_min_value = rng.gen_range(1..=total_runs.get());
                     let run_with_max_value = rng.gen_range(1..=total_runs.get());
@@ -42,25 +139,77 @@ impl RunParams {
                         run_with_max_value
                     };
 
-                    Ok(FuzzerArg {
+                    FuzzerArg {
                         cairo_type: argument,
                         run_with_max_value,
                         run_with_min_value,
-                    })
+                    }
                 } else {
-                    Ok(FuzzerArg {
+                    FuzzerArg {
                         cairo_type: argument,
                         run_with_max_value: u32::MAX,
                         run_with_min_value: u32::MAX,
-                    })
+                    }
                 }
             })
-            .collect::<Result<Vec<_>>>()?;
+            .collect();
 
-        Ok(Self {
+        Self {
             arguments,
             total_runs,
             executed_runs: 0,
-        })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejection_by_assume() {
+        assert!(is_rejected_by_assume(&[Felt252::from_bytes_be(
+            b"snforge assume rejected"
+        )]));
+        assert!(!is_rejected_by_assume(&[
+            Felt252::from_bytes_be(b"snforge assume rejected"),
+            Felt252::from(1)
+        ]));
+        assert!(!is_rejected_by_assume(&[Felt252::from_bytes_be(
+            b"failing check"
+        )]));
+    }
+
+    #[test]
+    fn exhaustive_fuzzing_up_to_threshold() {
+        let runs = NonZeroU32::new(10).unwrap();
+        let arguments = [
+            CairoType::Enum { variants: 2 },
+            CairoType::Enum { variants: 3 },
+        ];
+
+        let fuzzer = Fuzzer::create(1234, runs, &arguments, 6);
+        assert!(matches!(fuzzer, Fuzzer::Exhaustive(_)));
+        assert_eq!(fuzzer.total_runs().get(), 6);
+
+        let fuzzer = Fuzzer::create(1234, runs, &arguments, 5);
+        assert!(matches!(fuzzer, Fuzzer::Random(_)));
+        assert_eq!(fuzzer.total_runs(), runs);
+    }
+
+    #[test]
+    fn derived_seed_is_deterministic() {
+        assert_eq!(
+            derive_test_seed(1234, "tests::test_sum"),
+            derive_test_seed(1234, "tests::test_sum")
+        );
+    }
+
+    #[test]
+    fn derived_seed_differs_between_tests_and_global_seeds() {
+        let seed = derive_test_seed(1234, "tests::test_sum");
+
+        assert_ne!(seed, derive_test_seed(1234, "tests::test_mul"));
+        assert_ne!(seed, derive_test_seed(1235, "tests::test_sum"));
     }
 }
@@ -4,6 +4,7 @@ use num_bigint::{BigUint, RandBigInt};
 use num_integer::Integer;
 use num_traits::{One, Zero};
 use rand::prelude::StdRng;
+use rand::Rng;
 use std::ops::{Add, Shl, Shr, Sub};
 
 #[derive(Debug, Copy, Clone)]
@@ -15,6 +16,22 @@ pub enum CairoType {
     U128,
     U256,
     Felt252,
+    /// Felt smaller than 2^251, the bound of valid contract addresses
+    ContractAddress,
+    /// Felt smaller than 2^251, the bound of valid class hashes
+    ClassHash,
+    /// `Array<felt252>` with at most `max_length` elements
+    Array {
+        max_length: u32,
+    },
+    /// `ByteArray` with at most `max_length` bytes
+    ByteArray {
+        max_length: u32,
+    },
+    /// Enum whose variants carry no data, e.g. `bool`
+    Enum {
+        variants: u32,
+    },
 }
 
 impl CairoType {
@@ -30,7 +47,30 @@ impl CairoType {
             CairoType::U64 => BigUint::from(u64::MAX).add(BigUint::one()),
             CairoType::U128 => BigUint::from(u128::MAX).add(BigUint::one()),
             CairoType::U256 => BigUint::from(1_u32).shl(256),
-            CairoType::Felt252 => Felt252::prime(),
+            CairoType::ContractAddress | CairoType::ClassHash => BigUint::from(1_u32).shl(251),
+            CairoType::Felt252 | CairoType::Array { .. } => Felt252::prime(),
+            CairoType::ByteArray { .. } => BigUint::from(u8::MAX).add(BigUint::one()),
+            CairoType::Enum { variants } => BigUint::from(variants),
+        }
+    }
+
+    /// Number of values of the type, if it is small enough to enumerate all of them
+    pub fn domain_size(self) -> Option<u64> {
+        match self {
+            CairoType::U8 => Some(u64::from(u8::MAX) + 1),
+            CairoType::U16 => Some(u64::from(u16::MAX) + 1),
+            CairoType::U32 => Some(u64::from(u32::MAX) + 1),
+            CairoType::Enum { variants } => Some(u64::from(variants)),
+            _ => None,
+        }
+    }
+
+    /// Value with the `index` in the order of values of the type, smaller than its domain size
+    pub fn value_at(self, index: u64) -> Vec<Felt252> {
+        debug_assert!(self.domain_size().is_some_and(|size| index < size));
+        match self {
+            CairoType::Enum { variants } => vec![enum_selector(variants, index)],
+            _ => vec![Felt252::from(index)],
         }
     }
 
@@ -41,7 +81,9 @@ impl CairoType {
             | CairoType::U32
             | CairoType::U64
             | CairoType::U128
-            | CairoType::Felt252 => {
+            | CairoType::Felt252
+            | CairoType::ContractAddress
+            | CairoType::ClassHash => {
                 vec![Felt252::from(
                     rng.gen_biguint_range(&Self::low(), &self.high()),
                 )]
@@ -50,6 +92,23 @@ impl CairoType {
                 let val = rng.gen_biguint_range(&Self::low(), &self.high());
                 u256_to_felt252(val)
             }
+            CairoType::Array { max_length } => {
+                let length = rng.gen_range(0..=max_length);
+                let elements = (0..length)
+                    .map(|_| Felt252::from(rng.gen_biguint_range(&Self::low(), &self.high())));
+                array_to_felt252(elements)
+            }
+            CairoType::ByteArray { max_length } => {
+                let length = rng.gen_range(0..=max_length);
+                let bytes: Vec<u8> = (0..length).map(|_| rng.gen()).collect();
+                byte_array_to_felt252(&bytes)
+            }
+            CairoType::Enum { variants } => {
+                vec![enum_selector(
+                    variants,
+                    u64::from(rng.gen_range(0..variants)),
+                )]
+            }
         }
     }
 
@@ -60,8 +119,13 @@ impl CairoType {
             | CairoType::U32
             | CairoType::U64
             | CairoType::U128
-            | CairoType::Felt252 => vec![Felt252::from(Self::low())],
+            | CairoType::Felt252
+            | CairoType::ContractAddress
+            | CairoType::ClassHash => vec![Felt252::from(Self::low())],
             CairoType::U256 => vec![Felt252::from(Self::low()), Felt252::from(Self::low())],
+            CairoType::Array { .. } => array_to_felt252([]),
+            CairoType::ByteArray { .. } => byte_array_to_felt252(&[]),
+            CairoType::Enum { variants } => vec![enum_selector(variants, 0)],
         }
     }
 
@@ -72,20 +136,63 @@ impl CairoType {
             | CairoType::U32
             | CairoType::U64
             | CairoType::U128
-            | CairoType::Felt252 => vec![Felt252::from(self.high().sub(BigUint::one()))],
+            | CairoType::Felt252
+            | CairoType::ContractAddress
+            | CairoType::ClassHash => vec![Felt252::from(self.high().sub(BigUint::one()))],
             CairoType::U256 => u256_to_felt252(self.high().sub(BigUint::one())),
+            CairoType::Array { max_length } => array_to_felt252(
+                (0..max_length).map(|_| Felt252::from(self.high().sub(BigUint::one()))),
+            ),
+            CairoType::ByteArray { max_length } => {
+                byte_array_to_felt252(&vec![u8::MAX; max_length as usize])
+            }
+            CairoType::Enum { variants } => {
+                vec![enum_selector(variants, u64::from(variants) - 1)]
+            }
         }
     }
 }
 
+/// Enums without data are represented in memory by the selector of the variant, which is its index
+/// for enums with at most 2 variants, and the relative jump to the variant's branch in the jump table
+/// of `match` for larger ones
+fn enum_selector(variants: u32, index: u64) -> Felt252 {
+    if variants <= 2 {
+        Felt252::from(index)
+    } else {
+        Felt252::from(2 * (u64::from(variants) - index) - 1)
+    }
+}
+
 fn u256_to_felt252(val: BigUint) -> Vec<Felt252> {
     let low = val.mod_floor(&BigUint::from(2_u32).pow(128));
     let high = val.shr(128);
     vec![Felt252::from(low), Felt252::from(high)]
 }
 
+/// Serializes an array as its length followed by the elements
+fn array_to_felt252(elements: impl IntoIterator<Item = Felt252>) -> Vec<Felt252> {
+    let elements: Vec<Felt252> = elements.into_iter().collect();
+    let mut serialized = vec![Felt252::from(elements.len())];
+    serialized.extend(elements);
+    serialized
+}
+
+/// Serializes bytes as `ByteArray`: an array of full 31 bytes words,
+/// followed by the pending word and its length in bytes
+fn byte_array_to_felt252(bytes: &[u8]) -> Vec<Felt252> {
+    let full_words = bytes.chunks_exact(31);
+    let pending_word = full_words.remainder();
+
+    let mut serialized = array_to_felt252(full_words.map(Felt252::from_bytes_be));
+    serialized.push(Felt252::from_bytes_be(pending_word));
+    serialized.push(Felt252::from(pending_word.len()));
+    serialized
+}
+
 impl CairoType {
-    pub fn from_name(name: &str) -> Result<Self> {
+    /// `max_length` bounds the length of generated arrays and byte arrays
+    pub fn from_name(name: &str, max_length: u32) -> Result<Self> {
         match name {
             "u8" => Ok(Self::U8),
             "u16" => Ok(Self::U16),
@@ -94,9 +201,136 @@ impl CairoType {
             "u128" => Ok(Self::U128),
             "u256" | "core::integer::u256" => Ok(Self::U256),
             "felt252" => Ok(Self::Felt252),
+            "ContractAddress" | "core::starknet::contract_address::ContractAddress" => {
+                Ok(Self::ContractAddress)
+            }
+            "ClassHash" | "core::starknet::class_hash::ClassHash" => Ok(Self::ClassHash),
+            "Array<felt252>" | "core::array::Array::<core::felt252>" => {
+                Ok(Self::Array { max_length })
+            }
+            "ByteArray" | "core::byte_array::ByteArray" => Ok(Self::ByteArray { max_length }),
+            _ if name.contains("Array<") || name.contains("Array::<") => Err(anyhow!(
+                "Tried to use incorrect type for fuzzing. Type = {name} is not supported, only arrays of felt252 can be generated"
+            )),
             _ => Err(anyhow!(
                 "Tried to use incorrect type for fuzzing. Type = {name} is not supported"
             )),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn byte_array_serialization() {
+        let bytes = [7_u8; 33];
+
+        assert_eq!(
+            byte_array_to_felt252(&bytes),
+            vec![
+                Felt252::from(1),
+                Felt252::from_bytes_be(&[7; 31]),
+                Felt252::from_bytes_be(&[7; 2]),
+                Felt252::from(2),
+            ]
+        );
+        assert_eq!(
+            CairoType::ByteArray { max_length: 4 }.min(),
+            vec![Felt252::from(0), Felt252::from(0), Felt252::from(0)]
+        );
+    }
+
+    #[test]
+    fn generated_collections_are_bounded() {
+        let mut rng = StdRng::seed_from_u64(1234);
+
+        for _ in 0..100 {
+            let array = CairoType::Array { max_length: 5 }.gen(&mut rng);
+            let length = array[0].to_biguint();
+            assert!(length <= BigUint::from(5_u32));
+            assert_eq!(BigUint::from(array.len() - 1), length);
+
+            let byte_array = CairoType::ByteArray { max_length: 40 }.gen(&mut rng);
+            let full_words = byte_array[0].to_biguint();
+            let pending_word_len = byte_array.last().unwrap().to_biguint();
+            assert!(full_words * 31_u32 + pending_word_len <= BigUint::from(40_u32));
+        }
+    }
+
+    #[test]
+    fn generated_collections_are_deterministic() {
+        let generate = || {
+            let mut rng = StdRng::seed_from_u64(1234);
+            (0..10)
+                .flat_map(|_| CairoType::Array { max_length: 8 }.gen(&mut rng))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(generate(), generate());
+    }
+
+    #[test]
+    fn generated_addresses_and_class_hashes_are_valid() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let bound = BigUint::from(1_u32).shl(251);
+
+        for cairo_type in [CairoType::ContractAddress, CairoType::ClassHash] {
+            for _ in 0..100 {
+                assert!(cairo_type.gen(&mut rng)[0].to_biguint() < bound);
+            }
+            assert_eq!(
+                cairo_type.max(),
+                vec![Felt252::from(bound.clone().sub(BigUint::one()))]
+            );
+        }
+        assert!(matches!(
+            CairoType::from_name("core::starknet::contract_address::ContractAddress", 8),
+            Ok(CairoType::ContractAddress)
+        ));
+    }
+
+    #[test]
+    fn enum_values_are_selectors_of_variants() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let bool_type = CairoType::Enum { variants: 2 };
+        let enum_type = CairoType::Enum { variants: 3 };
+
+        assert_eq!(bool_type.min(), vec![Felt252::from(0)]);
+        assert_eq!(bool_type.max(), vec![Felt252::from(1)]);
+        let selectors: Vec<Vec<Felt252>> = (0..3).map(|index| enum_type.value_at(index)).collect();
+        assert_eq!(
+            selectors,
+            vec![
+                vec![Felt252::from(5)],
+                vec![Felt252::from(3)],
+                vec![Felt252::from(1)]
+            ]
+        );
+        for _ in 0..100 {
+            assert!(selectors.contains(&enum_type.gen(&mut rng)));
+        }
+        assert_eq!(enum_type.domain_size(), Some(3));
+    }
+
+    #[test]
+    fn nested_arrays_are_not_supported() {
+        let error = CairoType::from_name("Array<Array<felt252>>", 8).unwrap_err();
+
+        assert!(error
+            .to_string()
+            .contains("only arrays of felt252 can be generated"));
+    }
+
+    #[test]
+    fn unsupported_type() {
+        let error = CairoType::from_name("invalid", 16).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Tried to use incorrect type for fuzzing. Type = invalid is not supported"
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use crate::fuzzer::arguments::CairoType;
+use cairo_felt::Felt252;
+use std::num::NonZeroU32;
+
+/// Fuzzer running a test with every combination of values of its arguments,
+/// used instead of random fuzzing when the number of combinations is small
+#[derive(Debug, Clone)]
+pub struct ExhaustiveFuzzer {
+    arguments: Vec<(CairoType, u64)>,
+    total_runs: NonZeroU32,
+    executed_runs: u32,
+}
+
+impl ExhaustiveFuzzer {
+    /// Returns `None` if any argument can't be enumerated,
+    /// or the number of combinations is greater than `threshold`
+    #[must_use]
+    pub fn create(arguments: &[CairoType], threshold: u32) -> Option<Self> {
+        let arguments = arguments
+            .iter()
+            .map(|argument| Some((*argument, argument.domain_size()?)))
+            .collect::<Option<Vec<_>>>()?;
+
+        let combinations = arguments
+            .iter()
+            .try_fold(1_u64, |combinations, (_, size)| {
+                combinations.checked_mul(*size)
+            })?;
+        if combinations > u64::from(threshold) {
+            return None;
+        }
+
+        Some(Self {
+            arguments,
+            total_runs: NonZeroU32::new(u32::try_from(combinations).ok()?)?,
+            executed_runs: 0,
+        })
+    }
+
+    #[must_use]
+    pub fn total_runs(&self) -> NonZeroU32 {
+        self.total_runs
+    }
+
+    /// Combinations are enumerated in lexicographic order, the last argument changes the fastest
+    pub fn next_args(&mut self) -> Vec<Felt252> {
+        assert!(self.executed_runs < self.total_runs.get());
+
+        let indexes = self.indexes_of_run(self.executed_runs);
+        self.executed_runs += 1;
+
+        self.arguments
+            .iter()
+            .zip(indexes)
+            .flat_map(|((argument, _), index)| argument.value_at(index))
+            .collect()
+    }
+
+    /// Whether any argument generated by the last `next_args` call has its minimal or maximal value
+    #[must_use]
+    pub fn is_boundary_run(&self) -> bool {
+        let Some(run) = self.executed_runs.checked_sub(1) else {
+            return false;
+        };
+
+        self.indexes_of_run(run)
+            .into_iter()
+            .zip(&self.arguments)
+            .any(|(index, (_, size))| index == 0 || index == size - 1)
+    }
+
+    fn indexes_of_run(&self, run: u32) -> Vec<u64> {
+        let mut remaining = u64::from(run);
+        let mut indexes: Vec<u64> = self
+            .arguments
+            .iter()
+            .rev()
+            .map(|(_, size)| {
+                let index = remaining % size;
+                remaining /= size;
+                index
+            })
+            .collect();
+        indexes.reverse();
+        indexes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enumerates_all_combinations() {
+        let mut fuzzer =
+            ExhaustiveFuzzer::create(&[CairoType::Enum { variants: 2 }, CairoType::U8], 512)
+                .unwrap();
+
+        let combinations: Vec<Vec<Felt252>> = (0..fuzzer.total_runs().get())
+            .map(|_| fuzzer.next_args())
+            .collect();
+
+        let expected: Vec<Vec<Felt252>> = (0_u8..2)
+            .flat_map(|first| (0_u8..=u8::MAX).map(move |second| vec![first, second]))
+            .map(|combination| combination.into_iter().map(Felt252::from).collect())
+            .collect();
+        assert_eq!(combinations, expected);
+    }
+
+    #[test]
+    fn not_created_above_threshold() {
+        let arguments = [CairoType::U8, CairoType::Enum { variants: 2 }];
+
+        assert!(ExhaustiveFuzzer::create(&arguments, 511).is_none());
+        assert_eq!(
+            ExhaustiveFuzzer::create(&arguments, 512)
+                .unwrap()
+                .total_runs()
+                .get(),
+            512
+        );
+        assert!(ExhaustiveFuzzer::create(&[CairoType::Felt252], u32::MAX).is_none());
+    }
+
+    #[test]
+    fn boundary_runs() {
+        let mut fuzzer = ExhaustiveFuzzer::create(&[CairoType::Enum { variants: 3 }], 3).unwrap();
+
+        let boundary_runs: Vec<bool> = (0..3)
+            .map(|_| {
+                fuzzer.next_args();
+                fuzzer.is_boundary_run()
+            })
+            .collect();
+
+        assert_eq!(boundary_runs, vec![true, false, true]);
+    }
+}
@@ -1,5 +1,5 @@
+use crate::fuzzer::arguments::CairoType;
 use crate::fuzzer::RunParams;
-use anyhow::Result;
 use cairo_felt::Felt252;
 use rand::prelude::StdRng;
 use rand::SeedableRng;
@@ -12,11 +12,17 @@ pub struct RandomFuzzer {
 }
 
 impl RandomFuzzer {
-    pub fn create(seed: u64, total_runs: NonZeroU32, arguments: &[&str]) -> Result<Self> {
+    #[must_use]
+    pub fn create(seed: u64, total_runs: NonZeroU32, arguments: &[CairoType]) -> Self {
         let mut rng = StdRng::seed_from_u64(seed);
-        let run_params = RunParams::from(&mut rng, total_runs, arguments)?;
+        let run_params = RunParams::from(&mut rng, total_runs, arguments);
 
-        Ok(Self { rng, run_params })
+        Self { rng, run_params }
+    }
+
+    #[must_use]
+    pub fn total_runs(&self) -> NonZeroU32 {
+        self.run_params.total_runs
     }
 
     pub fn next_args(&mut self) -> Vec<Felt252> {
@@ -41,6 +47,25 @@ impl RandomFuzzer {
             .collect()
     }
 
+    /// Random arguments replacing the ones rejected by the test, the run they are generated for is not changed
+    pub fn regenerate_args(&mut self) -> Vec<Felt252> {
+        self.run_params
+            .arguments
+            .iter()
+            .flat_map(|argument| argument.cairo_type.gen(&mut self.rng))
+            .collect()
+    }
+
+    /// Whether any argument generated by the last `next_args` call has its minimal or maximal value
+    #[must_use]
+    pub fn is_boundary_run(&self) -> bool {
+        let current_run = self.run_params.executed_runs;
+
+        self.run_params.arguments.iter().any(|argument| {
+            argument.run_with_min_value == current_run || argument.run_with_max_value == current_run
+        })
+    }
+
     fn next_run(&mut self) {
         self.run_params.executed_runs += 1;
     }
@@ -49,7 +74,7 @@ impl RandomFuzzer {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::fuzzer::{arguments::CairoType, FuzzerArg};
+    use crate::fuzzer::{min_args, FuzzerArg};
     use num_bigint::BigUint;
     use num_traits::Zero;
     use rand::{thread_rng, RngCore};
@@ -116,6 +141,31 @@ mod tests {
         assert!(all_values_different(&args2, &args3));
     }
 
+    #[test]
+    fn boundary_runs() {
+        let run_params = RunParams {
+            arguments: vec![
+                FuzzerArg::new(CairoType::Felt252, 1, 3),
+                FuzzerArg::new(CairoType::U32, 3, 4),
+            ],
+            total_runs: NonZeroU32::new(5).unwrap(),
+            ..Default::default()
+        };
+        let mut fuzzer = RandomFuzzer {
+            rng: StdRng::seed_from_u64(1234),
+            run_params,
+        };
+
+        let boundary_runs: Vec<bool> = (0..5)
+            .map(|_| {
+                fuzzer.next_args();
+                fuzzer.is_boundary_run()
+            })
+            .collect();
+
+        assert_eq!(boundary_runs, vec![true, false, true, true, false]);
+    }
+
     #[test]
     fn run_with_max_value() {
         let run_params = RunParams {
@@ -160,20 +210,12 @@ mod tests {
     #[test]
     fn using_seed_consistent_result() {
         let seed = thread_rng().next_u64();
-        let mut fuzzer = RandomFuzzer::create(
-            seed,
-            NonZeroU32::new(3).unwrap(),
-            &["felt252", "felt252", "felt252"],
-        )
-        .unwrap();
+        let mut fuzzer =
+            RandomFuzzer::create(seed, NonZeroU32::new(3).unwrap(), &[CairoType::Felt252; 3]);
         let values = fuzzer.next_args();
 
-        let mut fuzzer = RandomFuzzer::create(
-            seed,
-            NonZeroU32::new(3).unwrap(),
-            &["felt252", "felt252", "felt252"],
-        )
-        .unwrap();
+        let mut fuzzer =
+            RandomFuzzer::create(seed, NonZeroU32::new(3).unwrap(), &[CairoType::Felt252; 3]);
         let values_from_seed = fuzzer.next_args();
 
         assert_eq!(values, values_from_seed);
@@ -183,10 +225,10 @@ mod tests {
     fn min_and_max_used_at_least_once_for_each_arg() {
         let seed = thread_rng().next_u64();
         let runs_number = NonZeroU32::new(10).unwrap();
-        let arguments = vec!["felt252", "felt252", "felt252"];
+        let arguments = vec![CairoType::Felt252; 3];
         let args_number = arguments.len();
 
-        let mut fuzzer = RandomFuzzer::create(seed, runs_number, &arguments).unwrap();
+        let mut fuzzer = RandomFuzzer::create(seed, runs_number, &arguments);
 
         let mut min_used = vec![false; args_number];
         let mut max_used = vec![false; args_number];
@@ -209,28 +251,21 @@ mod tests {
         assert_eq!(max_used, vec![true; args_number]);
     }
 
-    #[test]
-    fn create_fuzzer_from_invalid_arguments() {
-        let result = RandomFuzzer::create(
-            1234,
-            NonZeroU32::new(512).unwrap(),
-            &["felt252", "invalid", "args"],
-        );
-        let err = result.unwrap_err();
-
-        assert_eq!(
-            err.to_string(),
-            "Tried to use incorrect type for fuzzing. Type = invalid is not supported"
-        );
-    }
     #[test]
     fn fuzzer_less_than_3_runs() {
         for runs in 1..2 {
-            let result = RandomFuzzer::create(1234, NonZeroU32::new(runs).unwrap(), &["felt252"]);
-            let mut fuzzer = result.unwrap();
+            let mut fuzzer =
+                RandomFuzzer::create(1234, NonZeroU32::new(runs).unwrap(), &[CairoType::Felt252]);
 
             // just check if it panics
             fuzzer.next_args();
         }
     }
+
+    #[test]
+    fn min_args_are_zeroes() {
+        let args = min_args(&[CairoType::Felt252, CairoType::U256, CairoType::U8]);
+
+        assert_eq!(args, vec![Felt252::from(0_u8); 4]);
+    }
 }
@@ -12,6 +12,7 @@ use blockifier::state::cached_state::CachedState;
 use blockifier::state::errors::StateError;
 use blockifier::transaction::objects::{GasVector, HasRelatedFeeType};
 use blockifier::utils::u128_from_usize;
+use blockifier::versioned_constants::VersionedConstants;
 use cheatnet::runtime_extensions::call_to_blockifier_runtime_extension::rpc::UsedResources;
 use cheatnet::state::ExtendedStateReader;
 use starknet_api::transaction::EventContent;
@@ -41,6 +42,20 @@ pub fn calculate_used_gas(
     Ok(gas.l1_gas + gas.l1_data_gas)
 }
 
+/// L1 gas of VM resources used by constructors of contracts deployed in the test.
+/// Versioned constants are the same as the ones of the block context of tests
+#[must_use]
+pub fn calculate_deployments_gas(resources: &UsedResources) -> u128 {
+    let gas = calculate_l1_gas_by_vm_usage(
+        VersionedConstants::latest_constants(),
+        &resources.deployment_resources,
+        0,
+    )
+    .expect("Could not calculate gas");
+
+    gas.l1_gas + gas.l1_data_gas
+}
+
 fn get_events_cost(
     events: Vec<EventContent>,
     transaction_context: &TransactionContext,
@@ -154,6 +169,7 @@ pub fn check_available_gas(
             name,
             arguments,
             gas_info,
+            printed_output,
             ..
         } if available_gas.map_or(false, |available_gas| gas_info > available_gas as u128) => {
             TestCaseSummary::Failed {
@@ -163,6 +179,7 @@ pub fn check_available_gas(
                 )),
                 arguments,
                 test_statistics: (),
+                printed_output,
             }
         }
         _ => summary,
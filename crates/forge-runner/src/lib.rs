@@ -1,12 +1,13 @@
 use crate::build_trace_data::test_sierra_program_path::VersionedProgramPath;
 use crate::forge_config::{ExecutionDataToSave, ForgeConfig, TestRunnerConfig};
-use crate::fuzzer::RandomFuzzer;
-use crate::running::{run_fuzz_test, run_test};
-use crate::test_case_summary::TestCaseSummary;
+use crate::fuzzer::{derive_test_seed, min_args, CairoType, Fuzzer};
+use crate::running::{run_fuzz_test, run_test, FuzzTestRunResult};
+use crate::test_case_summary::{FuzzerArgumentsStatistics, FuzzingStatistics, TestCaseSummary};
 use anyhow::{anyhow, Result};
 use build_trace_data::save_trace_data;
+use cairo_felt::Felt252;
 use cairo_lang_sierra::ids::ConcreteTypeId;
-use cairo_lang_sierra::program::Function;
+use cairo_lang_sierra::program::{Function, GenericArg, Program};
 use camino::Utf8Path;
 use futures::stream::FuturesUnordered;
 use futures::StreamExt;
@@ -16,6 +17,8 @@ use package_tests::with_config_resolved::{
 };
 use profiler_api::run_profiler;
 use smol_str::SmolStr;
+use std::collections::HashSet;
+use std::num::NonZeroU32;
 use std::sync::Arc;
 use test_case_summary::{AnyTestCaseSummary, Fuzzing};
 use tokio::sync::mpsc::{channel, Sender};
@@ -23,12 +26,18 @@ use tokio::task::JoinHandle;
 use universal_sierra_compiler_api::AssembledProgramWithDebugInfo;
 
 pub mod build_trace_data;
+pub mod error_names;
 pub mod expected_result;
 pub mod forge_config;
+pub mod package_report;
 pub mod package_tests;
 pub mod profiler_api;
+pub mod results_jsonl;
+pub mod storage_snapshot;
+pub mod test_case_filter;
 pub mod test_case_summary;
 pub mod test_target_summary;
+pub mod vm_trace;
 
 mod fuzzer;
 mod gas;
@@ -36,6 +45,7 @@ pub mod printing;
 mod running;
 
 pub const CACHE_DIR: &str = ".snfoundry_cache";
+pub const SNAPSHOTS_DIR: &str = "snapshots";
 
 const BUILTINS: [&str; 8] = [
     "Pedersen",
@@ -101,16 +111,29 @@ pub fn maybe_save_versioned_program(
 
 #[must_use]
 pub fn run_for_test_case(
-    args: Vec<ConcreteTypeId>,
+    args: Vec<TestFunctionArg>,
     case: Arc<TestCaseWithResolvedConfig>,
     casm_program: Arc<AssembledProgramWithDebugInfo>,
     forge_config: Arc<ForgeConfig>,
     maybe_versioned_program_path: Arc<Option<VersionedProgramPath>>,
     send: Sender<()>,
 ) -> JoinHandle<Result<AnyTestCaseSummary>> {
+    let (fuzzer_runs, fuzzer_seed) = match case.config.fuzzer_config {
+        Some(RawFuzzerConfig {
+            fuzzer_runs,
+            fuzzer_seed,
+        }) => (fuzzer_runs, fuzzer_seed),
+        // Seed set for the test case is used as is, the global one is made specific to the test
+        _ => (
+            forge_config.test_runner_config.fuzzer_runs,
+            derive_test_seed(forge_config.test_runner_config.fuzzer_seed, &case.name),
+        ),
+    };
+
     if args.is_empty() {
         tokio::task::spawn(async move {
             let res = run_test(
+                vec![],
                 case,
                 casm_program,
                 forge_config.test_runner_config.clone(),
@@ -120,10 +143,12 @@ pub fn run_for_test_case(
             .await??;
             Ok(AnyTestCaseSummary::Single(res))
         })
-    } else {
+    } else if let Some(fuzzer_runs) = NonZeroU32::new(fuzzer_runs) {
         tokio::task::spawn(async move {
             let res = run_with_fuzzing(
                 args,
+                fuzzer_runs,
+                fuzzer_seed,
                 case,
                 casm_program,
                 forge_config.test_runner_config.clone(),
@@ -133,11 +158,50 @@ pub fn run_for_test_case(
             .await??;
             Ok(AnyTestCaseSummary::Fuzzing(res))
         })
+    } else {
+        // Zero fuzzer runs mean the test is run once, with the minimal value of every argument
+        tokio::task::spawn(async move {
+            let args = min_args(&arg_types(
+                &args,
+                forge_config.test_runner_config.fuzzer_max_length,
+            )?);
+            let res = run_test(
+                args,
+                case,
+                casm_program,
+                forge_config.test_runner_config.clone(),
+                maybe_versioned_program_path,
+                send,
+            )
+            .await??;
+            Ok(AnyTestCaseSummary::Single(res))
+        })
     }
 }
 
+/// `max_length` bounds the length of generated arrays and byte arrays
+fn arg_types(args: &[TestFunctionArg], max_length: u32) -> Result<Vec<CairoType>> {
+    args.iter()
+        .map(|arg| {
+            if let Some(variants) = arg.unit_enum_variants {
+                return Ok(CairoType::Enum { variants });
+            }
+            let name = arg
+                .type_id
+                .debug_name
+                .as_ref()
+                .ok_or_else(|| anyhow!("Type {:?} does not have a debug name", arg.type_id))?;
+            CairoType::from_name(name, max_length)
+        })
+        .collect()
+}
+
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::too_many_lines)]
 fn run_with_fuzzing(
-    args: Vec<ConcreteTypeId>,
+    args: Vec<TestFunctionArg>,
+    fuzzer_runs: NonZeroU32,
+    fuzzer_seed: u64,
     case: Arc<TestCaseWithResolvedConfig>,
     casm_program: Arc<AssembledProgramWithDebugInfo>,
     test_runner_config: Arc<TestRunnerConfig>,
@@ -150,34 +214,25 @@ fn run_with_fuzzing(
         }
 
         let (fuzzing_send, mut fuzzing_rec) = channel(1);
-        let args = args
-            .iter()
-            .map(|arg| {
-                arg.debug_name
-                    .as_ref()
-                    .ok_or_else(|| anyhow!("Type {arg:?} does not have a debug name"))
-                    .map(SmolStr::as_str)
-            })
-            .collect::<Result<Vec<_>>>()?;
-
-        let (fuzzer_runs, fuzzer_seed) = match case.config.fuzzer_config {
-            Some(RawFuzzerConfig {
-                fuzzer_runs,
-                fuzzer_seed,
-            }) => (fuzzer_runs, fuzzer_seed),
-            _ => (
-                test_runner_config.fuzzer_runs,
-                test_runner_config.fuzzer_seed,
-            ),
-        };
-        let mut fuzzer = RandomFuzzer::create(fuzzer_seed, fuzzer_runs, &args)?;
+        let args = arg_types(&args, test_runner_config.fuzzer_max_length)?;
 
-        let mut tasks = FuturesUnordered::new();
+        let mut fuzzer = Fuzzer::create(
+            fuzzer_seed,
+            fuzzer_runs,
+            &args,
+            test_runner_config
+                .fuzzer_exhaustive_threshold
+                .unwrap_or(fuzzer_runs.get()),
+        );
+        let mut total_runs = fuzzer.total_runs().get() as usize;
 
-        for _ in 1..=fuzzer_runs.get() {
-            let args = fuzzer.next_args();
+        let mut tasks = FuturesUnordered::new();
+        let mut unique_args = HashSet::new();
+        let mut boundary_runs = 0;
 
-            tasks.push(run_fuzz_test(
+        let mut spawn_run = |args: Vec<Felt252>| {
+            unique_args.insert(args.clone());
+            run_fuzz_test(
                 args,
                 case.clone(),
                 casm_program.clone(),
@@ -185,12 +240,43 @@ fn run_with_fuzzing(
                 maybe_versioned_program_path.clone(),
                 send.clone(),
                 fuzzing_send.clone(),
-            ));
+            )
+        };
+
+        for _ in 1..=fuzzer.total_runs().get() {
+            let args = fuzzer.next_args();
+            if fuzzer.is_boundary_run() {
+                boundary_runs += 1;
+            }
+            tasks.push(spawn_run(args));
         }
 
         let mut results = vec![];
+        let mut consecutive_rejections = 0;
         while let Some(task) = tasks.next().await {
-            let result = task??;
+            let result = match task?? {
+                FuzzTestRunResult::Completed(result) => result,
+                FuzzTestRunResult::Rejected if matches!(fuzzer, Fuzzer::Exhaustive(_)) => {
+                    // Every combination is run once, so the rejected ones are not replaced
+                    total_runs -= 1;
+                    continue;
+                }
+                FuzzTestRunResult::Rejected => {
+                    consecutive_rejections += 1;
+                    if consecutive_rejections > test_runner_config.fuzzer_max_rejects {
+                        fuzzing_rec.close();
+                        return Ok(too_many_rejections(
+                            &case.name,
+                            results.len(),
+                            consecutive_rejections,
+                        ));
+                    }
+                    // Rejected run is replaced with a run with new arguments
+                    tasks.push(spawn_run(fuzzer.regenerate_args()));
+                    continue;
+                }
+            };
+            consecutive_rejections = 0;
 
             results.push(result.clone());
 
@@ -200,31 +286,149 @@ fn run_with_fuzzing(
             }
         }
 
-        let runs = u32::try_from(
-            results
-                .iter()
-                .filter(|item| {
-                    matches!(
-                        item,
-                        TestCaseSummary::Passed { .. } | TestCaseSummary::Failed { .. }
-                    )
-                })
-                .count(),
-        )?;
+        if total_runs == 0 {
+            return Ok(too_many_rejections(
+                &case.name,
+                0,
+                fuzzer.total_runs().get(),
+            ));
+        }
 
-        let fuzzing_run_summary: TestCaseSummary<Fuzzing> = TestCaseSummary::from(results);
+        Ok(TestCaseSummary::<Fuzzing>::from(
+            results,
+            total_runs,
+            FuzzerArgumentsStatistics {
+                unique: unique_args.len(),
+                boundary_runs,
+            },
+        ))
+    })
+}
 
-        if let TestCaseSummary::Passed { .. } = fuzzing_run_summary {
-            // Because we execute tests parallel, it's possible to
-            // get Passed after Skipped. To treat fuzzing a test as Passed
-            // we have to ensure that all fuzzing subtests Passed
-            if runs != fuzzer_runs.get() {
-                return Ok(TestCaseSummary::Skipped {});
-            };
-        };
+fn too_many_rejections(test_name: &str, runs: usize, rejections: u32) -> TestCaseSummary<Fuzzing> {
+    TestCaseSummary::Failed {
+        name: test_name.to_string(),
+        msg: Some(format!(
+            "\n    Arguments of {rejections} consecutive runs were rejected with `assume`, the precondition is too strict\n"
+        )),
+        arguments: vec![],
+        test_statistics: FuzzingStatistics {
+            runs,
+            arguments: FuzzerArgumentsStatistics::default(),
+        },
+        printed_output: String::new(),
+    }
+}
 
-        Ok(fuzzing_run_summary)
-    })
+/// Finds a function in the sierra program whose debug name corresponds to the test case name.
+/// If there is none, the error lists functions with names closest to the searched one.
+pub fn find_test_function<'a>(program: &'a Program, test_name: &str) -> Result<&'a Function> {
+    program
+        .funcs
+        .iter()
+        .find(|function| {
+            function
+                .id
+                .debug_name
+                .as_ref()
+                .is_some_and(|name| name.ends_with(test_name))
+        })
+        .ok_or_else(|| {
+            let closest_names = closest_function_names(program, test_name);
+            if closest_names.is_empty() {
+                anyhow!("Function for test = {test_name} was not found in the sierra program")
+            } else {
+                anyhow!(
+                    "Function for test = {test_name} was not found in the sierra program. Closest available functions: {}",
+                    closest_names.join(", ")
+                )
+            }
+        })
+}
+
+fn closest_function_names(program: &Program, test_name: &str) -> Vec<String> {
+    const MAX_SUGGESTIONS: usize = 3;
+
+    let mut names_with_distances: Vec<_> = program
+        .funcs
+        .iter()
+        .filter_map(|function| function.id.debug_name.as_ref())
+        .map(|name| (levenshtein_distance(name, test_name), name.to_string()))
+        .collect();
+    names_with_distances.sort();
+
+    names_with_distances
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, name)| name)
+        .collect()
+}
+
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut previous_row: Vec<usize> = (0..=b_chars.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current_row = vec![i + 1];
+        for (j, b_char) in b_chars.iter().enumerate() {
+            let substitution_cost = usize::from(a_char != *b_char);
+            current_row.push(
+                (previous_row[j] + substitution_cost)
+                    .min(previous_row[j + 1] + 1)
+                    .min(current_row[j] + 1),
+            );
+        }
+        previous_row = current_row;
+    }
+
+    previous_row[b_chars.len()]
+}
+
+/// Argument of a test function, with the number of variants of its type
+/// if it is an enum whose variants carry no data, e.g. `bool`
+#[derive(Debug, Clone)]
+pub struct TestFunctionArg {
+    pub type_id: ConcreteTypeId,
+    pub unit_enum_variants: Option<u32>,
+}
+
+#[must_use]
+pub fn test_function_args(program: &Program, function: &Function) -> Vec<TestFunctionArg> {
+    function_args(function)
+        .into_iter()
+        .map(|type_id| TestFunctionArg {
+            type_id: type_id.clone(),
+            unit_enum_variants: unit_enum_variants(program, type_id),
+        })
+        .collect()
+}
+
+/// Enums are declared in Sierra as `Enum<ut@name, variant types...>`,
+/// variants without data have the unit type `Struct<ut@Tuple>`, which has no members
+fn unit_enum_variants(program: &Program, type_id: &ConcreteTypeId) -> Option<u32> {
+    let declaration = |id: &ConcreteTypeId| {
+        program
+            .type_declarations
+            .iter()
+            .find(|declaration| declaration.id == *id)
+    };
+
+    let long_id = &declaration(type_id)?.long_id;
+    if long_id.generic_id.0 != "Enum" {
+        return None;
+    }
+    let variants = long_id.generic_args.get(1..)?;
+    let all_unit = !variants.is_empty()
+        && variants.iter().all(|variant| match variant {
+            GenericArg::Type(variant_id) => declaration(variant_id).is_some_and(|variant| {
+                variant.long_id.generic_id.0 == "Struct" && variant.long_id.generic_args.len() == 1
+            }),
+            _ => false,
+        });
+
+    all_unit
+        .then(|| u32::try_from(variants.len()).ok())
+        .flatten()
 }
 
 #[must_use]
@@ -241,3 +445,70 @@ pub fn function_args(function: &Function) -> Vec<&ConcreteTypeId> {
         .filter(|pt| !builtins.contains(&pt.debug_name))
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cairo_lang_sierra::ids::FunctionId;
+    use cairo_lang_sierra::program::{FunctionSignature, StatementIdx};
+
+    fn program_with_functions(names: &[&str]) -> Program {
+        Program {
+            type_declarations: vec![],
+            libfunc_declarations: vec![],
+            statements: vec![],
+            funcs: names
+                .iter()
+                .map(|name| Function {
+                    id: FunctionId::from_string(*name),
+                    signature: FunctionSignature {
+                        param_types: vec![],
+                        ret_types: vec![],
+                    },
+                    params: vec![],
+                    entry_point: StatementIdx(0),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn find_existing_test_function() {
+        let program = program_with_functions(&["tests::test_one", "tests::test_two"]);
+
+        let function = find_test_function(&program, "tests::test_two").unwrap();
+
+        assert_eq!(
+            function.id.debug_name.as_ref().unwrap().as_str(),
+            "tests::test_two"
+        );
+    }
+
+    #[test]
+    fn find_renamed_test_function() {
+        let program = program_with_functions(&[
+            "tests::test_transfer_v2",
+            "tests::test_approve",
+            "tests::helper",
+        ]);
+
+        let error = find_test_function(&program, "tests::test_transfer").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Function for test = tests::test_transfer was not found in the sierra program. Closest available functions: tests::test_transfer_v2, tests::test_approve, tests::helper"
+        );
+    }
+
+    #[test]
+    fn find_test_function_in_empty_program() {
+        let program = program_with_functions(&[]);
+
+        let error = find_test_function(&program, "tests::test_transfer").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Function for test = tests::test_transfer was not found in the sierra program"
+        );
+    }
+}
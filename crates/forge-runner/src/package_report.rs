@@ -0,0 +1,352 @@
+use crate::results_jsonl::status;
+use crate::test_case_summary::{
+    AnyTestCaseSummary, FuzzingGasInfo, GasStatistics, TestCaseSummary,
+};
+use anyhow::{Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
+use serde::Serialize;
+use std::fs;
+
+/// Version of the report format, bumped on every incompatible change
+const REPORT_VERSION: u8 = 1;
+
+#[derive(Serialize)]
+struct StatisticsReport {
+    min: u128,
+    max: u128,
+    mean: f64,
+    std_deviation: f64,
+}
+
+impl From<&GasStatistics> for StatisticsReport {
+    fn from(statistics: &GasStatistics) -> Self {
+        Self {
+            min: statistics.min,
+            max: statistics.max,
+            mean: statistics.mean,
+            std_deviation: statistics.std_deviation,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum ResourcesReport {
+    Single {
+        l1_gas: u128,
+        steps: usize,
+    },
+    Fuzzing {
+        runs: usize,
+        l1_gas: StatisticsReport,
+        steps: StatisticsReport,
+    },
+}
+
+#[derive(Serialize)]
+struct TestCaseReport {
+    name: String,
+    status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    msg: Option<String>,
+    /// Present only for passed test cases
+    #[serde(skip_serializing_if = "Option::is_none")]
+    resources: Option<ResourcesReport>,
+    printed_output: String,
+}
+
+impl TestCaseReport {
+    fn from_summary(result: &AnyTestCaseSummary) -> Option<Self> {
+        let resources = match result {
+            AnyTestCaseSummary::Single(TestCaseSummary::Passed {
+                gas_info,
+                used_resources,
+                ..
+            }) => Some(ResourcesReport::Single {
+                l1_gas: *gas_info,
+                steps: used_resources.execution_resources.n_steps,
+            }),
+            AnyTestCaseSummary::Fuzzing(TestCaseSummary::Passed {
+                gas_info,
+                test_statistics,
+                ..
+            }) => Some(ResourcesReport::Fuzzing {
+                runs: test_statistics.runs,
+                l1_gas: (&gas_info.l1_gas).into(),
+                steps: (&gas_info.steps).into(),
+            }),
+            _ => None,
+        };
+
+        Some(Self {
+            name: result.name()?.to_string(),
+            status: status(result),
+            msg: result.msg().map(String::from),
+            resources,
+            printed_output: result.printed_output().unwrap_or_default().to_string(),
+        })
+    }
+}
+
+#[derive(Serialize)]
+struct FuzzingGasReport {
+    min: u128,
+    max: u128,
+    mean: f64,
+}
+
+/// Run of a fuzz test which used the most L1 gas
+#[derive(Serialize)]
+struct MostExpensiveRunReport {
+    test: String,
+    l1_gas: u128,
+    /// Arguments of the run as decimal numbers
+    arguments: Vec<String>,
+}
+
+/// L1 gas used by all runs of passed fuzz tests of the package, other tests are not included
+#[derive(Serialize)]
+struct FuzzingReport {
+    tests: usize,
+    runs: usize,
+    l1_gas: FuzzingGasReport,
+    most_expensive_run: MostExpensiveRunReport,
+}
+
+impl FuzzingReport {
+    fn new(name: &str, gas_info: &FuzzingGasInfo, runs: usize) -> Self {
+        Self {
+            tests: 1,
+            runs,
+            l1_gas: FuzzingGasReport {
+                min: gas_info.l1_gas.min,
+                max: gas_info.l1_gas.max,
+                mean: gas_info.l1_gas.mean,
+            },
+            most_expensive_run: MostExpensiveRunReport {
+                test: name.to_string(),
+                l1_gas: gas_info.l1_gas.max,
+                arguments: gas_info
+                    .max_l1_gas_arguments
+                    .iter()
+                    .map(|argument| argument.to_biguint().to_string())
+                    .collect(),
+            },
+        }
+    }
+
+    /// Mean of all runs is the mean of the tests weighted by their number of runs
+    #[allow(clippy::cast_precision_loss)]
+    fn merge(self, other: Self) -> Self {
+        let mean = (self.l1_gas.mean * self.runs as f64 + other.l1_gas.mean * other.runs as f64)
+            / (self.runs + other.runs) as f64;
+        let most_expensive_run = if other.most_expensive_run.l1_gas > self.most_expensive_run.l1_gas
+        {
+            other.most_expensive_run
+        } else {
+            self.most_expensive_run
+        };
+
+        Self {
+            tests: self.tests + other.tests,
+            runs: self.runs + other.runs,
+            l1_gas: FuzzingGasReport {
+                min: self.l1_gas.min.min(other.l1_gas.min),
+                max: self.l1_gas.max.max(other.l1_gas.max),
+                mean,
+            },
+            most_expensive_run,
+        }
+    }
+}
+
+/// Results of all test cases of a package, saved as a JSON file in the output directory
+#[derive(Serialize)]
+pub struct PackageReport {
+    version: u8,
+    package: String,
+    test_cases: Vec<TestCaseReport>,
+    /// Present only if any fuzz test passed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fuzzing: Option<FuzzingReport>,
+}
+
+impl PackageReport {
+    #[must_use]
+    pub fn new(package_name: &str) -> Self {
+        Self {
+            version: REPORT_VERSION,
+            package: package_name.to_string(),
+            test_cases: vec![],
+            fuzzing: None,
+        }
+    }
+
+    /// Skipped test cases are not included, as they were never run
+    pub fn add(&mut self, result: &AnyTestCaseSummary) {
+        if let Some(test_case) = TestCaseReport::from_summary(result) {
+            self.test_cases.push(test_case);
+        }
+
+        if let AnyTestCaseSummary::Fuzzing(TestCaseSummary::Passed {
+            name,
+            gas_info,
+            test_statistics,
+            ..
+        }) = result
+        {
+            let fuzzing = FuzzingReport::new(name, gas_info, test_statistics.runs);
+            self.fuzzing = Some(match self.fuzzing.take() {
+                Some(previous) => previous.merge(fuzzing),
+                None => fuzzing,
+            });
+        }
+    }
+
+    /// Test cases finish in a nondeterministic order, so they are sorted by name
+    fn sorted(mut self) -> Self {
+        self.test_cases.sort_by(|a, b| a.name.cmp(&b.name));
+        self
+    }
+
+    /// Saves the report as `<package name>.json` in the output directory
+    pub fn save(self, output_dir: &Utf8Path) -> Result<Utf8PathBuf> {
+        let report = self.sorted();
+        let path = output_dir.join(format!("{}.json", report.package));
+        fs::write(&path, serde_json::to_string_pretty(&report)?)
+            .with_context(|| format!("Failed to write test report to {path}"))?;
+
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_case_summary::{FuzzerArgumentsStatistics, FuzzingStatistics};
+    use cairo_felt::Felt252;
+    use cheatnet::runtime_extensions::call_to_blockifier_runtime_extension::rpc::UsedResources;
+    use serde_json::json;
+
+    #[test]
+    fn serializes_sorted_test_cases() {
+        let mut report = PackageReport::new("my_package");
+        report.add(&AnyTestCaseSummary::Single(TestCaseSummary::Failed {
+            name: "tests::b".to_string(),
+            msg: Some("assertion failed".to_string()),
+            arguments: vec![],
+            test_statistics: (),
+            printed_output: "printed\n".to_string(),
+        }));
+        report.add(&AnyTestCaseSummary::Fuzzing(TestCaseSummary::Passed {
+            name: "tests::a".to_string(),
+            msg: None,
+            arguments: vec![],
+            gas_info: FuzzingGasInfo::default(),
+            used_resources: UsedResources::default(),
+            test_statistics: FuzzingStatistics {
+                runs: 3,
+                arguments: FuzzerArgumentsStatistics::default(),
+            },
+            trace_data: (),
+            printed_output: String::new(),
+        }));
+        report.add(&AnyTestCaseSummary::Single(TestCaseSummary::Skipped {}));
+
+        let content = serde_json::to_value(report.sorted()).unwrap();
+        let statistics = json!({ "min": 0, "max": 0, "mean": 0.0, "std_deviation": 0.0 });
+        assert_eq!(
+            content,
+            json!({
+                "version": 1,
+                "package": "my_package",
+                "test_cases": [
+                    {
+                        "name": "tests::a",
+                        "status": "passed",
+                        "resources": { "runs": 3, "l1_gas": statistics, "steps": statistics },
+                        "printed_output": ""
+                    },
+                    {
+                        "name": "tests::b",
+                        "status": "failed",
+                        "msg": "assertion failed",
+                        "printed_output": "printed\n"
+                    }
+                ],
+                "fuzzing": {
+                    "tests": 1,
+                    "runs": 3,
+                    "l1_gas": { "min": 0, "max": 0, "mean": 0.0 },
+                    "most_expensive_run": { "test": "tests::a", "l1_gas": 0, "arguments": [] }
+                }
+            })
+        );
+    }
+
+    fn passed_fuzz_test(name: &str, runs: usize, l1_gas: GasStatistics) -> AnyTestCaseSummary {
+        AnyTestCaseSummary::Fuzzing(TestCaseSummary::Passed {
+            name: name.to_string(),
+            msg: None,
+            arguments: vec![],
+            gas_info: FuzzingGasInfo {
+                max_l1_gas_arguments: vec![Felt252::from(l1_gas.max)],
+                l1_gas,
+                steps: GasStatistics::default(),
+            },
+            used_resources: UsedResources::default(),
+            test_statistics: FuzzingStatistics {
+                runs,
+                arguments: FuzzerArgumentsStatistics::default(),
+            },
+            trace_data: (),
+            printed_output: String::new(),
+        })
+    }
+
+    #[test]
+    fn aggregates_fuzz_tests() {
+        let mut report = PackageReport::new("my_package");
+        report.add(&passed_fuzz_test(
+            "tests::a",
+            1,
+            GasStatistics {
+                min: 10,
+                max: 20,
+                mean: 15.0,
+                std_deviation: 5.0,
+            },
+        ));
+        report.add(&passed_fuzz_test(
+            "tests::b",
+            3,
+            GasStatistics {
+                min: 5,
+                max: 40,
+                mean: 25.0,
+                std_deviation: 1.0,
+            },
+        ));
+        report.add(&AnyTestCaseSummary::Fuzzing(TestCaseSummary::Failed {
+            name: "tests::c".to_string(),
+            msg: None,
+            arguments: vec![],
+            test_statistics: FuzzingStatistics {
+                runs: 10,
+                arguments: FuzzerArgumentsStatistics::default(),
+            },
+            printed_output: String::new(),
+        }));
+
+        let content = serde_json::to_value(report).unwrap();
+        assert_eq!(
+            content["fuzzing"],
+            json!({
+                "tests": 2,
+                "runs": 4,
+                "l1_gas": { "min": 5, "max": 40, "mean": 22.5 },
+                "most_expensive_run": { "test": "tests::b", "l1_gas": 40, "arguments": ["40"] }
+            })
+        );
+    }
+}
@@ -4,8 +4,7 @@ use super::{
 };
 use crate::expected_result::ExpectedTestResult;
 use cairo_lang_sierra::program::VersionedProgram;
-use serde::Deserialize;
-use std::num::NonZeroU32;
+use serde::{Deserialize, Serialize};
 
 /// these structs are representation of scarb output for `scarb build --test`
 
@@ -55,14 +54,14 @@ pub struct TestCaseRaw {
 }
 
 /// produced by scarb
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum RawForkConfig {
     Id(String),
     Params(RawForkParams),
 }
 
 /// produced by scarb
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RawForkParams {
     pub url: String,
     pub block_id_type: String,
@@ -70,8 +69,8 @@ pub struct RawForkParams {
 }
 
 /// produced by scarb
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RawFuzzerConfig {
-    pub fuzzer_runs: NonZeroU32,
+    pub fuzzer_runs: u32,
     pub fuzzer_seed: u64,
 }
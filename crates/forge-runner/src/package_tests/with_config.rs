@@ -3,14 +3,16 @@ use super::{
     TestCase, TestTarget,
 };
 use crate::expected_result::ExpectedTestResult;
+use serde::{Deserialize, Serialize};
 
 pub type TestTargetWithConfig = TestTarget<TestCaseConfig>;
 
 pub type TestCaseWithConfig = TestCase<TestCaseConfig>;
 
 /// Test case with config that has not yet been resolved
-/// see [`super::with_config_resolved::TestCaseResolvedConfig`] for more info
-#[derive(Debug, Clone)]
+/// see [`super::with_config_resolved::TestCaseResolvedConfig`] for more info.
+/// Serialized with the same fields as produced by scarb, e.g. for listing tests with `--list --json`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TestCaseConfig {
     pub available_gas: Option<usize>,
     pub ignored: bool,
@@ -18,3 +20,33 @@ pub struct TestCaseConfig {
     pub fork_config: Option<RawForkConfig>,
     pub fuzzer_config: Option<RawFuzzerConfig>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expected_result::ExpectedPanicValue;
+    use cairo_felt::Felt252;
+
+    #[test]
+    fn config_round_trip() {
+        let config = TestCaseConfig {
+            available_gas: Some(100),
+            ignored: false,
+            expected_result: ExpectedTestResult::Panics(ExpectedPanicValue::Exact(vec![
+                Felt252::from(1),
+            ])),
+            fork_config: Some(RawForkConfig::Id("mainnet".to_string())),
+            fuzzer_config: Some(RawFuzzerConfig {
+                fuzzer_runs: 10,
+                fuzzer_seed: 1,
+            }),
+        };
+
+        let serialized = serde_json::to_string(&config).unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<TestCaseConfig>(&serialized).unwrap(),
+            config
+        );
+    }
+}
@@ -11,6 +11,9 @@ pub type TestCaseWithResolvedConfig = TestCase<TestCaseResolvedConfig>;
 pub struct ResolvedForkConfig {
     pub url: Url,
     pub block_number: BlockNumber,
+    /// Read the latest state of the chain instead of the state at `block_number`,
+    /// bypassing the fork cache
+    pub live: bool,
 }
 
 /// Test case with config that has been resolved, that is
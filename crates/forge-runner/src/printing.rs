@@ -1,10 +1,77 @@
-use crate::test_case_summary::{AnyTestCaseSummary, FuzzingStatistics, TestCaseSummary};
+use crate::forge_config::GasUnit;
+use crate::gas::calculate_deployments_gas;
+use crate::test_case_summary::{
+    AnyTestCaseSummary, FuzzingStatistics, GasStatistics, TestCaseSummary,
+};
+use cairo_felt::Felt252;
+use cairo_lang_runner::casm_run::format_next_item;
+use cairo_lang_runner::short_string::as_cairo_short_string;
+use cairo_lang_utils::byte_array::BYTE_ARRAY_MAGIC;
 use cheatnet::runtime_extensions::call_to_blockifier_runtime_extension::rpc::UsedResources;
 use console::style;
+use num_bigint::BigUint;
+use std::io::Write;
 
-pub fn print_test_result(any_test_result: &AnyTestCaseSummary, print_detailed_resources: bool) {
-    if any_test_result.is_skipped() {
-        return;
+pub fn print_test_result(
+    any_test_result: &AnyTestCaseSummary,
+    print_detailed_resources: bool,
+    print_fuzzer_stats: bool,
+    gas_unit: GasUnit,
+    hide_passed_output: bool,
+    quiet: bool,
+) {
+    if let Some(test_result) = format_test_result(
+        any_test_result,
+        print_detailed_resources,
+        print_fuzzer_stats,
+        gas_unit,
+        hide_passed_output,
+        quiet,
+    ) {
+        println!("{test_result}");
+    }
+}
+
+/// Prints a single character standing for the result of the test case, without a new line.
+/// Nothing is printed for skipped test cases
+pub fn print_test_result_compact(any_test_result: &AnyTestCaseSummary) {
+    if let Some(character) = format_test_result_compact(any_test_result) {
+        print!("{character}");
+        // Characters are displayed as soon as test cases finish, not when the line ends
+        let _ = std::io::stdout().flush();
+    }
+}
+
+/// Formats the character printed by [`print_test_result_compact`], `None` for skipped test cases
+#[must_use]
+pub fn format_test_result_compact(any_test_result: &AnyTestCaseSummary) -> Option<String> {
+    let character = if any_test_result.is_passed() {
+        style(".").green()
+    } else if any_test_result.is_failed() {
+        style("F").red()
+    } else if any_test_result.is_ignored() {
+        style("i").yellow()
+    } else {
+        return None;
+    };
+    Some(character.to_string())
+}
+
+/// Explains characters printed by [`print_test_result_compact`]
+pub const COMPACT_RESULTS_LEGEND: &str = ". passed, F failed, i ignored";
+
+/// Formats the line displayed for the test case, `None` if the test case is not displayed
+#[must_use]
+pub fn format_test_result(
+    any_test_result: &AnyTestCaseSummary,
+    print_detailed_resources: bool,
+    print_fuzzer_stats: bool,
+    gas_unit: GasUnit,
+    hide_passed_output: bool,
+    quiet: bool,
+) -> Option<String> {
+    if any_test_result.is_skipped() || (quiet && !any_test_result.is_failed()) {
+        return None;
     }
     let result_header = result_header(any_test_result);
     let result_name = any_test_result.name().unwrap();
@@ -15,16 +82,28 @@ pub fn print_test_result(any_test_result: &AnyTestCaseSummary, print_detailed_re
     if let AnyTestCaseSummary::Fuzzing(test_result) = any_test_result {
         fuzzer_report = match test_result {
             TestCaseSummary::Passed {
-                test_statistics: FuzzingStatistics { runs },
+                test_statistics: FuzzingStatistics { runs, arguments },
                 gas_info,
                 ..
             } => Some(format!(
-                " (runs: {runs}, gas: {{max: ~{}, min: ~{}, mean: ~{:.2}, std deviation: ~{:.2}}})",
-                gas_info.max, gas_info.min, gas_info.mean, gas_info.std_deviation
+                " (runs: {runs}, {}{})",
+                if print_fuzzer_stats {
+                    format!(
+                        "unique arguments: {}, runs with boundary values: {}, ",
+                        arguments.unique, arguments.boundary_runs
+                    )
+                } else {
+                    String::new()
+                },
+                format_gas_usage(
+                    &format_statistics(&gas_info.l1_gas, "~"),
+                    &format_statistics(&gas_info.steps, ""),
+                    gas_unit
+                )
             )),
             TestCaseSummary::Failed {
                 arguments,
-                test_statistics: FuzzingStatistics { runs },
+                test_statistics: FuzzingStatistics { runs, .. },
                 ..
             } => Some(format!(" (runs: {runs}, arguments: {arguments:?})")),
             _ => None,
@@ -33,23 +112,88 @@ pub fn print_test_result(any_test_result: &AnyTestCaseSummary, print_detailed_re
     let fuzzer_report = fuzzer_report.unwrap_or_else(String::new);
 
     let gas_usage = match any_test_result {
-        AnyTestCaseSummary::Single(TestCaseSummary::Passed { gas_info, .. }) => {
-            format!(" (gas: ~{gas_info})")
-        }
+        AnyTestCaseSummary::Single(TestCaseSummary::Passed {
+            gas_info,
+            used_resources,
+            ..
+        }) => format!(
+            " ({})",
+            format_gas_usage(
+                &format!("~{gas_info}"),
+                &used_resources.execution_resources.n_steps.to_string(),
+                gas_unit
+            )
+        ),
         _ => String::new(),
     };
 
     let used_resources = match (print_detailed_resources, any_test_result) {
-        (true, AnyTestCaseSummary::Single(TestCaseSummary::Passed { used_resources, .. })) => {
-            format_detailed_resources(used_resources)
-        }
+        (
+            true,
+            AnyTestCaseSummary::Single(TestCaseSummary::Passed {
+                gas_info,
+                used_resources,
+                ..
+            }),
+        ) => format_detailed_resources(used_resources, *gas_info),
         _ => String::new(),
     };
 
-    println!("{result_header} {result_name}{fuzzer_report}{gas_usage}{used_resources}{result_msg}");
+    let ignore_reason = match any_test_result {
+        AnyTestCaseSummary::Single(TestCaseSummary::Ignored {
+            reason: Some(reason),
+            ..
+        })
+        | AnyTestCaseSummary::Fuzzing(TestCaseSummary::Ignored {
+            reason: Some(reason),
+            ..
+        }) => format!(" ({reason})"),
+        _ => String::new(),
+    };
+
+    let printed_output = printed_output_message(any_test_result, hide_passed_output);
+
+    Some(format!("{result_header} {result_name}{ignore_reason}{fuzzer_report}{gas_usage}{used_resources}{result_msg}{printed_output}"))
 }
 
-fn format_detailed_resources(used_resources: &UsedResources) -> String {
+fn format_gas_usage(l1_gas: &str, steps: &str, gas_unit: GasUnit) -> String {
+    let mut usages = vec![];
+    if gas_unit.includes_l1_gas() {
+        usages.push(format!("gas: {l1_gas}"));
+    }
+    if gas_unit.includes_steps() {
+        usages.push(format!("steps: {steps}"));
+    }
+    usages.join(", ")
+}
+
+/// `approximation` is prepended to minimum and maximum if the values are estimates
+fn format_statistics(statistics: &GasStatistics, approximation: &str) -> String {
+    format!(
+        "{{max: {approximation}{}, min: {approximation}{}, mean: ~{:.2}, std deviation: ~{:.2}}}",
+        statistics.max, statistics.min, statistics.mean, statistics.std_deviation
+    )
+}
+
+fn printed_output_message(
+    any_test_result: &AnyTestCaseSummary,
+    hide_passed_output: bool,
+) -> String {
+    if hide_passed_output && any_test_result.is_passed() {
+        return String::new();
+    }
+
+    match any_test_result.printed_output() {
+        Some(output) => {
+            let indented_output: String =
+                output.lines().map(|line| format!("\n    {line}")).collect();
+            format!("\n\nPrinted output:{indented_output}\n")
+        }
+        None => String::new(),
+    }
+}
+
+fn format_detailed_resources(used_resources: &UsedResources, gas: u128) -> String {
     let vm_resources = &used_resources.execution_resources;
 
     let sorted_builtins = sort_by_value(&vm_resources.builtin_instance_counter);
@@ -57,18 +201,134 @@ fn format_detailed_resources(used_resources: &UsedResources) -> String {
 
     let builtins = format_items(&sorted_builtins);
     let syscalls = format_items(&sorted_syscalls);
+    let deployments = format_deployments_split(used_resources, gas);
 
     format!(
         "
         steps: {}
         memory holes: {}
         builtins: ({})
-        syscalls: ({})
+        syscalls: ({}){}
         ",
-        vm_resources.n_steps, vm_resources.n_memory_holes, builtins, syscalls,
+        vm_resources.n_steps, vm_resources.n_memory_holes, builtins, syscalls, deployments,
+    )
+}
+
+/// Splits gas and steps between constructors of deployed contracts and the rest of the test,
+/// empty if no contract was deployed
+fn format_deployments_split(used_resources: &UsedResources, gas: u128) -> String {
+    let deployment_steps = used_resources.deployment_resources.n_steps;
+    if deployment_steps == 0 {
+        return String::new();
+    }
+    let deployments_gas = calculate_deployments_gas(used_resources);
+
+    format!(
+        "
+        deployments: (gas: ~{}, steps: {})
+        test body: (gas: ~{}, steps: {})",
+        deployments_gas,
+        deployment_steps,
+        gas.saturating_sub(deployments_gas),
+        used_resources
+            .execution_resources
+            .n_steps
+            .saturating_sub(deployment_steps),
     )
 }
 
+/// Renders the message of a failed `assert_eq!` as a diff of the compared values, e.g.
+/// ```text
+/// assertion `x == y` failed.
+/// left (x):  5
+/// right (y): 6
+/// ```
+/// Returns `None` if the panic data is not a message produced by `assert_eq!`.
+#[must_use]
+pub(crate) fn format_assert_eq_failure(panic_data: &[Felt252]) -> Option<String> {
+    let magic = Felt252::parse_bytes(BYTE_ARRAY_MAGIC.as_bytes(), 16)?;
+    if panic_data.first() != Some(&magic) {
+        return None;
+    }
+
+    let mut data_iter = panic_data.iter().cloned();
+    let message = format_next_item(&mut data_iter)?.get();
+    if data_iter.next().is_some() {
+        return None;
+    }
+
+    let lines: Vec<&str> = message.lines().collect();
+    let [header @ .., left_line, right_line] = &lines[..] else {
+        return None;
+    };
+    let compared = header
+        .first()?
+        .strip_prefix("assertion `")?
+        .split_once("` failed")?
+        .0;
+
+    // Expressions may contain ` == ` themselves, so every split is checked against the value lines
+    let (left_expr, left_value, right_expr, right_value) = compared
+        .match_indices(" == ")
+        .find_map(|(index, separator)| {
+            let left_expr = &compared[..index];
+            let right_expr = &compared[index + separator.len()..];
+            let left_value = left_line.strip_prefix(&format!("{left_expr}: "))?;
+            let right_value = right_line.strip_prefix(&format!("{right_expr}: "))?;
+            Some((left_expr, left_value, right_expr, right_value))
+        })?;
+
+    let left_label = format!("left ({left_expr}):");
+    let right_label = format!("right ({right_expr}):");
+    let width = left_label.len().max(right_label.len());
+
+    let mut result: String = header.iter().map(|line| format!("\n    {line}")).collect();
+    result.push_str(&format!(
+        "\n    {left_label:<width$} {}\n    {right_label:<width$} {}\n",
+        decode_debug_value(left_value),
+        decode_debug_value(right_value),
+    ));
+    Some(result)
+}
+
+/// Merges `u256` values printed as `u256 { low: .., high: .. }` into a single number
+/// and appends the short string a plain number encodes, if it is readable
+fn decode_debug_value(value: &str) -> String {
+    let mut decoded = String::new();
+    let mut rest = value;
+
+    while let Some(start) = rest.find("u256 { low: ") {
+        let merged = rest[start..].split_once(" }").and_then(|(u256, _)| {
+            let (low, high) = u256.strip_prefix("u256 { low: ")?.split_once(", high: ")?;
+            let value = (high.parse::<BigUint>().ok()? << 128) + low.parse::<BigUint>().ok()?;
+            Some((value, start + u256.len() + " }".len()))
+        });
+        let Some((merged, end)) = merged else {
+            break;
+        };
+
+        decoded.push_str(&rest[..start]);
+        decoded.push_str(&merged.to_string());
+        rest = &rest[end..];
+    }
+    decoded.push_str(rest);
+
+    let short_string = Felt252::parse_bytes(decoded.as_bytes(), 10)
+        .filter(|_| decoded.chars().all(|character| character.is_ascii_digit()))
+        .and_then(|felt| as_cairo_short_string(&felt))
+        .filter(|string| {
+            !string.is_empty()
+                && string
+                    .chars()
+                    .all(|character| character.is_ascii_graphic() || character == ' ')
+        });
+
+    match short_string {
+        Some(short_string) => format!("{decoded} ('{short_string}')"),
+        None => decoded,
+    }
+}
+
 fn sort_by_value<'a, K, V, M>(map: M) -> Vec<(&'a K, &'a V)>
 where
     M: IntoIterator<Item = (&'a K, &'a V)>,
@@ -115,3 +375,43 @@ fn result_header(any_test_result: &AnyTestCaseSummary) -> String {
     }
     unreachable!()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use conversions::byte_array::ByteArray;
+
+    fn panic_data(message: &str) -> Vec<Felt252> {
+        ByteArray::from(message).serialize_with_magic()
+    }
+
+    #[test]
+    fn assert_eq_failure() {
+        let result =
+            format_assert_eq_failure(&panic_data("assertion `x == y` failed.\nx: 5\ny: 6"));
+
+        assert_eq!(
+            result.unwrap(),
+            "\n    assertion `x == y` failed.\n    left (x):  5\n    right (y): 6\n"
+        );
+    }
+
+    #[test]
+    fn assert_eq_failure_with_decoded_values() {
+        let result = format_assert_eq_failure(&panic_data(
+            "assertion `a == b == true` failed: custom message\na == b: 6382179\ntrue: u256 { low: 1, high: 1 }",
+        ));
+
+        assert_eq!(
+            result.unwrap(),
+            "\n    assertion `a == b == true` failed: custom message\n    left (a == b): 6382179 ('abc')\n    right (true):  340282366920938463463374607431768211457\n"
+        );
+    }
+
+    #[test]
+    fn other_panic_data() {
+        assert!(format_assert_eq_failure(&panic_data("assertion failed: `x`.")).is_none());
+        assert!(format_assert_eq_failure(&[Felt252::from(123)]).is_none());
+        assert!(format_assert_eq_failure(&[]).is_none());
+    }
+}
@@ -0,0 +1,55 @@
+use crate::test_case_summary::AnyTestCaseSummary;
+use anyhow::{Context, Result};
+use camino::Utf8Path;
+use serde::Serialize;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+
+#[derive(Serialize)]
+struct TestResultLine<'a> {
+    name: Option<&'a str>,
+    status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    msg: Option<&'a str>,
+}
+
+/// Writes results of test cases to a file as they finish, one JSON object per line
+pub struct ResultsJsonlWriter {
+    file: File,
+}
+
+impl ResultsJsonlWriter {
+    /// Opens the results file in append mode, so results of all test targets end up in one file
+    pub fn open(path: &Utf8Path) -> Result<Self> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("Failed to open results file at {path}"))?;
+
+        Ok(Self { file })
+    }
+
+    pub fn write(&mut self, result: &AnyTestCaseSummary) -> Result<()> {
+        let line = TestResultLine {
+            name: result.name(),
+            status: status(result),
+            msg: result.msg(),
+        };
+
+        writeln!(self.file, "{}", serde_json::to_string(&line)?)
+            .context("Failed to write to results file")
+    }
+}
+
+pub(crate) fn status(result: &AnyTestCaseSummary) -> &'static str {
+    if result.is_passed() {
+        "passed"
+    } else if result.is_failed() {
+        "failed"
+    } else if result.is_ignored() {
+        "ignored"
+    } else {
+        "skipped"
+    }
+}
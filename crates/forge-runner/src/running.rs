@@ -2,21 +2,24 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::default::Default;
 use std::marker::PhantomData;
+use std::num::NonZeroUsize;
 use std::rc::Rc;
 use std::sync::Arc;
 
 use crate::build_trace_data::test_sierra_program_path::VersionedProgramPath;
+use crate::error_names::ErrorNames;
 use crate::forge_config::{RuntimeConfig, TestRunnerConfig};
+use crate::fuzzer::is_rejected_by_assume;
 use crate::gas::calculate_used_gas;
 use crate::package_tests::with_config_resolved::{ResolvedForkConfig, TestCaseWithResolvedConfig};
+use crate::storage_snapshot::{check_storage_snapshot, StorageSnapshot};
 use crate::test_case_summary::{Single, TestCaseSummary};
+use crate::vm_trace::VmTrace;
 use anyhow::{bail, ensure, Result};
 use blockifier::execution::entry_point::EntryPointExecutionContext;
 use blockifier::execution::execution_utils::ReadOnlySegments;
 use blockifier::execution::syscalls::hint_processor::SyscallHintProcessor;
-use blockifier::state::cached_state::{
-    CachedState, GlobalContractCache, GLOBAL_CONTRACT_CACHE_SIZE_FOR_TEST,
-};
+use blockifier::state::cached_state::{CachedState, GlobalContractCache};
 use blockifier::state::state_api::State;
 use cairo_felt::Felt252;
 use cairo_lang_casm::hints::Hint;
@@ -24,7 +27,9 @@ use cairo_lang_casm::instructions::Instruction;
 use cairo_lang_runner::casm_run::{
     build_cairo_runner, hint_to_hint_params, run_function_with_runner,
 };
-use cairo_lang_runner::{initialize_vm, Arg, RunResult, RunnerError, SierraCasmRunner};
+use cairo_lang_runner::{
+    initialize_vm, Arg, RunResult, RunResultValue, RunnerError, SierraCasmRunner,
+};
 use cairo_lang_sierra::extensions::segment_arena::SegmentArenaType;
 use cairo_lang_sierra::extensions::NoGenericArgsGenericType;
 use cairo_lang_sierra::ids::GenericTypeId;
@@ -39,6 +44,7 @@ use cheatnet::forking::state::ForkStateReader;
 use cheatnet::runtime_extensions::call_to_blockifier_runtime_extension::rpc::UsedResources;
 use cheatnet::runtime_extensions::call_to_blockifier_runtime_extension::CallToBlockifierExtension;
 use cheatnet::runtime_extensions::cheatable_starknet_runtime_extension::CheatableStarknetRuntimeExtension;
+use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::storage::load;
 use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
 use cheatnet::runtime_extensions::forge_runtime_extension::{
     get_all_used_resources, update_top_call_execution_resources, update_top_call_l1_resources,
@@ -55,6 +61,7 @@ use universal_sierra_compiler_api::{
 };
 
 pub fn run_test(
+    args: Vec<Felt252>,
     case: Arc<TestCaseWithResolvedConfig>,
     casm_program: Arc<AssembledProgramWithDebugInfo>,
     test_runner_config: Arc<TestRunnerConfig>,
@@ -68,8 +75,8 @@ pub fn run_test(
         if send.is_closed() {
             return Ok(TestCaseSummary::Skipped {});
         }
-        let run_result = run_test_case(
-            vec![],
+        let mut run_result = run_test_case(
+            args.clone(),
             &case,
             &casm_program,
             &RuntimeConfig::from(&test_runner_config),
@@ -82,16 +89,92 @@ pub fn run_test(
             return Ok(TestCaseSummary::Skipped {});
         }
 
-        extract_test_case_summary(
+        let storage_snapshot = run_result
+            .as_ref()
+            .map(|result_with_info| result_with_info.storage_snapshot.clone())
+            .unwrap_or_default();
+        let vm_trace = run_result
+            .as_mut()
+            .ok()
+            .and_then(|result_with_info| result_with_info.vm_trace.take());
+
+        let summary = extract_test_case_summary(
             run_result,
             &case,
-            vec![],
+            args,
             &test_runner_config.contracts_data,
             &maybe_versioned_program_path,
-        )
+            &test_runner_config.error_names,
+        )?;
+
+        let summary = compare_storage_snapshot(summary, &storage_snapshot, &test_runner_config)?;
+        maybe_save_vm_trace(&summary, vm_trace, &test_runner_config)?;
+
+        Ok(summary)
     })
 }
 
+/// Saves the VM trace of a failed test, or of any test if traces of all tests are requested
+fn maybe_save_vm_trace(
+    summary: &TestCaseSummary<Single>,
+    vm_trace: Option<VmTrace>,
+    test_runner_config: &TestRunnerConfig,
+) -> Result<()> {
+    let (Some(output), Some(vm_trace)) = (&test_runner_config.vm_trace_output, vm_trace) else {
+        return Ok(());
+    };
+    let name = match summary {
+        TestCaseSummary::Failed { name, .. } => name,
+        TestCaseSummary::Passed { name, .. } if output.all_tests => name,
+        _ => return Ok(()),
+    };
+
+    vm_trace.save(&output.dir, name)
+}
+
+/// Fails a passed test if the storage registered with `snapshot_storage` diverged from its snapshot
+fn compare_storage_snapshot(
+    summary: TestCaseSummary<Single>,
+    storage_snapshot: &StorageSnapshot,
+    test_runner_config: &TestRunnerConfig,
+) -> Result<TestCaseSummary<Single>> {
+    let TestCaseSummary::Passed {
+        name,
+        arguments,
+        printed_output,
+        ..
+    } = &summary
+    else {
+        return Ok(summary);
+    };
+    if storage_snapshot.is_empty() {
+        return Ok(summary);
+    }
+
+    match check_storage_snapshot(
+        storage_snapshot,
+        name,
+        &test_runner_config.snapshots_dir,
+        test_runner_config.update_snapshots,
+    )? {
+        Some(msg) => Ok(TestCaseSummary::Failed {
+            name: name.clone(),
+            msg: Some(msg),
+            arguments: arguments.clone(),
+            test_statistics: (),
+            printed_output: printed_output.clone(),
+        }),
+        None => Ok(summary),
+    }
+}
+
+/// Result of a single run of a fuzz test
+pub(crate) enum FuzzTestRunResult {
+    Completed(TestCaseSummary<Single>),
+    /// Arguments were rejected with `assume`, the run does not count toward fuzzer runs
+    Rejected,
+}
+
 pub(crate) fn run_fuzz_test(
     args: Vec<Felt252>,
     case: Arc<TestCaseWithResolvedConfig>,
@@ -100,16 +183,16 @@ pub(crate) fn run_fuzz_test(
     maybe_versioned_program_path: Arc<Option<VersionedProgramPath>>,
     send: Sender<()>,
     fuzzing_send: Sender<()>,
-) -> JoinHandle<Result<TestCaseSummary<Single>>> {
+) -> JoinHandle<Result<FuzzTestRunResult>> {
     tokio::task::spawn_blocking(move || {
         // Due to the inability of spawn_blocking to be abruptly cancelled,
         // a channel is used to receive information indicating
         // that the execution of the task is no longer necessary.
         if send.is_closed() | fuzzing_send.is_closed() {
-            return Ok(TestCaseSummary::Skipped {});
+            return Ok(FuzzTestRunResult::Completed(TestCaseSummary::Skipped {}));
         }
 
-        let run_result = run_test_case(
+        let mut run_result = run_test_case(
             args.clone(),
             &case,
             &casm_program,
@@ -120,19 +203,44 @@ pub(crate) fn run_fuzz_test(
         // remove it after improve exit-first tests
         // issue #1043
         if send.is_closed() {
-            return Ok(TestCaseSummary::Skipped {});
+            return Ok(FuzzTestRunResult::Completed(TestCaseSummary::Skipped {}));
         }
 
-        extract_test_case_summary(
+        if is_rejected(&run_result) {
+            return Ok(FuzzTestRunResult::Rejected);
+        }
+        let vm_trace = run_result
+            .as_mut()
+            .ok()
+            .and_then(|result_with_info| result_with_info.vm_trace.take());
+
+        let summary = extract_test_case_summary(
             run_result,
             &case,
             args,
             &test_runner_config.contracts_data,
             &maybe_versioned_program_path,
-        )
+            &test_runner_config.error_names,
+        )?;
+        maybe_save_vm_trace(&summary, vm_trace, &test_runner_config)?;
+
+        Ok(FuzzTestRunResult::Completed(summary))
     })
 }
 
+fn is_rejected(run_result: &Result<RunResultWithInfo>) -> bool {
+    matches!(
+        run_result,
+        Ok(RunResultWithInfo {
+            run_result: Ok(RunResult {
+                value: RunResultValue::Panic(panic_data),
+                ..
+            }),
+            ..
+        }) if is_rejected_by_assume(panic_data)
+    )
+}
+
 fn get_syscall_segment_index(test_param_types: &[(GenericTypeId, i16)]) -> isize {
     // Segment arena is allocated conditionally, so segment index is automatically moved (+2 segments)
     if test_param_types
@@ -173,6 +281,9 @@ pub struct RunResultWithInfo {
     pub(crate) call_trace: Rc<RefCell<CallTrace>>,
     pub(crate) gas_used: u128,
     pub(crate) used_resources: UsedResources,
+    pub(crate) printed_output: String,
+    pub(crate) storage_snapshot: StorageSnapshot,
+    pub(crate) vm_trace: Option<VmTrace>,
 }
 
 #[allow(clippy::too_many_lines)]
@@ -216,7 +327,8 @@ pub fn run_test_case(
             &case.config.fork_config,
         )?,
     };
-    let block_info = state_reader.get_block_info()?;
+    let mut block_info = state_reader.get_block_info()?;
+    runtime_config.block_info_overrides.apply(&mut block_info);
 
     let mut context = build_context(&block_info);
 
@@ -226,7 +338,7 @@ pub fn run_test_case(
     let mut execution_resources = ExecutionResources::default();
     let mut cached_state = CachedState::new(
         state_reader,
-        GlobalContractCache::new(GLOBAL_CONTRACT_CACHE_SIZE_FOR_TEST),
+        build_contract_class_cache(runtime_config.contract_cache_size),
     );
     let syscall_handler = build_syscall_handler(
         &mut cached_state,
@@ -238,6 +350,7 @@ pub fn run_test_case(
 
     let mut cheatnet_state = CheatnetState {
         block_info,
+        captured_output: Some(String::new()),
         ..Default::default()
     };
     cheatnet_state.trace_data.is_vm_trace_needed = runtime_config.is_vm_trace_needed;
@@ -278,6 +391,7 @@ pub fn run_test_case(
     let data_len = data.len();
     let mut runner = build_cairo_runner(data, builtins, hints_dict)?;
 
+    let mut vm_trace = None;
     let run_result = match run_function_with_runner(
         &mut vm,
         data_len,
@@ -316,6 +430,13 @@ pub fn run_test_case(
 
             update_top_call_vm_trace(&mut forge_runtime, &vm);
 
+            if runtime_config.save_vm_trace {
+                vm_trace = Some(VmTrace {
+                    trace: vm.get_relocated_trace().unwrap().clone(),
+                    memory: cells.clone(),
+                });
+            }
+
             Ok(RunResult {
                 gas_counter,
                 memory: cells,
@@ -323,7 +444,16 @@ pub fn run_test_case(
                 profiling_info: None,
             })
         }
-        Err(err) => Err(RunnerError::CairoRunError(err)),
+        Err(err) => {
+            // Trace of the run which failed with an error is relocated only when it is saved
+            if runtime_config.save_vm_trace && runner.relocate(&mut vm, true).is_ok() {
+                vm_trace = Some(VmTrace {
+                    trace: vm.get_relocated_trace().unwrap().clone(),
+                    memory: std::mem::take(&mut runner.relocated_memory),
+                });
+            }
+            Err(RunnerError::CairoRunError(err))
+        }
     };
 
     let call_trace_ref = get_call_trace_ref(&mut forge_runtime);
@@ -338,11 +468,20 @@ pub fn run_test_case(
         used_resources.clone(),
     )?;
 
+    let mut storage_snapshot = StorageSnapshot::default();
+    for (contract_address, storage_address) in &cheatnet_state.storage_snapshot_slots {
+        let value = load(&mut cached_state, *contract_address, storage_address)?;
+        storage_snapshot.insert(*contract_address, storage_address.clone(), value);
+    }
+
     Ok(RunResultWithInfo {
         run_result,
         gas_used: gas,
         used_resources,
         call_trace: call_trace_ref,
+        printed_output: cheatnet_state.captured_output.unwrap_or_default(),
+        storage_snapshot,
+        vm_trace,
     })
 }
 
@@ -359,6 +498,7 @@ fn extract_test_case_summary(
     args: Vec<Felt252>,
     contracts_data: &ContractsData,
     maybe_versioned_program_path: &Option<VersionedProgramPath>,
+    error_names: &ErrorNames,
 ) -> Result<TestCaseSummary<Single>> {
     match run_result {
         Ok(result_with_info) => {
@@ -372,6 +512,8 @@ fn extract_test_case_summary(
                     &result_with_info.call_trace,
                     contracts_data,
                     maybe_versioned_program_path,
+                    result_with_info.printed_output,
+                    error_names,
                 )),
                 // CairoRunError comes from VirtualMachineError which may come from HintException that originates in TestExecutionSyscallHandler
                 Err(RunnerError::CairoRunError(error)) => Ok(TestCaseSummary::Failed {
@@ -382,6 +524,7 @@ fn extract_test_case_summary(
                     )),
                     arguments: args,
                     test_statistics: (),
+                    printed_output: result_with_info.printed_output,
                 }),
                 Err(err) => bail!(err),
             }
@@ -393,6 +536,7 @@ fn extract_test_case_summary(
             msg: Some(error.to_string()),
             arguments: args,
             test_statistics: (),
+            printed_output: String::new(),
         }),
     }
 }
@@ -403,9 +547,19 @@ fn get_fork_state_reader(
 ) -> Result<Option<ForkStateReader>> {
     fork_config
         .as_ref()
-        .map(|ResolvedForkConfig { url, block_number }| {
-            ForkStateReader::new(url.clone(), *block_number, cache_dir)
-        })
+        .map(
+            |ResolvedForkConfig {
+                 url,
+                 block_number,
+                 live,
+             }| {
+                if *live {
+                    Ok(ForkStateReader::new_live(url.clone(), *block_number))
+                } else {
+                    ForkStateReader::new(url.clone(), *block_number, cache_dir)
+                }
+            },
+        )
         .transpose()
 }
 
@@ -484,3 +638,40 @@ fn create_hints_dict(
 
     (string_to_hint, hints_dict)
 }
+
+fn build_contract_class_cache(contract_cache_size: NonZeroUsize) -> GlobalContractCache {
+    GlobalContractCache::new(contract_cache_size.get())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use blockifier::execution::contract_class::{ContractClass, ContractClassV0};
+    use cached::Cached;
+    use starknet_api::core::ClassHash;
+    use starknet_api::hash::StarkFelt;
+
+    fn cache_hits(contract_cache_size: usize, requested_classes: &[ClassHash]) -> u64 {
+        let mut contract_class_cache =
+            build_contract_class_cache(NonZeroUsize::new(contract_cache_size).unwrap());
+        let mut cache = contract_class_cache.lock();
+
+        for class_hash in requested_classes {
+            if cache.cache_get(class_hash).is_none() {
+                cache.cache_set(*class_hash, ContractClass::V0(ContractClassV0::default()));
+            }
+        }
+
+        cache.cache_hits().unwrap()
+    }
+
+    #[test]
+    fn contract_class_cache_keeps_configured_number_of_classes() {
+        let first = ClassHash(StarkFelt::from(1_u8));
+        let second = ClassHash(StarkFelt::from(2_u8));
+        let requested_classes = [first, second, first, second];
+
+        assert_eq!(cache_hits(1, &requested_classes), 0);
+        assert_eq!(cache_hits(2, &requested_classes), 2);
+    }
+}
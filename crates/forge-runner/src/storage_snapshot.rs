@@ -0,0 +1,157 @@
+use anyhow::{Context, Result};
+use cairo_felt::Felt252;
+use camino::{Utf8Path, Utf8PathBuf};
+use conversions::string::IntoHexStr;
+use serde::{Deserialize, Serialize};
+use starknet_api::core::ContractAddress;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Values of storage slots registered with `snapshot_storage`,
+/// by the contract address and the storage address
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StorageSnapshot(BTreeMap<String, BTreeMap<String, String>>);
+
+impl StorageSnapshot {
+    pub(crate) fn insert(
+        &mut self,
+        contract_address: ContractAddress,
+        storage_address: Felt252,
+        value: Felt252,
+    ) {
+        self.0
+            .entry(contract_address.into_hex_string())
+            .or_default()
+            .insert(storage_address.into_hex_string(), value.into_hex_string());
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Slots whose values differ from the `expected` snapshot, one line per slot
+    fn diff(&self, expected: &Self) -> Vec<String> {
+        let slots: BTreeSet<(&String, &String)> = self
+            .slots()
+            .chain(expected.slots())
+            .map(|(contract, storage_address, _)| (contract, storage_address))
+            .collect();
+
+        slots
+            .into_iter()
+            .filter_map(|(contract, storage_address)| {
+                let actual = self.value(contract, storage_address);
+                let expected = expected.value(contract, storage_address);
+                (actual != expected).then(|| {
+                    format!(
+                        "contract {contract}, storage address {storage_address}: expected {}, got {}",
+                        expected.map_or("no value", String::as_str),
+                        actual.map_or("no value", String::as_str),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    fn slots(&self) -> impl Iterator<Item = (&String, &String, &String)> {
+        self.0.iter().flat_map(|(contract, values)| {
+            values
+                .iter()
+                .map(move |(storage_address, value)| (contract, storage_address, value))
+        })
+    }
+
+    fn value(&self, contract: &str, storage_address: &str) -> Option<&String> {
+        self.0.get(contract)?.get(storage_address)
+    }
+}
+
+fn snapshot_path(snapshots_dir: &Utf8Path, test_name: &str) -> Utf8PathBuf {
+    snapshots_dir.join(format!("{}.json", test_name.replace("::", ".")))
+}
+
+/// Compares the storage snapshot taken at the end of a test with the one saved for the test.
+/// The saved snapshot is created if it does not exist yet, or overwritten if `update` is set.
+/// Returns the failure message if the snapshots diverge.
+pub(crate) fn check_storage_snapshot(
+    snapshot: &StorageSnapshot,
+    test_name: &str,
+    snapshots_dir: &Utf8Path,
+    update: bool,
+) -> Result<Option<String>> {
+    let path = snapshot_path(snapshots_dir, test_name);
+
+    if path.exists() && !update {
+        let saved = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read storage snapshot = {path}"))?;
+        let saved: StorageSnapshot = serde_json::from_str(&saved)
+            .with_context(|| format!("Failed to parse storage snapshot = {path}"))?;
+
+        let diff = snapshot.diff(&saved);
+        if diff.is_empty() {
+            return Ok(None);
+        }
+        let diff: String = diff.iter().map(|line| format!("\n    {line}")).collect();
+        return Ok(Some(format!(
+            "\n    Storage does not match the snapshot {path}:{diff}\n    Run with --update-snapshots to update the snapshot\n"
+        )));
+    }
+
+    std::fs::create_dir_all(snapshots_dir)
+        .with_context(|| format!("Failed to create snapshots directory = {snapshots_dir}"))?;
+    std::fs::write(&path, serde_json::to_string_pretty(snapshot)?)
+        .with_context(|| format!("Failed to write storage snapshot = {path}"))?;
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use starknet_api::core::PatriciaKey;
+    use starknet_api::hash::StarkHash;
+    use starknet_api::{contract_address, patricia_key};
+
+    fn snapshot(values: &[(u64, u64)]) -> StorageSnapshot {
+        let mut snapshot = StorageSnapshot::default();
+        for (storage_address, value) in values {
+            snapshot.insert(
+                contract_address!("0x123"),
+                Felt252::from(*storage_address),
+                Felt252::from(*value),
+            );
+        }
+        snapshot
+    }
+
+    #[test]
+    fn same_snapshots() {
+        assert!(snapshot(&[(1, 2), (3, 4)])
+            .diff(&snapshot(&[(1, 2), (3, 4)]))
+            .is_empty());
+    }
+
+    #[test]
+    fn diverged_snapshots() {
+        let diff = snapshot(&[(1, 2), (3, 5)]).diff(&snapshot(&[(1, 2), (3, 4), (6, 7)]));
+
+        assert_eq!(
+            diff,
+            vec![
+                "contract 0x123, storage address 0x3: expected 0x4, got 0x5",
+                "contract 0x123, storage address 0x6: expected 0x7, got no value",
+            ]
+        );
+    }
+
+    #[test]
+    fn snapshot_file_name() {
+        assert_eq!(
+            snapshot_path(
+                Utf8Path::new("snapshots"),
+                "tests::test_contract::test_store"
+            ),
+            Utf8PathBuf::from("snapshots/tests.test_contract.test_store.json")
+        );
+    }
+}
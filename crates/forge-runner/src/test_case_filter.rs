@@ -0,0 +1,146 @@
+use crate::package_tests::with_config_resolved::TestCaseWithResolvedConfig;
+use crate::TestCaseFilter;
+
+pub type BoxedTestCaseFilter<'a> = Box<dyn TestCaseFilter + 'a>;
+
+impl<T: TestCaseFilter + ?Sized> TestCaseFilter for &T {
+    fn should_be_run(&self, test_case: &TestCaseWithResolvedConfig) -> bool {
+        (**self).should_be_run(test_case)
+    }
+}
+
+/// Runs a test case if all of the filters run it, or if there are no filters
+pub struct And<'a>(pub Vec<BoxedTestCaseFilter<'a>>);
+
+impl TestCaseFilter for And<'_> {
+    fn should_be_run(&self, test_case: &TestCaseWithResolvedConfig) -> bool {
+        self.0.iter().all(|filter| filter.should_be_run(test_case))
+    }
+}
+
+/// Runs a test case if any of the filters runs it
+pub struct Or<'a>(pub Vec<BoxedTestCaseFilter<'a>>);
+
+impl TestCaseFilter for Or<'_> {
+    fn should_be_run(&self, test_case: &TestCaseWithResolvedConfig) -> bool {
+        self.0.iter().any(|filter| filter.should_be_run(test_case))
+    }
+}
+
+/// Runs a test case if the filter does not run it
+pub struct Not<'a>(pub BoxedTestCaseFilter<'a>);
+
+impl TestCaseFilter for Not<'_> {
+    fn should_be_run(&self, test_case: &TestCaseWithResolvedConfig) -> bool {
+        !self.0.should_be_run(test_case)
+    }
+}
+
+/// Runs a test case if the predicate returns `true` for it
+pub struct Predicate<F>(pub F);
+
+impl<F> TestCaseFilter for Predicate<F>
+where
+    F: Fn(&TestCaseWithResolvedConfig) -> bool,
+{
+    fn should_be_run(&self, test_case: &TestCaseWithResolvedConfig) -> bool {
+        (self.0)(test_case)
+    }
+}
+
+/// Creates a filter running test cases for which all of the predicates return `true`
+pub fn from_predicates<'a>(
+    predicates: Vec<Box<dyn Fn(&TestCaseWithResolvedConfig) -> bool + 'a>>,
+) -> And<'a> {
+    And(predicates
+        .into_iter()
+        .map(|predicate| Box::new(Predicate(predicate)) as BoxedTestCaseFilter)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expected_result::ExpectedTestResult;
+    use crate::package_tests::with_config_resolved::TestCaseResolvedConfig;
+    use crate::package_tests::{TestCase, TestDetails};
+
+    fn test_case(name: &str, ignored: bool) -> TestCaseWithResolvedConfig {
+        TestCase {
+            test_details: TestDetails::default(),
+            name: name.to_string(),
+            config: TestCaseResolvedConfig {
+                available_gas: None,
+                ignored,
+                expected_result: ExpectedTestResult::Success,
+                fork_config: None,
+                fuzzer_config: None,
+            },
+        }
+    }
+
+    fn name_contains(pattern: &'static str) -> BoxedTestCaseFilter<'static> {
+        Box::new(Predicate(move |test_case: &TestCaseWithResolvedConfig| {
+            test_case.name.contains(pattern)
+        }))
+    }
+
+    #[test]
+    fn empty_combinators() {
+        let case = test_case("crate::test", false);
+
+        assert!(And(vec![]).should_be_run(&case));
+        assert!(!Or(vec![]).should_be_run(&case));
+    }
+
+    #[test]
+    fn and_of_or_and_not() {
+        // (contains "a" or contains "b") and not contains "skip"
+        let filter = And(vec![
+            Box::new(Or(vec![name_contains("a"), name_contains("b")])),
+            Box::new(Not(name_contains("skip"))),
+        ]);
+
+        assert!(filter.should_be_run(&test_case("test_a", false)));
+        assert!(filter.should_be_run(&test_case("test_b", false)));
+        assert!(!filter.should_be_run(&test_case("test_c", false)));
+        assert!(!filter.should_be_run(&test_case("test_a_skip", false)));
+    }
+
+    #[test]
+    fn or_of_and_and_not() {
+        // (contains "a" and contains "b") or not contains "test"
+        let filter = Or(vec![
+            Box::new(And(vec![name_contains("a"), name_contains("b")])),
+            Box::new(Not(name_contains("test"))),
+        ]);
+
+        assert!(filter.should_be_run(&test_case("test_a_b", false)));
+        assert!(!filter.should_be_run(&test_case("test_a", false)));
+        assert!(filter.should_be_run(&test_case("other", false)));
+    }
+
+    #[test]
+    fn predicates() {
+        let filter = from_predicates(vec![
+            Box::new(|test_case: &TestCaseWithResolvedConfig| {
+                test_case.name.starts_with("crate::")
+            }),
+            Box::new(|test_case: &TestCaseWithResolvedConfig| !test_case.config.ignored),
+        ]);
+
+        assert!(filter.should_be_run(&test_case("crate::test", false)));
+        assert!(!filter.should_be_run(&test_case("crate::test", true)));
+        assert!(!filter.should_be_run(&test_case("other::test", false)));
+    }
+
+    #[test]
+    fn borrowed_filter() {
+        let not_ignored =
+            Predicate(|test_case: &TestCaseWithResolvedConfig| !test_case.config.ignored);
+        let filter = Not(Box::new(&not_ignored));
+
+        assert!(filter.should_be_run(&test_case("crate::test", true)));
+        assert!(!filter.should_be_run(&test_case("crate::test", false)));
+    }
+}
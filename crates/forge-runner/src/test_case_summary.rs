@@ -1,8 +1,10 @@
 use crate::build_trace_data::build_profiler_call_trace;
 use crate::build_trace_data::test_sierra_program_path::VersionedProgramPath;
+use crate::error_names::ErrorNames;
 use crate::expected_result::{ExpectedPanicValue, ExpectedTestResult};
 use crate::gas::check_available_gas;
 use crate::package_tests::with_config_resolved::TestCaseWithResolvedConfig;
+use crate::printing::format_assert_eq_failure;
 use cairo_felt::Felt252;
 use cairo_lang_runner::short_string::as_cairo_short_string;
 use cairo_lang_runner::{RunResult, RunResultValue};
@@ -53,9 +55,28 @@ impl GasStatistics {
     }
 }
 
+/// Statistics of resources used by all runs of a passed fuzz test
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct FuzzingGasInfo {
+    pub l1_gas: GasStatistics,
+    pub steps: GasStatistics,
+    /// Arguments of the run which used the most L1 gas
+    pub max_l1_gas_arguments: Vec<Felt252>,
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct FuzzingStatistics {
     pub runs: usize,
+    pub arguments: FuzzerArgumentsStatistics,
+}
+
+/// Statistics of arguments generated by the fuzzer for all runs of a fuzz test
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct FuzzerArgumentsStatistics {
+    /// Number of distinct sets of arguments
+    pub unique: usize,
+    /// Number of runs in which at least one argument has its minimal or maximal value
+    pub boundary_runs: usize,
 }
 
 pub trait TestType {
@@ -67,7 +88,7 @@ pub trait TestType {
 #[derive(Debug, PartialEq, Clone)]
 pub struct Fuzzing;
 impl TestType for Fuzzing {
-    type GasInfo = GasStatistics;
+    type GasInfo = FuzzingGasInfo;
     type TestStatistics = FuzzingStatistics;
     type TraceData = ();
 }
@@ -99,6 +120,8 @@ pub enum TestCaseSummary<T: TestType> {
         test_statistics: <T as TestType>::TestStatistics,
         /// Test trace data
         trace_data: <T as TestType>::TraceData,
+        /// Output printed during the test run
+        printed_output: String,
     },
     /// Test case failed
     Failed {
@@ -110,11 +133,16 @@ pub enum TestCaseSummary<T: TestType> {
         arguments: Vec<Felt252>,
         /// Statistics of the test run
         test_statistics: <T as TestType>::TestStatistics,
+        /// Output printed during the test run
+        printed_output: String,
     },
-    /// Test case ignored due to `#[ignored]` attribute or `--ignored` flag
+    /// Test case ignored due to `#[ignored]` attribute, `--ignored` or `--skip-forked` flag
     Ignored {
         /// Name of the test case
         name: String,
+        /// Why the test case was excluded by a filter, `None` if it is marked with `#[ignored]` attribute.
+        /// Test cases with a reason are counted as filtered out instead of ignored
+        reason: Option<String>,
     },
     /// Test case skipped due to exit first or execution interrupted, test result is ignored.
     Skipped {},
@@ -146,61 +174,156 @@ impl<T: TestType> TestCaseSummary<T> {
             _ => None,
         }
     }
+
+    #[must_use]
+    pub fn printed_output(&self) -> Option<&str> {
+        match self {
+            TestCaseSummary::Failed { printed_output, .. }
+            | TestCaseSummary::Passed { printed_output, .. }
+                if !printed_output.is_empty() =>
+            {
+                Some(printed_output)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Numbers of runs of a fuzz test with each result. Runs are executed in parallel and complete in any order,
+/// so the result of the fuzz test is decided from these numbers rather than from the order of the results
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+struct FuzzingRunsTally {
+    passed: usize,
+    failed: usize,
+    /// Runs which were not completed, e.g. because another test failed with `--exit-first`
+    skipped: usize,
+}
+
+#[derive(Debug, PartialEq)]
+enum FuzzingOutcome {
+    Passed,
+    Failed,
+    Skipped,
+}
+
+impl FuzzingRunsTally {
+    fn of(results: &[TestCaseSummary<Single>]) -> Self {
+        let mut tally = Self::default();
+        for result in results {
+            match result {
+                TestCaseSummary::Passed { .. } => tally.passed += 1,
+                TestCaseSummary::Failed { .. } => tally.failed += 1,
+                _ => tally.skipped += 1,
+            }
+        }
+        tally
+    }
+
+    /// The test fails if any run failed, and passes only if all `fuzzer_runs` runs passed
+    fn outcome(self, fuzzer_runs: usize) -> FuzzingOutcome {
+        if self.failed > 0 {
+            FuzzingOutcome::Failed
+        } else if self.passed == fuzzer_runs && self.skipped == 0 {
+            FuzzingOutcome::Passed
+        } else {
+            FuzzingOutcome::Skipped
+        }
+    }
 }
 
 impl TestCaseSummary<Fuzzing> {
+    /// Aggregates results of runs of a fuzz test, which may be in any order of completion
     #[must_use]
-    pub fn from(results: Vec<TestCaseSummary<Single>>) -> Self {
-        let last: TestCaseSummary<Single> = results
-            .iter()
-            .last()
-            .cloned()
-            .expect("Fuzz test should always run at least once");
-        // Only the last result matters as fuzzing is cancelled after first fail
-        match last {
-            TestCaseSummary::Passed {
-                name,
-                msg,
-                arguments,
-                gas_info: _,
-                used_resources: _,
-                test_statistics: (),
-                trace_data: _,
-            } => {
-                let runs = results.len();
-                let gas_usages: Vec<u128> = results
+    pub fn from(
+        results: Vec<TestCaseSummary<Single>>,
+        fuzzer_runs: usize,
+        arguments_statistics: FuzzerArgumentsStatistics,
+    ) -> Self {
+        let tally = FuzzingRunsTally::of(&results);
+        let runs = tally.passed + tally.failed;
+
+        match tally.outcome(fuzzer_runs) {
+            FuzzingOutcome::Failed => {
+                // Fuzzing is cancelled after the first failed run
+                let Some(TestCaseSummary::Failed {
+                    name,
+                    msg,
+                    arguments,
+                    test_statistics: (),
+                    printed_output,
+                }) = results
+                    .into_iter()
+                    .find(|result| matches!(result, TestCaseSummary::Failed { .. }))
+                else {
+                    unreachable!("Tally of the runs contains a failed run")
+                };
+
+                TestCaseSummary::Failed {
+                    name,
+                    msg,
+                    arguments,
+                    test_statistics: FuzzingStatistics {
+                        runs,
+                        arguments: arguments_statistics,
+                    },
+                    printed_output,
+                }
+            }
+            FuzzingOutcome::Passed => {
+                let max_l1_gas_arguments = results
+                    .iter()
+                    .filter_map(|result| match result {
+                        TestCaseSummary::Passed {
+                            arguments,
+                            gas_info,
+                            ..
+                        } => Some((gas_info, arguments)),
+                        _ => None,
+                    })
+                    .max_by_key(|(gas_info, _)| **gas_info)
+                    .map(|(_, arguments)| arguments.clone())
+                    .unwrap_or_default();
+                let Some(TestCaseSummary::Passed {
+                    name,
+                    msg,
+                    arguments,
+                    printed_output,
+                    ..
+                }) = results.last().cloned()
+                else {
+                    unreachable!("All runs passed")
+                };
+                let (gas_usages, steps_usages): (Vec<u128>, Vec<u128>) = results
                     .into_iter()
                     .map(|a| match a {
-                        TestCaseSummary::Passed { gas_info, .. } => gas_info,
+                        TestCaseSummary::Passed {
+                            gas_info,
+                            used_resources,
+                            ..
+                        } => (gas_info, used_resources.execution_resources.n_steps as u128),
                         _ => unreachable!(),
                     })
-                    .collect();
+                    .unzip();
 
                 TestCaseSummary::Passed {
                     name,
                     msg,
                     arguments,
-                    gas_info: GasStatistics::new(&gas_usages),
+                    gas_info: FuzzingGasInfo {
+                        l1_gas: GasStatistics::new(&gas_usages),
+                        steps: GasStatistics::new(&steps_usages),
+                        max_l1_gas_arguments,
+                    },
                     used_resources: UsedResources::default(),
-                    test_statistics: FuzzingStatistics { runs },
+                    test_statistics: FuzzingStatistics {
+                        runs,
+                        arguments: arguments_statistics,
+                    },
                     trace_data: (),
+                    printed_output,
                 }
             }
-            TestCaseSummary::Failed {
-                name,
-                msg,
-                arguments,
-                test_statistics: (),
-            } => TestCaseSummary::Failed {
-                name,
-                msg,
-                arguments,
-                test_statistics: FuzzingStatistics {
-                    runs: results.len(),
-                },
-            },
-            TestCaseSummary::Ignored { name } => TestCaseSummary::Ignored { name: name.clone() },
-            TestCaseSummary::Skipped {} => TestCaseSummary::Skipped {},
+            FuzzingOutcome::Skipped => TestCaseSummary::Skipped {},
         }
     }
 }
@@ -217,9 +340,11 @@ impl TestCaseSummary<Single> {
         call_trace: &Rc<RefCell<InternalCallTrace>>,
         contracts_data: &ContractsData,
         maybe_versioned_program_path: &Option<VersionedProgramPath>,
+        printed_output: String,
+        error_names: &ErrorNames,
     ) -> Self {
         let name = test_case.name.clone();
-        let msg = extract_result_data(&run_result, &test_case.config.expected_result);
+        let msg = extract_result_data(&run_result, &test_case.config.expected_result, error_names);
         match run_result.value {
             RunResultValue::Success(_) => match &test_case.config.expected_result {
                 ExpectedTestResult::Success => {
@@ -235,6 +360,7 @@ impl TestCaseSummary<Single> {
                             contracts_data,
                             maybe_versioned_program_path,
                         ),
+                        printed_output,
                     };
                     check_available_gas(&test_case.config.available_gas, summary)
                 }
@@ -243,6 +369,7 @@ impl TestCaseSummary<Single> {
                     msg,
                     arguments,
                     test_statistics: (),
+                    printed_output,
                 },
             },
             RunResultValue::Panic(value) => match &test_case.config.expected_result {
@@ -251,6 +378,7 @@ impl TestCaseSummary<Single> {
                     msg,
                     arguments,
                     test_statistics: (),
+                    printed_output,
                 },
                 ExpectedTestResult::Panics(panic_expectation) => match panic_expectation {
                     ExpectedPanicValue::Exact(expected) if &value != expected => {
@@ -259,8 +387,21 @@ impl TestCaseSummary<Single> {
                             msg,
                             arguments,
                             test_statistics: (),
+                            printed_output,
                         }
                     }
+                    // Running out of gas satisfies only an expectation of the `Out of gas` panic
+                    ExpectedPanicValue::Any if is_out_of_gas(&value) => TestCaseSummary::Failed {
+                        name,
+                        msg: Some(
+                            "\n    Expected to panic but ran out of gas instead\n    Use `#[should_panic(expected: 'Out of gas')]` if running out of gas is expected\n"
+                                .into(),
+                        ),
+                        arguments,
+                        test_statistics: (),
+                        printed_output,
+                    },
+                    // `available_gas` is not checked, the test panicked as expected
                     _ => TestCaseSummary::Passed {
                         name,
                         msg,
@@ -273,6 +414,7 @@ impl TestCaseSummary<Single> {
                             contracts_data,
                             maybe_versioned_program_path,
                         ),
+                        printed_output,
                     },
                 },
             },
@@ -280,9 +422,18 @@ impl TestCaseSummary<Single> {
     }
 }
 
-fn join_short_strings(data: &[Felt252]) -> String {
+/// Whether the test ran out of gas, possibly in a call whose failure propagated to the test
+fn is_out_of_gas(panic_data: &[Felt252]) -> bool {
+    panic_data.first() == Some(&Felt252::from_bytes_be(b"Out of gas"))
+}
+
+/// Short strings encoded by the felts, or names of errors which the felts encode
+fn join_short_strings(data: &[Felt252], error_names: &ErrorNames) -> String {
     data.iter()
-        .map(|felt| as_cairo_short_string(felt).unwrap_or_default())
+        .map(|felt| match error_names.name(felt) {
+            Some(name) => name.to_string(),
+            None => as_cairo_short_string(felt).unwrap_or_default(),
+        })
         .collect::<Vec<String>>()
         .join(", ")
 }
@@ -291,12 +442,16 @@ fn join_short_strings(data: &[Felt252]) -> String {
 /// If the test was expected to fail with specific data e.g. `#[should_panic(expected: ('data',))]`
 /// and failed to do so, it returns a string comparing the panic data and the expected data.
 #[must_use]
-fn extract_result_data(run_result: &RunResult, expectation: &ExpectedTestResult) -> Option<String> {
+fn extract_result_data(
+    run_result: &RunResult,
+    expectation: &ExpectedTestResult,
+    error_names: &ErrorNames,
+) -> Option<String> {
     match &run_result.value {
         RunResultValue::Success(data) => match expectation {
             ExpectedTestResult::Panics(panic_expectation) => match panic_expectation {
                 ExpectedPanicValue::Exact(panic_data) => {
-                    let panic_string = join_short_strings(panic_data);
+                    let panic_string = join_short_strings(panic_data, error_names);
 
                     Some(format!(
                         "\n    Expected to panic but didn't\n    Expected panic data:  {panic_data:?} ({panic_string})\n"
@@ -318,8 +473,8 @@ fn extract_result_data(run_result: &RunResult, expectation: &ExpectedTestResult)
             match expected_data {
                 Some(expected) if expected == panic_data => None,
                 Some(expected) => {
-                    let panic_string = join_short_strings(panic_data);
-                    let expected_string = join_short_strings(expected);
+                    let panic_string = join_short_strings(panic_data, error_names);
+                    let expected_string = join_short_strings(expected, error_names);
 
                     Some(format!(
                         "\n    Incorrect panic data\n    {}\n    {}\n",
@@ -327,7 +482,9 @@ fn extract_result_data(run_result: &RunResult, expectation: &ExpectedTestResult)
                         format_args!("Expected:  {expected:?} ({expected_string})")
                     ))
                 }
-                None => build_readable_text(panic_data),
+                None => format_assert_eq_failure(panic_data)
+                    .or_else(|| error_names.format_panic_data(panic_data))
+                    .or_else(|| build_readable_text(panic_data)),
             }
         }
     }
@@ -350,6 +507,14 @@ impl AnyTestCaseSummary {
         }
     }
 
+    #[must_use]
+    pub fn printed_output(&self) -> Option<&str> {
+        match self {
+            AnyTestCaseSummary::Fuzzing(case) => case.printed_output(),
+            AnyTestCaseSummary::Single(case) => case.printed_output(),
+        }
+    }
+
     #[must_use]
     pub fn is_passed(&self) -> bool {
         matches!(
@@ -385,4 +550,82 @@ impl AnyTestCaseSummary {
                 | AnyTestCaseSummary::Fuzzing(TestCaseSummary::Ignored { .. })
         )
     }
+
+    /// Whether the test case was ignored with a reason, because it was excluded by a filter
+    /// selected with a flag rather than marked with `#[ignore]` attribute
+    #[must_use]
+    pub fn is_filtered(&self) -> bool {
+        matches!(
+            self,
+            AnyTestCaseSummary::Single(TestCaseSummary::Ignored {
+                reason: Some(_),
+                ..
+            }) | AnyTestCaseSummary::Fuzzing(TestCaseSummary::Ignored {
+                reason: Some(_),
+                ..
+            })
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+
+    fn failed_run() -> TestCaseSummary<Single> {
+        TestCaseSummary::Failed {
+            name: "fuzz".to_string(),
+            msg: None,
+            arguments: vec![Felt252::from(1)],
+            test_statistics: (),
+            printed_output: String::new(),
+        }
+    }
+
+    #[test]
+    fn tally_does_not_depend_on_order_of_runs() {
+        let results = [
+            failed_run(),
+            TestCaseSummary::Skipped {},
+            TestCaseSummary::Skipped {},
+        ];
+
+        for permutation in results.iter().cloned().permutations(results.len()) {
+            let tally = FuzzingRunsTally::of(&permutation);
+
+            assert_eq!(
+                tally,
+                FuzzingRunsTally {
+                    passed: 0,
+                    failed: 1,
+                    skipped: 2,
+                }
+            );
+            assert_eq!(tally.outcome(3), FuzzingOutcome::Failed);
+
+            let summary = TestCaseSummary::<Fuzzing>::from(
+                permutation,
+                3,
+                FuzzerArgumentsStatistics::default(),
+            );
+            assert!(
+                matches!(summary, TestCaseSummary::Failed { test_statistics, .. } if test_statistics.runs == 1)
+            );
+        }
+    }
+
+    #[test]
+    fn passes_only_if_all_runs_passed() {
+        let tally = |passed, skipped| FuzzingRunsTally {
+            passed,
+            failed: 0,
+            skipped,
+        };
+
+        assert_eq!(tally(3, 0).outcome(3), FuzzingOutcome::Passed);
+        assert_eq!(tally(2, 1).outcome(3), FuzzingOutcome::Skipped);
+        assert_eq!(tally(2, 0).outcome(3), FuzzingOutcome::Skipped);
+        assert_eq!(tally(0, 0).outcome(3), FuzzingOutcome::Skipped);
+    }
 }
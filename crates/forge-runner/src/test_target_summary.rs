@@ -1,42 +1,225 @@
-use crate::test_case_summary::AnyTestCaseSummary;
+use crate::forge_config::TestSummaryMode;
+use crate::test_case_summary::{AnyTestCaseSummary, TestCaseSummary};
+
+/// Counts of test cases results in the file
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct TestCaseCounts {
+    pub passed: usize,
+    pub failed: usize,
+    pub skipped: usize,
+    /// Number of test cases marked with `#[ignore]` attribute which were not run
+    pub ignored: usize,
+    /// Number of test cases excluded by a filter selected with a flag, e.g. `--skip-forked`,
+    /// which are reported as ignored with a reason
+    pub filtered: usize,
+    /// Number of fuzz tests that were actually run (either passed or failed)
+    pub fuzz_tests_run: usize,
+    /// L1 gas used by passed test cases, fuzz tests count with the mean of their runs
+    pub l1_gas: u128,
+}
+
+impl TestCaseCounts {
+    fn add(&mut self, summary: &AnyTestCaseSummary) {
+        if summary.is_passed() {
+            self.passed += 1;
+            self.l1_gas += passed_l1_gas(summary);
+        } else if summary.is_failed() {
+            self.failed += 1;
+        } else if summary.is_skipped() {
+            self.skipped += 1;
+        } else if summary.is_filtered() {
+            self.filtered += 1;
+        } else if summary.is_ignored() {
+            self.ignored += 1;
+        }
+
+        if matches!(summary, AnyTestCaseSummary::Fuzzing(_))
+            && (summary.is_passed() || summary.is_failed())
+        {
+            self.fuzz_tests_run += 1;
+        }
+    }
+}
+
+fn passed_l1_gas(summary: &AnyTestCaseSummary) -> u128 {
+    match summary {
+        AnyTestCaseSummary::Single(TestCaseSummary::Passed { gas_info, .. }) => *gas_info,
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        AnyTestCaseSummary::Fuzzing(TestCaseSummary::Passed { gas_info, .. }) => {
+            gas_info.l1_gas.mean as u128
+        }
+        _ => 0,
+    }
+}
 
 /// Summary of the test run in the file
 #[derive(Debug)]
 pub struct TestTargetSummary {
-    /// Summaries of each test case in the file
+    /// Summaries of each test case in the file.
+    /// With [`TestSummaryMode::Streaming`] only summaries of failed test cases are kept
     pub test_case_summaries: Vec<AnyTestCaseSummary>,
+    /// Counts of all test cases results, regardless of the summary mode
+    pub counts: TestCaseCounts,
+    summary_mode: TestSummaryMode,
 }
 
 impl TestTargetSummary {
+    #[must_use]
+    pub fn new(summary_mode: TestSummaryMode) -> Self {
+        Self {
+            test_case_summaries: vec![],
+            counts: TestCaseCounts::default(),
+            summary_mode,
+        }
+    }
+
+    pub fn add(&mut self, summary: AnyTestCaseSummary) {
+        self.counts.add(&summary);
+
+        let keep = match self.summary_mode {
+            TestSummaryMode::Full => true,
+            TestSummaryMode::Streaming => summary.is_failed(),
+        };
+        if keep {
+            self.test_case_summaries.push(summary);
+        }
+    }
+
     #[must_use]
     pub fn count_passed(&self) -> usize {
-        self.test_case_summaries
-            .iter()
-            .filter(|tu| tu.is_passed())
-            .count()
+        self.counts.passed
     }
 
     #[must_use]
     pub fn count_failed(&self) -> usize {
-        self.test_case_summaries
-            .iter()
-            .filter(|tu| tu.is_failed())
-            .count()
+        self.counts.failed
     }
 
     #[must_use]
     pub fn count_skipped(&self) -> usize {
-        self.test_case_summaries
-            .iter()
-            .filter(|tu| tu.is_skipped())
-            .count()
+        self.counts.skipped
     }
 
     #[must_use]
     pub fn count_ignored(&self) -> usize {
-        self.test_case_summaries
-            .iter()
-            .filter(|tu| tu.is_ignored())
-            .count()
+        self.counts.ignored
+    }
+
+    #[must_use]
+    pub fn count_filtered(&self) -> usize {
+        self.counts.filtered
+    }
+
+    #[must_use]
+    pub fn l1_gas(&self) -> u128 {
+        self.counts.l1_gas
+    }
+
+    #[must_use]
+    pub fn any_fuzz_test_was_run(&self) -> bool {
+        self.counts.fuzz_tests_run > 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_case_summary::{
+        FuzzerArgumentsStatistics, FuzzingGasInfo, FuzzingStatistics, GasStatistics,
+    };
+    use cheatnet::runtime_extensions::call_to_blockifier_runtime_extension::rpc::UsedResources;
+
+    fn summaries() -> Vec<AnyTestCaseSummary> {
+        vec![
+            AnyTestCaseSummary::Single(TestCaseSummary::Failed {
+                name: "failing".to_string(),
+                msg: None,
+                arguments: vec![],
+                test_statistics: (),
+                printed_output: String::new(),
+            }),
+            AnyTestCaseSummary::Single(TestCaseSummary::Ignored {
+                name: "ignored".to_string(),
+                reason: None,
+            }),
+            AnyTestCaseSummary::Single(TestCaseSummary::Ignored {
+                name: "forked".to_string(),
+                reason: Some("requires a fork".to_string()),
+            }),
+            AnyTestCaseSummary::Single(TestCaseSummary::Skipped {}),
+        ]
+    }
+
+    #[test]
+    fn full_mode_keeps_all_summaries() {
+        let mut summary = TestTargetSummary::new(TestSummaryMode::Full);
+        for case in summaries() {
+            summary.add(case);
+        }
+
+        assert_eq!(summary.test_case_summaries.len(), 4);
+        assert_eq!(summary.count_failed(), 1);
+        assert_eq!(summary.count_ignored(), 1);
+        assert_eq!(summary.count_filtered(), 1);
+        assert_eq!(summary.count_skipped(), 1);
+        assert!(!summary.any_fuzz_test_was_run());
+    }
+
+    #[test]
+    fn streaming_mode_keeps_only_failed_summaries() {
+        let mut summary = TestTargetSummary::new(TestSummaryMode::Streaming);
+        for case in summaries() {
+            summary.add(case);
+        }
+
+        assert_eq!(summary.test_case_summaries.len(), 1);
+        assert!(summary.test_case_summaries[0].is_failed());
+        assert_eq!(
+            summary.counts,
+            TestCaseCounts {
+                passed: 0,
+                failed: 1,
+                skipped: 1,
+                ignored: 1,
+                filtered: 1,
+                fuzz_tests_run: 0,
+                l1_gas: 0,
+            }
+        );
+    }
+
+    fn passed_fuzz_test(mean_l1_gas: f64) -> AnyTestCaseSummary {
+        AnyTestCaseSummary::Fuzzing(TestCaseSummary::Passed {
+            name: "fuzz".to_string(),
+            msg: None,
+            arguments: vec![],
+            gas_info: FuzzingGasInfo {
+                l1_gas: GasStatistics {
+                    mean: mean_l1_gas,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            used_resources: UsedResources::default(),
+            test_statistics: FuzzingStatistics {
+                runs: 2,
+                arguments: FuzzerArgumentsStatistics::default(),
+            },
+            trace_data: (),
+            printed_output: String::new(),
+        })
+    }
+
+    #[test]
+    fn counts_l1_gas_of_passed_tests() {
+        let mut summary = TestTargetSummary::new(TestSummaryMode::Streaming);
+        summary.add(passed_fuzz_test(100.0));
+        summary.add(passed_fuzz_test(20.5));
+        for case in summaries() {
+            summary.add(case);
+        }
+
+        assert_eq!(summary.count_passed(), 2);
+        assert_eq!(summary.l1_gas(), 120);
     }
 }
@@ -0,0 +1,122 @@
+use anyhow::{Context, Result};
+use cairo_felt::Felt252;
+use cairo_vm::vm::trace::trace_entry::TraceEntry;
+use camino::{Utf8Path, Utf8PathBuf};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+/// Directory to which traces of the Cairo VM are saved
+#[derive(Debug, PartialEq, Clone)]
+pub struct VmTraceOutput {
+    pub dir: Utf8PathBuf,
+    /// Save traces of passed tests too, not only of the failed ones
+    pub all_tests: bool,
+}
+
+/// Relocated execution trace and memory of a test case run
+#[derive(Debug, Default)]
+pub struct VmTrace {
+    pub(crate) trace: Vec<TraceEntry>,
+    pub(crate) memory: Vec<Option<Felt252>>,
+}
+
+impl VmTrace {
+    /// Saves the trace as `<test name>.trace` and the memory as `<test name>.memory`,
+    /// in the binary format of `cairo-run --trace_file` and `--memory_file`
+    pub(crate) fn save(&self, dir: &Utf8Path, test_name: &str) -> Result<()> {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create trace directory = {dir}"))?;
+
+        let trace_path = dir.join(format!("{test_name}.trace"));
+        write_to_file(&trace_path, |writer| write_trace(&self.trace, writer))?;
+        let memory_path = dir.join(format!("{test_name}.memory"));
+        write_to_file(&memory_path, |writer| write_memory(&self.memory, writer))
+    }
+}
+
+fn write_to_file(
+    path: &Utf8Path,
+    write: impl FnOnce(&mut BufWriter<File>) -> std::io::Result<()>,
+) -> Result<()> {
+    let file = File::create(path).with_context(|| format!("Failed to create file = {path}"))?;
+    let mut writer = BufWriter::new(file);
+    write(&mut writer)
+        .and_then(|()| writer.flush())
+        .with_context(|| format!("Failed to write VM trace to {path}"))
+}
+
+/// Each entry is written as `ap`, `fp` and `pc` encoded as little endian 64-bit numbers
+fn write_trace(trace: &[TraceEntry], writer: &mut impl Write) -> std::io::Result<()> {
+    for entry in trace {
+        writer.write_all(&(entry.ap as u64).to_le_bytes())?;
+        writer.write_all(&(entry.fp as u64).to_le_bytes())?;
+        writer.write_all(&(entry.pc as u64).to_le_bytes())?;
+    }
+    Ok(())
+}
+
+/// Each memory cell with a value is written as its address encoded as a little endian 64-bit number,
+/// followed by the value encoded as a little endian 256-bit number
+fn write_memory(memory: &[Option<Felt252>], writer: &mut impl Write) -> std::io::Result<()> {
+    for (address, value) in memory.iter().enumerate() {
+        let Some(value) = value else {
+            continue;
+        };
+        let mut value_bytes = value.to_biguint().to_bytes_le();
+        value_bytes.resize(32, 0);
+
+        writer.write_all(&(address as u64).to_le_bytes())?;
+        writer.write_all(&value_bytes)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encoded_trace() {
+        let trace = vec![
+            TraceEntry {
+                pc: 1,
+                ap: 2,
+                fp: 3,
+            },
+            TraceEntry {
+                pc: 4,
+                ap: 5,
+                fp: 6,
+            },
+        ];
+        let mut encoded = vec![];
+
+        write_trace(&trace, &mut encoded).unwrap();
+
+        let words: Vec<u64> = encoded
+            .chunks(8)
+            .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+            .collect();
+        assert_eq!(words, vec![2, 3, 1, 5, 6, 4]);
+    }
+
+    #[test]
+    fn encoded_memory_skips_empty_cells() {
+        let memory = vec![
+            None,
+            Some(Felt252::from(0x1234)),
+            None,
+            Some(Felt252::from(7)),
+        ];
+        let mut encoded = vec![];
+
+        write_memory(&memory, &mut encoded).unwrap();
+
+        assert_eq!(encoded.len(), 2 * 40);
+        assert_eq!(encoded[..8], 1_u64.to_le_bytes());
+        assert_eq!(encoded[8..10], [0x34, 0x12]);
+        assert!(encoded[10..40].iter().all(|byte| *byte == 0));
+        assert_eq!(encoded[40..48], 3_u64.to_le_bytes());
+        assert_eq!(encoded[48], 7);
+    }
+}
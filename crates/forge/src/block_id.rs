@@ -0,0 +1,105 @@
+use anyhow::{anyhow, bail, Result};
+use cairo_felt::Felt252;
+use num_bigint::BigUint;
+use num_traits::Num;
+use starknet_api::block::BlockNumber;
+
+/// Parses `block_id.number` of a fork, used both when `Scarb.toml` is validated and when forks are resolved
+pub(crate) fn parse_block_number(value: &str) -> Result<BlockNumber> {
+    value.parse::<u64>().map(BlockNumber).map_err(|_| {
+        anyhow!("block_id.number = {value} is not a valid block number. Expected a non-negative integer fitting in u64")
+    })
+}
+
+/// Parses `block_id.hash` of a fork given either as a hex string prefixed with `0x` or as a decimal number,
+/// used both when `Scarb.toml` is validated and when forks are resolved.
+/// Hashes from the `#[fork]` attribute are passed by the test collector as decimal numbers
+pub(crate) fn parse_block_hash(value: &str) -> Result<Felt252> {
+    let block_hash = match value.strip_prefix("0x") {
+        Some(hex) => {
+            if hex.is_empty() || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                bail!("block_id.hash = {value} is not a valid block hash. Expected only hex digits after 0x");
+            }
+            if hex.len() > 64 {
+                bail!("block_id.hash = {value} is not a valid block hash. Expected at most 64 hex digits after 0x");
+            }
+            BigUint::from_str_radix(hex, 16)
+        }
+        None => {
+            if value.is_empty() || !value.chars().all(|c| c.is_ascii_digit()) {
+                bail!("block_id.hash = {value} is not a valid block hash. Expected a hex string starting with 0x or a decimal number");
+            }
+            BigUint::from_str_radix(value, 10)
+        }
+    }
+    .expect("Digits have been checked");
+
+    if block_hash >= felt252_prime() {
+        bail!("block_id.hash = {value} is not a valid block hash. Value is out of felt252 range");
+    }
+
+    Ok(block_hash.into())
+}
+
+fn felt252_prime() -> BigUint {
+    (BigUint::from(1_u8) << 251) + (BigUint::from(17_u8) << 192) + 1_u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_valid_block_ids() {
+        assert_eq!(parse_block_number("54060").unwrap(), BlockNumber(54060));
+        assert_eq!(
+            parse_block_hash("0x6ae121e46f5375f93b00475fb130348ae38148e121f84b0865e17542e9485de")
+                .unwrap(),
+            Felt252::from_str_radix(
+                "6ae121e46f5375f93b00475fb130348ae38148e121f84b0865e17542e9485de",
+                16
+            )
+            .unwrap()
+        );
+        assert_eq!(parse_block_hash("0x7b").unwrap(), Felt252::from(123));
+        assert_eq!(parse_block_hash("123").unwrap(), Felt252::from(123));
+    }
+
+    #[test]
+    fn parse_invalid_block_number() {
+        for value in ["-1", "18446744073709551616", "0x10", "latest"] {
+            let err = parse_block_number(value).unwrap_err();
+            assert!(err.to_string().contains(&format!(
+                "block_id.number = {value} is not a valid block number"
+            )));
+        }
+    }
+
+    #[test]
+    fn parse_invalid_block_hash() {
+        for value in ["", "-5", "latest", "7b"] {
+            let err = parse_block_hash(value).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!("block_id.hash = {value} is not a valid block hash. Expected a hex string starting with 0x or a decimal number")
+            );
+        }
+
+        for value in ["0x", "0xzz", "0x-5", "0x+5"] {
+            let err = parse_block_hash(value).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!("block_id.hash = {value} is not a valid block hash. Expected only hex digits after 0x")
+            );
+        }
+
+        let err = parse_block_hash(&format!("0x{}", "1".repeat(65))).unwrap_err();
+        assert!(err.to_string().contains("Expected at most 64 hex digits"));
+
+        let err = parse_block_hash(&format!("0x{}", "f".repeat(64))).unwrap_err();
+        assert!(err.to_string().contains("Value is out of felt252 range"));
+
+        let err = parse_block_hash(&"9".repeat(80)).unwrap_err();
+        assert!(err.to_string().contains("Value is out of felt252 range"));
+    }
+}
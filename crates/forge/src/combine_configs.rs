@@ -1,29 +1,115 @@
 use crate::scarb::config::ForgeConfigFromScarb;
+use crate::TestArgs;
 use camino::Utf8PathBuf;
 use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
+use forge_runner::error_names::ErrorNames;
 use forge_runner::forge_config::{
-    is_vm_trace_needed, ExecutionDataToSave, ForgeConfig, OutputConfig, TestRunnerConfig,
+    is_vm_trace_needed, CompileWarnings, ExecutionDataToSave, ForgeConfig, GasUnit, OutputConfig,
+    ResultFormat, TestRunnerConfig, TestSummaryMode, DEFAULT_CONTRACT_CACHE_SIZE,
 };
+use forge_runner::vm_trace::VmTraceOutput;
 use rand::{thread_rng, RngCore};
 use std::env;
-use std::num::NonZeroU32;
+use std::num::NonZeroUsize;
 use std::sync::Arc;
 
-#[allow(clippy::too_many_arguments)]
-#[allow(clippy::fn_params_excessive_bools)]
+/// Options of the run set with flags of `snforge test`, they take precedence over [`ForgeConfigFromScarb`]
+#[derive(Debug, Default, Clone)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ForgeConfigFromCli {
+    pub exit_first: bool,
+    pub fuzzer_runs: Option<u32>,
+    pub fuzzer_seed: Option<u64>,
+    pub fuzzer_max_rejects: Option<u32>,
+    pub fuzzer_max_length: Option<u32>,
+    pub fuzzer_exhaustive_threshold: Option<u32>,
+    pub detailed_resources: bool,
+    pub gas_unit: GasUnit,
+    pub hide_passed_output: bool,
+    pub fuzzer_stats: bool,
+    pub quiet: bool,
+    pub result_format: ResultFormat,
+    pub save_trace_data: bool,
+    pub build_profile: bool,
+    pub max_n_steps: Option<u32>,
+    pub contract_cache_size: Option<NonZeroUsize>,
+    pub test_target_concurrency: Option<NonZeroUsize>,
+    pub max_concurrency: Option<NonZeroUsize>,
+    pub streaming_summary: bool,
+    /// Absolute path of the file created for `--results-jsonl`
+    pub results_jsonl_path: Option<Utf8PathBuf>,
+    pub update_snapshots: bool,
+    pub vm_trace_output: Option<VmTraceOutput>,
+    pub compile_warnings: CompileWarnings,
+}
+
+impl From<&TestArgs> for ForgeConfigFromCli {
+    fn from(args: &TestArgs) -> Self {
+        Self {
+            exit_first: args.exit_first,
+            fuzzer_runs: args.fuzzer_runs,
+            fuzzer_seed: args.fuzzer_seed,
+            fuzzer_max_rejects: args.fuzzer_max_rejects,
+            fuzzer_max_length: args.fuzzer_max_length,
+            fuzzer_exhaustive_threshold: args.fuzzer_exhaustive_threshold,
+            detailed_resources: args.detailed_resources,
+            gas_unit: args.gas_unit.into(),
+            hide_passed_output: args.hide_passed_output,
+            fuzzer_stats: args.fuzzer_stats,
+            quiet: args.quiet,
+            result_format: args.format.into(),
+            save_trace_data: args.save_trace_data,
+            build_profile: args.build_profile,
+            max_n_steps: args.max_n_steps,
+            contract_cache_size: args.contract_cache_size,
+            test_target_concurrency: args.test_target_concurrency,
+            max_concurrency: args.test_threads,
+            streaming_summary: args.streaming_summary,
+            results_jsonl_path: None,
+            update_snapshots: args.update_snapshots,
+            vm_trace_output: args.save_trace.clone().map(|dir| VmTraceOutput {
+                dir,
+                all_tests: args.trace_all,
+            }),
+            compile_warnings: args.compile_warnings.into(),
+        }
+    }
+}
+
 pub fn combine_configs(
-    exit_first: bool,
-    fuzzer_runs: Option<NonZeroU32>,
-    fuzzer_seed: Option<u64>,
-    detailed_resources: bool,
-    save_trace_data: bool,
-    build_profile: bool,
-    max_n_steps: Option<u32>,
+    forge_config_from_cli: ForgeConfigFromCli,
     contracts_data: ContractsData,
     cache_dir: Utf8PathBuf,
     versioned_programs_dir: Utf8PathBuf,
+    snapshots_dir: Utf8PathBuf,
+    error_names: ErrorNames,
     forge_config_from_scarb: &ForgeConfigFromScarb,
 ) -> ForgeConfig {
+    let ForgeConfigFromCli {
+        exit_first,
+        fuzzer_runs,
+        fuzzer_seed,
+        fuzzer_max_rejects,
+        fuzzer_max_length,
+        fuzzer_exhaustive_threshold,
+        detailed_resources,
+        gas_unit,
+        hide_passed_output,
+        fuzzer_stats,
+        quiet,
+        result_format,
+        save_trace_data,
+        build_profile,
+        max_n_steps,
+        contract_cache_size,
+        test_target_concurrency,
+        max_concurrency,
+        streaming_summary,
+        results_jsonl_path,
+        update_snapshots,
+        vm_trace_output,
+        compile_warnings,
+    } = forge_config_from_cli;
     let execution_data_to_save = ExecutionDataToSave::from_flags(
         save_trace_data || forge_config_from_scarb.save_trace_data,
         build_profile || forge_config_from_scarb.build_profile,
@@ -34,20 +120,50 @@ pub fn combine_configs(
             exit_first: exit_first || forge_config_from_scarb.exit_first,
             fuzzer_runs: fuzzer_runs
                 .or(forge_config_from_scarb.fuzzer_runs)
-                .unwrap_or(NonZeroU32::new(256).unwrap()),
+                .unwrap_or(256),
             fuzzer_seed: fuzzer_seed
                 .or(forge_config_from_scarb.fuzzer_seed)
                 .unwrap_or_else(|| thread_rng().next_u64()),
+            fuzzer_max_rejects: fuzzer_max_rejects
+                .or(forge_config_from_scarb.fuzzer_max_rejects)
+                .unwrap_or(1000),
+            fuzzer_max_length: fuzzer_max_length
+                .or(forge_config_from_scarb.fuzzer_max_length)
+                .unwrap_or(16),
+            fuzzer_exhaustive_threshold: fuzzer_exhaustive_threshold
+                .or(forge_config_from_scarb.fuzzer_exhaustive_threshold),
             max_n_steps: max_n_steps.or(forge_config_from_scarb.max_n_steps),
+            contract_cache_size: contract_cache_size
+                .or(forge_config_from_scarb.contract_cache_size)
+                .unwrap_or(DEFAULT_CONTRACT_CACHE_SIZE),
+            test_target_concurrency: test_target_concurrency.unwrap_or(NonZeroUsize::MIN),
+            max_concurrency,
             is_vm_trace_needed: is_vm_trace_needed(execution_data_to_save),
             cache_dir,
             contracts_data,
             environment_variables: env::vars().collect(),
+            block_info_overrides: forge_config_from_scarb.block_info.clone(),
+            snapshots_dir,
+            update_snapshots,
+            vm_trace_output,
+            compile_warnings,
+            error_names,
         }),
         output_config: Arc::new(OutputConfig {
             detailed_resources: detailed_resources || forge_config_from_scarb.detailed_resources,
+            gas_unit,
+            hide_passed_output,
+            fuzzer_stats,
+            quiet,
+            result_format,
             execution_data_to_save,
             versioned_programs_dir,
+            summary_mode: if streaming_summary {
+                TestSummaryMode::Streaming
+            } else {
+                TestSummaryMode::Full
+            },
+            results_jsonl_path,
         }),
     }
 }
@@ -59,29 +175,21 @@ mod tests {
     #[test]
     fn fuzzer_default_seed() {
         let config = combine_configs(
-            false,
-            None,
-            None,
-            false,
-            false,
-            false,
-            None,
+            ForgeConfigFromCli::default(),
             Default::default(),
             Default::default(),
             Default::default(),
+            Default::default(),
+            ErrorNames::default(),
             &Default::default(),
         );
         let config2 = combine_configs(
-            false,
-            None,
-            None,
-            false,
-            false,
-            false,
-            None,
+            ForgeConfigFromCli::default(),
+            Default::default(),
             Default::default(),
             Default::default(),
             Default::default(),
+            ErrorNames::default(),
             &Default::default(),
         );
 
@@ -96,16 +204,12 @@ mod tests {
     #[test]
     fn runner_config_default_arguments() {
         let config = combine_configs(
-            false,
-            None,
-            None,
-            false,
-            false,
-            false,
-            None,
+            ForgeConfigFromCli::default(),
+            Default::default(),
             Default::default(),
             Default::default(),
             Default::default(),
+            ErrorNames::default(),
             &Default::default(),
         );
         assert_eq!(
@@ -113,18 +217,37 @@ mod tests {
             ForgeConfig {
                 test_runner_config: Arc::new(TestRunnerConfig {
                     exit_first: false,
-                    fuzzer_runs: NonZeroU32::new(256).unwrap(),
+                    fuzzer_runs: 256,
                     fuzzer_seed: config.test_runner_config.fuzzer_seed,
+                    fuzzer_max_rejects: 1000,
+                    fuzzer_max_length: 16,
+                    fuzzer_exhaustive_threshold: None,
+                    contract_cache_size: DEFAULT_CONTRACT_CACHE_SIZE,
                     max_n_steps: None,
+                    test_target_concurrency: NonZeroUsize::new(1).unwrap(),
+                    max_concurrency: None,
                     is_vm_trace_needed: false,
                     cache_dir: Default::default(),
                     contracts_data: Default::default(),
                     environment_variables: config.test_runner_config.environment_variables.clone(),
+                    block_info_overrides: Default::default(),
+                    snapshots_dir: Default::default(),
+                    update_snapshots: false,
+                    vm_trace_output: None,
+                    compile_warnings: CompileWarnings::Warn,
+                    error_names: ErrorNames::default(),
                 }),
                 output_config: Arc::new(OutputConfig {
                     detailed_resources: false,
+                    gas_unit: GasUnit::default(),
+                    hide_passed_output: false,
+                    fuzzer_stats: false,
+                    quiet: false,
+                    result_format: ResultFormat::default(),
                     execution_data_to_save: ExecutionDataToSave::None,
                     versioned_programs_dir: Default::default(),
+                    summary_mode: TestSummaryMode::Full,
+                    results_jsonl_path: None,
                 }),
             }
         );
@@ -135,25 +258,27 @@ mod tests {
         let config_from_scarb = ForgeConfigFromScarb {
             exit_first: true,
             fork: vec![],
-            fuzzer_runs: Some(NonZeroU32::new(1234).unwrap()),
+            fuzzer_runs: Some(1234),
             fuzzer_seed: Some(500),
+            fuzzer_max_rejects: Some(50),
+            fuzzer_max_length: Some(8),
+            fuzzer_exhaustive_threshold: Some(64),
+            contract_cache_size: NonZeroUsize::new(500),
             detailed_resources: true,
             save_trace_data: true,
             build_profile: true,
             max_n_steps: Some(1_000_000),
+            block_info: Default::default(),
+            error_names: None,
         };
 
         let config = combine_configs(
-            false,
-            None,
-            None,
-            false,
-            false,
-            false,
-            None,
+            ForgeConfigFromCli::default(),
             Default::default(),
             Default::default(),
             Default::default(),
+            Default::default(),
+            ErrorNames::default(),
             &config_from_scarb,
         );
         assert_eq!(
@@ -161,18 +286,37 @@ mod tests {
             ForgeConfig {
                 test_runner_config: Arc::new(TestRunnerConfig {
                     exit_first: true,
-                    fuzzer_runs: NonZeroU32::new(1234).unwrap(),
+                    fuzzer_runs: 1234,
                     fuzzer_seed: 500,
+                    fuzzer_max_rejects: 50,
+                    fuzzer_max_length: 8,
+                    fuzzer_exhaustive_threshold: Some(64),
+                    contract_cache_size: NonZeroUsize::new(500).unwrap(),
                     max_n_steps: Some(1_000_000),
+                    test_target_concurrency: NonZeroUsize::new(1).unwrap(),
+                    max_concurrency: None,
                     is_vm_trace_needed: true,
                     cache_dir: Default::default(),
                     contracts_data: Default::default(),
                     environment_variables: config.test_runner_config.environment_variables.clone(),
+                    block_info_overrides: Default::default(),
+                    snapshots_dir: Default::default(),
+                    update_snapshots: false,
+                    vm_trace_output: None,
+                    compile_warnings: CompileWarnings::Warn,
+                    error_names: ErrorNames::default(),
                 }),
                 output_config: Arc::new(OutputConfig {
                     detailed_resources: true,
+                    gas_unit: GasUnit::default(),
+                    hide_passed_output: false,
+                    fuzzer_stats: false,
+                    quiet: false,
+                    result_format: ResultFormat::default(),
                     execution_data_to_save: ExecutionDataToSave::TraceAndProfile,
                     versioned_programs_dir: Default::default(),
+                    summary_mode: TestSummaryMode::Full,
+                    results_jsonl_path: None,
                 }),
             }
         );
@@ -183,24 +327,41 @@ mod tests {
         let config_from_scarb = ForgeConfigFromScarb {
             exit_first: false,
             fork: vec![],
-            fuzzer_runs: Some(NonZeroU32::new(1234).unwrap()),
+            fuzzer_runs: Some(1234),
             fuzzer_seed: Some(1000),
+            fuzzer_max_rejects: Some(50),
+            fuzzer_max_length: Some(8),
+            fuzzer_exhaustive_threshold: Some(64),
+            contract_cache_size: NonZeroUsize::new(500),
             detailed_resources: false,
             save_trace_data: false,
             build_profile: false,
             max_n_steps: Some(1234),
+            block_info: Default::default(),
+            error_names: None,
         };
         let config = combine_configs(
-            true,
-            Some(NonZeroU32::new(100).unwrap()),
-            Some(32),
-            true,
-            true,
-            true,
-            Some(1_000_000),
+            ForgeConfigFromCli {
+                exit_first: true,
+                fuzzer_runs: Some(100),
+                fuzzer_seed: Some(32),
+                fuzzer_max_rejects: Some(20),
+                fuzzer_max_length: Some(4),
+                fuzzer_exhaustive_threshold: Some(32),
+                detailed_resources: true,
+                save_trace_data: true,
+                build_profile: true,
+                max_n_steps: Some(1_000_000),
+                contract_cache_size: NonZeroUsize::new(1000),
+                streaming_summary: true,
+                results_jsonl_path: Some(Utf8PathBuf::from("results.jsonl")),
+                ..Default::default()
+            },
+            Default::default(),
             Default::default(),
             Default::default(),
             Default::default(),
+            ErrorNames::default(),
             &config_from_scarb,
         );
 
@@ -209,18 +370,37 @@ mod tests {
             ForgeConfig {
                 test_runner_config: Arc::new(TestRunnerConfig {
                     exit_first: true,
-                    fuzzer_runs: NonZeroU32::new(100).unwrap(),
+                    fuzzer_runs: 100,
                     fuzzer_seed: 32,
+                    fuzzer_max_rejects: 20,
+                    fuzzer_max_length: 4,
+                    fuzzer_exhaustive_threshold: Some(32),
+                    contract_cache_size: NonZeroUsize::new(1000).unwrap(),
                     max_n_steps: Some(1_000_000),
+                    test_target_concurrency: NonZeroUsize::new(1).unwrap(),
+                    max_concurrency: None,
                     is_vm_trace_needed: true,
                     cache_dir: Default::default(),
                     contracts_data: Default::default(),
                     environment_variables: config.test_runner_config.environment_variables.clone(),
+                    block_info_overrides: Default::default(),
+                    snapshots_dir: Default::default(),
+                    update_snapshots: false,
+                    vm_trace_output: None,
+                    compile_warnings: CompileWarnings::Warn,
+                    error_names: ErrorNames::default(),
                 }),
                 output_config: Arc::new(OutputConfig {
                     detailed_resources: true,
+                    gas_unit: GasUnit::default(),
+                    hide_passed_output: false,
+                    fuzzer_stats: false,
+                    quiet: false,
+                    result_format: ResultFormat::default(),
                     execution_data_to_save: ExecutionDataToSave::TraceAndProfile,
                     versioned_programs_dir: Default::default(),
+                    summary_mode: TestSummaryMode::Streaming,
+                    results_jsonl_path: Some(Utf8PathBuf::from("results.jsonl")),
                 }),
             }
         );
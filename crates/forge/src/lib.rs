@@ -1,20 +1,26 @@
 use anyhow::Result;
+use camino::Utf8PathBuf;
 use clap::{Parser, Subcommand, ValueEnum};
-use forge_runner::CACHE_DIR;
+use forge_runner::{
+    forge_config::{CompileWarnings, GasUnit, ResultFormat},
+    CACHE_DIR,
+};
 use run_tests::workspace::run_for_workspace;
 use scarb_api::{metadata::MetadataCommandExt, ScarbCommand};
 use scarb_ui::args::PackagesFilter;
-use std::{fs, num::NonZeroU32, thread::available_parallelism};
+use std::{fs, num::NonZeroUsize, thread::available_parallelism};
 use tokio::runtime::Builder;
 use universal_sierra_compiler_api::UniversalSierraCompilerCommand;
 
+mod block_id;
 pub mod block_number_map;
 mod combine_configs;
 mod init;
 pub mod pretty_printing;
 pub mod run_tests;
 pub mod scarb;
-mod shared_cache;
+pub mod shared_cache;
+mod snforge_config;
 pub mod test_filter;
 mod warn;
 
@@ -80,6 +86,63 @@ enum ColorOption {
     Never,
 }
 
+#[derive(ValueEnum, Debug, Clone, Copy)]
+enum GasUnitOption {
+    /// Estimated L1 gas
+    L1Gas,
+    /// Cairo VM steps
+    Steps,
+    /// Both L1 gas and steps
+    All,
+}
+
+impl From<GasUnitOption> for GasUnit {
+    fn from(value: GasUnitOption) -> Self {
+        match value {
+            GasUnitOption::L1Gas => GasUnit::L1Gas,
+            GasUnitOption::Steps => GasUnit::Steps,
+            GasUnitOption::All => GasUnit::All,
+        }
+    }
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+enum FormatOption {
+    /// A line with the result of each test
+    Detailed,
+    /// A single character for each test, followed by details of failed tests
+    Compact,
+}
+
+impl From<FormatOption> for ResultFormat {
+    fn from(value: FormatOption) -> Self {
+        match value {
+            FormatOption::Detailed => ResultFormat::Detailed,
+            FormatOption::Compact => ResultFormat::Compact,
+        }
+    }
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+enum CompileWarningsOption {
+    /// Don't display warnings
+    Ignore,
+    /// Display warnings and run tests
+    Warn,
+    /// Fail the run if there are warnings
+    Error,
+}
+
+impl From<CompileWarningsOption> for CompileWarnings {
+    fn from(value: CompileWarningsOption) -> Self {
+        match value {
+            CompileWarningsOption::Ignore => CompileWarnings::Ignore,
+            CompileWarningsOption::Warn => CompileWarnings::Warn,
+            CompileWarningsOption::Error => CompileWarnings::Error,
+        }
+    }
+}
+
 #[derive(Parser, Debug)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct TestArgs {
@@ -98,10 +161,38 @@ pub struct TestArgs {
 
     /// Number of fuzzer runs
     #[arg(short = 'r', long)]
-    fuzzer_runs: Option<NonZeroU32>,
+    fuzzer_runs: Option<u32>,
     /// Seed for the fuzzer
     #[arg(short = 's', long)]
     fuzzer_seed: Option<u64>,
+    /// Generate a new fuzzer seed instead of reusing the one saved by a previous run
+    #[arg(long, conflicts_with = "fuzzer_seed")]
+    new_seed: bool,
+    /// Number of consecutive runs of a fuzz test rejected with `assume` after which the test fails
+    #[arg(long)]
+    fuzzer_max_rejects: Option<u32>,
+    /// Maximum length of arrays and byte arrays generated by the fuzzer [default: 16]
+    #[arg(long)]
+    fuzzer_max_length: Option<u32>,
+    /// Run fuzz tests with all combinations of arguments, instead of random ones,
+    /// if there are at most this many of them [default: number of fuzzer runs]
+    #[arg(long)]
+    fuzzer_exhaustive_threshold: Option<u32>,
+
+    /// Profile from `[snforge.<profile>]` table of snfoundry.toml used for options not passed as flags
+    #[arg(long)]
+    profile: Option<String>,
+
+    /// List tests which would be run with the current filters, without running them
+    #[arg(long)]
+    list: bool,
+    /// Print the list of tests as JSON
+    #[arg(long, requires = "list")]
+    json: bool,
+
+    /// Run only tests listed in a file, one fully qualified test name per line
+    #[arg(long, value_name = "FILE")]
+    test_list: Option<Utf8PathBuf>,
 
     /// Run only tests marked with `#[ignore]` attribute
     #[arg(long = "ignored")]
@@ -110,10 +201,37 @@ pub struct TestArgs {
     #[arg(long, conflicts_with = "only_ignored")]
     include_ignored: bool,
 
+    /// Don't run tests marked with `#[fork]` attribute, display them as ignored instead
+    #[arg(long)]
+    skip_forked: bool,
+    /// Run only tests marked with `#[fork]` attribute
+    #[arg(long, conflicts_with = "skip_forked")]
+    only_forked: bool,
+
     /// Display more detailed info about used resources
     #[arg(long)]
     detailed_resources: bool,
 
+    /// Resources reported as the cost of passed tests
+    #[arg(value_enum, long, default_value_t = GasUnitOption::L1Gas, value_name = "UNIT")]
+    gas_unit: GasUnitOption,
+
+    /// Don't display output printed by tests which passed
+    #[arg(long)]
+    hide_passed_output: bool,
+
+    /// Display statistics of arguments generated by the fuzzer for passed fuzz tests
+    #[arg(long)]
+    fuzzer_stats: bool,
+
+    /// Display only failed tests and the summary, without passed and ignored tests
+    #[arg(short, long)]
+    quiet: bool,
+
+    /// Format in which results of tests are displayed
+    #[arg(value_enum, long, default_value_t = FormatOption::Detailed, conflicts_with = "quiet")]
+    format: FormatOption,
+
     /// Control when colored output is used
     #[arg(value_enum, long, default_value_t = ColorOption::Auto, value_name="WHEN")]
     color: ColorOption,
@@ -121,6 +239,9 @@ pub struct TestArgs {
     /// Run tests that failed during the last run
     #[arg(long)]
     rerun_failed: bool,
+    /// Run all tests with `--rerun-failed` if there is no previous run, instead of running none
+    #[arg(long, requires = "rerun_failed")]
+    run_all_without_prior_run: bool,
 
     /// Save execution traces of all test which have passed and are not fuzz tests
     #[arg(long)]
@@ -130,14 +251,84 @@ pub struct TestArgs {
     #[arg(long)]
     build_profile: bool,
 
+    /// Save the Cairo VM execution trace and memory of each failed test to `<DIR>/<test name>.trace` and `.memory`
+    #[arg(long, value_name = "DIR")]
+    save_trace: Option<Utf8PathBuf>,
+    /// Save the Cairo VM execution traces of passed tests too
+    #[arg(long, requires = "save_trace")]
+    trace_all: bool,
+
+    /// Overwrite saved storage snapshots of tests with the storage at the end of the tests
+    #[arg(long)]
+    update_snapshots: bool,
+
+    /// Compile tests to CASM on every run, instead of reusing programs compiled by previous runs from the cache directory
+    #[arg(long)]
+    no_compile_cache: bool,
+
+    /// What happens with warnings reported while compiling tests to CASM
+    #[arg(value_enum, long, default_value_t = CompileWarningsOption::Warn, value_name = "POLICY")]
+    compile_warnings: CompileWarningsOption,
+
     /// Number of maximum steps during a single test. For fuzz tests this value is applied to each subtest separately.
     #[arg(long)]
     max_n_steps: Option<u32>,
+
+    /// Number of compiled contract classes kept in the cache of a single test [default: 100].
+    /// Raising it speeds up tests using many classes, e.g. when forking, at the cost of memory
+    #[arg(long)]
+    contract_cache_size: Option<NonZeroUsize>,
+
+    /// Maximum number of test targets of a package run at the same time [default: 1]
+    #[arg(long)]
+    test_target_concurrency: Option<NonZeroUsize>,
+
+    /// Maximum number of tests of a test target run at the same time, unbounded by default.
+    /// With 1, tests are run one by one in the order of declaration
+    #[arg(long, value_name = "N")]
+    test_threads: Option<NonZeroUsize>,
+
+    /// Keep only counts of test results and summaries of failed tests instead of all test summaries.
+    /// Reduces memory usage for very large test suites
+    #[arg(long)]
+    streaming_summary: bool,
+
+    /// Write result of each test to the given file as JSON lines, as soon as the test finishes
+    #[arg(long, value_name = "PATH")]
+    results_jsonl: Option<Utf8PathBuf>,
+
+    /// Save results, resources and captured output of all tests of each package to `<DIR>/<package name>.json`
+    #[arg(long, value_name = "DIR")]
+    output_dir: Option<Utf8PathBuf>,
+
+    /// Print a single `SNFORGE_RESULT` line with counts of test results and used gas at the end of the run
+    #[arg(long)]
+    machine_summary: bool,
 }
 
+/// Outcome of a snforge command, mapped to a stable process exit code
+#[derive(Debug, PartialEq)]
 pub enum ExitStatus {
+    /// All tests which were run passed
     Success,
+    /// Some tests failed or the run was interrupted
     Failure,
+    /// No tests were run, e.g. all of them were filtered out or ignored
+    NoTestsRun,
+}
+
+/// Exit code of a snforge command which failed with an error, e.g. tests failed to compile
+pub const ERROR_EXIT_CODE: i32 = 2;
+
+impl ExitStatus {
+    #[must_use]
+    pub fn code(&self) -> i32 {
+        match self {
+            ExitStatus::Success => 0,
+            ExitStatus::Failure => 1,
+            ExitStatus::NoTestsRun => 3,
+        }
+    }
 }
 
 pub fn main_execution() -> Result<ExitStatus> {
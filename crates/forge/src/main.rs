@@ -1,12 +1,11 @@
-use forge::{main_execution, pretty_printing, ExitStatus};
+use forge::{main_execution, pretty_printing, ERROR_EXIT_CODE};
 
 fn main() {
     match main_execution() {
-        Ok(ExitStatus::Success) => std::process::exit(0),
-        Ok(ExitStatus::Failure) => std::process::exit(1),
+        Ok(exit_status) => std::process::exit(exit_status.code()),
         Err(error) => {
             pretty_printing::print_error_message(&error);
-            std::process::exit(2);
+            std::process::exit(ERROR_EXIT_CODE);
         }
     };
 }
@@ -1,7 +1,11 @@
+use crate::run_tests::list::ListedTest;
 use anyhow::Error;
 use console::style;
 use forge_runner::package_tests::TestTargetLocation;
-use forge_runner::{test_case_summary::AnyTestCaseSummary, test_target_summary::TestTargetSummary};
+use forge_runner::{
+    printing::COMPACT_RESULTS_LEGEND, test_case_summary::AnyTestCaseSummary,
+    test_target_summary::TestTargetSummary,
+};
 use starknet_api::block::BlockNumber;
 use std::collections::HashMap;
 use url::Url;
@@ -16,14 +20,17 @@ pub(crate) fn print_collected_tests_count(tests_num: usize, package_name: &str)
     println!("{}", style(plain_text).bold());
 }
 
-pub(crate) fn print_running_tests(test_target_location: TestTargetLocation, tests_num: usize) {
+pub(crate) fn format_running_tests(
+    test_target_location: TestTargetLocation,
+    tests_num: usize,
+) -> String {
     let dir_name = match test_target_location {
         TestTargetLocation::Lib => "src",
         TestTargetLocation::Tests => "tests",
     };
     let plain_text = format!("Running {tests_num} test(s) from {dir_name}/");
 
-    println!("{}", style(plain_text).bold());
+    style(plain_text).bold().to_string()
 }
 
 pub(crate) fn print_test_summary(summaries: &[TestTargetSummary], filtered: usize) {
@@ -31,6 +38,12 @@ pub(crate) fn print_test_summary(summaries: &[TestTargetSummary], filtered: usiz
     let failed: usize = summaries.iter().map(TestTargetSummary::count_failed).sum();
     let skipped: usize = summaries.iter().map(TestTargetSummary::count_skipped).sum();
     let ignored: usize = summaries.iter().map(TestTargetSummary::count_ignored).sum();
+    // Tests excluded before the run and the ones reported as ignored by a filter, e.g. `--skip-forked`
+    let filtered = filtered
+        + summaries
+            .iter()
+            .map(TestTargetSummary::count_filtered)
+            .sum::<usize>();
 
     println!(
         "{}: {} passed, {} failed, {} skipped, {} ignored, {} filtered out",
@@ -43,10 +56,64 @@ pub(crate) fn print_test_summary(summaries: &[TestTargetSummary], filtered: usiz
     );
 }
 
+/// Counts of test results of all packages, printed as a line of `key=value` pairs.
+/// The format is stable, so that the line can be parsed by tools reading the output of snforge
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct MachineSummary {
+    passed: usize,
+    failed: usize,
+    ignored: usize,
+    filtered: usize,
+    gas_total: u128,
+}
+
+impl MachineSummary {
+    pub(crate) fn add(&mut self, summaries: &[TestTargetSummary], filtered: usize) {
+        for summary in summaries {
+            self.passed += summary.count_passed();
+            self.failed += summary.count_failed();
+            self.ignored += summary.count_ignored();
+            self.filtered += summary.count_filtered();
+            self.gas_total += summary.l1_gas();
+        }
+        self.filtered += filtered;
+    }
+}
+
+pub(crate) fn print_machine_summary(machine_summary: &MachineSummary) {
+    let MachineSummary {
+        passed,
+        failed,
+        ignored,
+        filtered,
+        gas_total,
+    } = machine_summary;
+    println!("SNFORGE_RESULT passed={passed} failed={failed} ignored={ignored} filtered={filtered} gas_total={gas_total}");
+}
+
+pub(crate) fn print_no_prior_run() {
+    println!(
+        "No previous test run found, no tests will be run. Use --run-all-without-prior-run to run all tests instead"
+    );
+}
+
+pub(crate) fn print_interrupted() {
+    println!(
+        "Interrupted, waiting for running tests to finish. Press Ctrl-C again to abort immediately"
+    );
+}
+
 pub(crate) fn print_test_seed(seed: u64) {
     println!("{}: {seed}", style("Fuzzer seed").bold());
 }
 
+pub(crate) fn print_generated_fuzzer_seed(seed: u64) {
+    println!(
+        "{}: {seed}, it is reused by subsequent runs until --new-seed flag is passed",
+        style("Generated fuzzer seed").bold()
+    );
+}
+
 pub fn print_failures(all_failed_tests: &[AnyTestCaseSummary]) {
     if all_failed_tests.is_empty() {
         return;
@@ -61,6 +128,10 @@ pub fn print_failures(all_failed_tests: &[AnyTestCaseSummary]) {
     }
 }
 
+pub fn print_compact_results_legend() {
+    println!("\nLegend: {COMPACT_RESULTS_LEGEND}");
+}
+
 #[allow(clippy::implicit_hasher)]
 pub fn print_latest_blocks_numbers(url_to_latest_block_number_map: &HashMap<Url, BlockNumber>) {
     if !url_to_latest_block_number_map.is_empty() {
@@ -70,3 +141,27 @@ pub fn print_latest_blocks_numbers(url_to_latest_block_number_map: &HashMap<Url,
         println!("Latest block number = {latest_block_number} for url = {url}");
     }
 }
+
+pub(crate) fn print_listed_tests(listed_tests: &[ListedTest]) {
+    for test in listed_tests {
+        let attributes: Vec<&str> = [
+            (test.fuzzed, "fuzzed"),
+            (test.forked, "forked"),
+            (test.ignored, "ignored"),
+        ]
+        .into_iter()
+        .filter_map(|(is_set, attribute)| is_set.then_some(attribute))
+        .collect();
+
+        if attributes.is_empty() {
+            println!("{}", test.name);
+        } else {
+            println!("{} ({})", test.name, attributes.join(", "));
+        }
+    }
+    println!(
+        "{}: {} test(s) listed",
+        style("Tests").bold(),
+        listed_tests.len()
+    );
+}
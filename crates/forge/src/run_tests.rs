@@ -1,3 +1,4 @@
+pub mod list;
 pub mod package;
 pub mod resolve_config;
 pub mod test_target;
@@ -0,0 +1,131 @@
+use super::package::build_tests_filter;
+use crate::{
+    pretty_printing, scarb::load_test_artifacts, test_filter::TestListFilter, ExitStatus, TestArgs,
+};
+use anyhow::Result;
+use camino::{Utf8Path, Utf8PathBuf};
+use forge_runner::{
+    find_test_function, function_args,
+    package_tests::{
+        raw::TestTargetRaw,
+        with_config::TestCaseConfig,
+        with_config_resolved::{TestCaseResolvedConfig, TestCaseWithResolvedConfig},
+    },
+    TestCaseFilter, CACHE_DIR,
+};
+use scarb_metadata::{Metadata, PackageMetadata};
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Test case which would be run with the current filters
+#[derive(Serialize, Debug, PartialEq)]
+pub struct ListedTest {
+    pub name: String,
+    pub package: String,
+    /// Test has parameters, so it is run with the fuzzer
+    pub fuzzed: bool,
+    /// Test is marked with `#[fork]` attribute
+    pub forked: bool,
+    /// Test is collected, but not run due to `#[ignore]` attribute or other filters
+    pub ignored: bool,
+    /// Config of the test collected from its attributes, with forks not resolved
+    pub config: TestCaseConfig,
+}
+
+/// Prints tests of all packages which would be run with the current filters
+pub fn list_for_workspace(
+    args: &TestArgs,
+    packages: &[PackageMetadata],
+    scarb_metadata: &Metadata,
+    snforge_target_dir_path: &Utf8Path,
+) -> Result<ExitStatus> {
+    let cache_dir = scarb_metadata.workspace.root.join(CACHE_DIR);
+    let test_list_filter = args
+        .test_list
+        .as_deref()
+        .map(TestListFilter::from_file)
+        .transpose()?;
+
+    let mut listed_tests = vec![];
+    for package in packages {
+        listed_tests.extend(list_for_package(
+            package,
+            args,
+            &cache_dir,
+            snforge_target_dir_path,
+            test_list_filter.as_ref(),
+        )?);
+    }
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&listed_tests)?);
+    } else {
+        pretty_printing::print_listed_tests(&listed_tests);
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Collects tests matching the filters from the test artifacts, without compiling or running them
+pub fn list_for_package(
+    package: &PackageMetadata,
+    args: &TestArgs,
+    cache_dir: &Utf8PathBuf,
+    snforge_target_dir_path: &Utf8Path,
+    test_list_filter: Option<&TestListFilter>,
+) -> Result<Vec<ListedTest>> {
+    let tests_filter = build_tests_filter(args, test_list_filter, cache_dir);
+    let mut listed_tests = vec![];
+
+    for test_target in load_test_artifacts(snforge_target_dir_path, &package.name)? {
+        let mut test_target = TestTargetRaw::with_config(test_target);
+        let mut configs: HashMap<String, TestCaseConfig> = test_target
+            .test_cases
+            .iter()
+            .map(|case| (case.name.clone(), case.config.clone()))
+            .collect();
+        let skipped_forked = tests_filter.filter_by_fork(&mut test_target.test_cases);
+
+        let mut forked = vec![];
+        // Forks are not resolved, as it requires fetching the block number from the network
+        let mut test_cases: Vec<TestCaseWithResolvedConfig> = test_target
+            .test_cases
+            .into_iter()
+            .map(|case| {
+                if case.config.fork_config.is_some() {
+                    forked.push(case.name.clone());
+                }
+                TestCaseWithResolvedConfig {
+                    name: case.name,
+                    test_details: case.test_details,
+                    config: TestCaseResolvedConfig {
+                        available_gas: case.config.available_gas,
+                        ignored: case.config.ignored,
+                        expected_result: case.config.expected_result,
+                        fork_config: None,
+                        fuzzer_config: case.config.fuzzer_config,
+                    },
+                }
+            })
+            .collect();
+
+        tests_filter.filter_tests(&mut test_cases)?;
+
+        for case in test_cases {
+            let function = find_test_function(&test_target.sierra_program.program, &case.name)?;
+
+            listed_tests.push(ListedTest {
+                fuzzed: !function_args(function).is_empty(),
+                forked: forked.contains(&case.name) || skipped_forked.contains(&case.name),
+                ignored: !tests_filter.should_be_run(&case) || skipped_forked.contains(&case.name),
+                package: package.name.clone(),
+                config: configs
+                    .remove(&case.name)
+                    .expect("Config of every listed test is collected"),
+                name: case.name,
+            });
+        }
+    }
+
+    Ok(listed_tests)
+}
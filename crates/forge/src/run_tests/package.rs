@@ -4,14 +4,14 @@ use super::{
 };
 use crate::{
     block_number_map::BlockNumberMap,
-    combine_configs::combine_configs,
+    combine_configs::{combine_configs, ForgeConfigFromCli},
     pretty_printing,
     scarb::{
         config::{ForgeConfigFromScarb, ForkTarget},
         load_test_artifacts,
     },
-    shared_cache::FailedTestsCache,
-    test_filter::TestsFilter,
+    shared_cache::{CompiledTestsCache, FailedTestsCache, FuzzerSeedCache},
+    test_filter::{TestListFilter, TestsFilter, TestsFilterFlags},
     warn::{
         warn_if_available_gas_used_with_incompatible_scarb_version,
         warn_if_incompatible_rpc_version,
@@ -23,17 +23,24 @@ use camino::{Utf8Path, Utf8PathBuf};
 use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
 use configuration::load_package_config;
 use forge_runner::{
+    error_names::ErrorNames,
     forge_config::ForgeConfig,
+    package_report::PackageReport,
     package_tests::{
         raw::TestTargetRaw, with_config::TestTargetWithConfig,
         with_config_resolved::TestTargetWithResolvedConfig,
     },
-    test_case_summary::AnyTestCaseSummary,
     test_target_summary::TestTargetSummary,
+    SNAPSHOTS_DIR,
 };
+use futures::{stream, StreamExt};
 use scarb_api::get_contracts_artifacts_and_source_sierra_paths;
 use scarb_metadata::{Metadata, PackageMetadata};
-use std::sync::Arc;
+use std::{
+    collections::HashSet,
+    sync::{Arc, Mutex},
+};
+use tokio_util::sync::CancellationToken;
 
 pub struct RunForPackageArgs {
     pub test_targets: Vec<TestTargetWithConfig>,
@@ -41,9 +48,21 @@ pub struct RunForPackageArgs {
     pub forge_config: Arc<ForgeConfig>,
     pub fork_targets: Vec<ForkTarget>,
     pub package_name: String,
+    /// Cancelled when the run is interrupted with Ctrl-C
+    pub interrupt: CancellationToken,
+    /// Directory where the report of the package is saved
+    pub output_dir: Option<Utf8PathBuf>,
+}
+
+/// Results of tests of a package
+pub struct PackageSummary {
+    pub test_target_summaries: Vec<TestTargetSummary>,
+    /// Number of tests excluded by filters before the run
+    pub filtered: usize,
 }
 
 impl RunForPackageArgs {
+    #[allow(clippy::too_many_arguments)]
     pub fn build(
         package: PackageMetadata,
         scarb_metadata: &Metadata,
@@ -51,6 +70,10 @@ impl RunForPackageArgs {
         cache_dir: &Utf8PathBuf,
         snforge_target_dir_path: &Utf8Path,
         versioned_programs_dir: Utf8PathBuf,
+        results_jsonl_path: Option<Utf8PathBuf>,
+        test_list_filter: Option<&TestListFilter>,
+        interrupt: CancellationToken,
+        output_dir: Option<Utf8PathBuf>,
     ) -> Result<RunForPackageArgs> {
         let raw_test_targets = load_test_artifacts(snforge_target_dir_path, &package.name)?;
 
@@ -60,28 +83,33 @@ impl RunForPackageArgs {
 
         let forge_config_from_scarb =
             load_package_config::<ForgeConfigFromScarb>(scarb_metadata, &package.id)?;
+        let error_names = forge_config_from_scarb
+            .error_names
+            .as_ref()
+            .map(|path| ErrorNames::load(&package.root.join(path)))
+            .transpose()?
+            .unwrap_or_default();
+        // Seed is persisted only if it is not set explicitly
+        let fuzzer_seed = match args.fuzzer_seed.or(forge_config_from_scarb.fuzzer_seed) {
+            Some(fuzzer_seed) => fuzzer_seed,
+            None => FuzzerSeedCache::new(cache_dir).load_or_generate()?,
+        };
+        let forge_config_from_cli = ForgeConfigFromCli {
+            fuzzer_seed: Some(fuzzer_seed),
+            results_jsonl_path,
+            ..ForgeConfigFromCli::from(args)
+        };
         let forge_config = Arc::new(combine_configs(
-            args.exit_first,
-            args.fuzzer_runs,
-            args.fuzzer_seed,
-            args.detailed_resources,
-            args.save_trace_data,
-            args.build_profile,
-            args.max_n_steps,
+            forge_config_from_cli,
             contracts_data,
             cache_dir.clone(),
             versioned_programs_dir,
+            package.root.join(SNAPSHOTS_DIR),
+            error_names,
             &forge_config_from_scarb,
         ));
 
-        let test_filter = TestsFilter::from_flags(
-            args.test_filter.clone(),
-            args.exact,
-            args.only_ignored,
-            args.include_ignored,
-            args.rerun_failed,
-            FailedTestsCache::new(cache_dir),
-        );
+        let test_filter = build_tests_filter(args, test_list_filter, cache_dir);
 
         Ok(RunForPackageArgs {
             test_targets: raw_test_targets
@@ -92,10 +120,24 @@ impl RunForPackageArgs {
             tests_filter: test_filter,
             fork_targets: forge_config_from_scarb.fork,
             package_name: package.name,
+            interrupt,
+            output_dir,
         })
     }
 }
 
+pub(crate) fn build_tests_filter(
+    args: &TestArgs,
+    test_list_filter: Option<&TestListFilter>,
+    cache_dir: &Utf8PathBuf,
+) -> TestsFilter {
+    TestsFilter::from_flags(
+        TestsFilterFlags::from(args),
+        test_list_filter.cloned(),
+        FailedTestsCache::new(cache_dir),
+    )
+}
+
 pub async fn run_for_package(
     RunForPackageArgs {
         test_targets,
@@ -103,23 +145,26 @@ pub async fn run_for_package(
         tests_filter,
         fork_targets,
         package_name,
+        interrupt,
+        output_dir,
     }: RunForPackageArgs,
     block_number_map: &mut BlockNumberMap,
-) -> Result<Vec<TestTargetSummary>> {
+    compiled_tests_cache: Option<&CompiledTestsCache>,
+) -> Result<PackageSummary> {
     let mut test_targets_with_resolved_config: Vec<TestTargetWithResolvedConfig> =
         Vec::with_capacity(test_targets.len());
+    let mut all_tests = 0;
+    let mut skipped_forked = HashSet::new();
+
+    for mut test_target in test_targets {
+        all_tests += test_target.test_cases.len();
+        skipped_forked.extend(tests_filter.filter_by_fork(&mut test_target.test_cases));
 
-    for test_target in test_targets {
         let test_target = resolve_config(test_target, &fork_targets, block_number_map).await?;
 
         test_targets_with_resolved_config.push(test_target);
     }
 
-    let all_tests: usize = test_targets_with_resolved_config
-        .iter()
-        .map(|tc| tc.test_cases.len())
-        .sum();
-
     let test_targets = test_targets_with_resolved_config
         .into_iter()
         .map(|mut tc| {
@@ -136,45 +181,72 @@ pub async fn run_for_package(
     pretty_printing::print_collected_tests_count(not_filtered, &package_name);
 
     let mut summaries = vec![];
+    let package_report = output_dir
+        .as_ref()
+        .map(|_| Mutex::new(PackageReport::new(&package_name)));
+
+    // Every test case runs on its own state, so test targets can run at the same time without
+    // affecting each other. `buffered` yields the results in the order of test targets,
+    // output of test targets running at the same time is printed when they are yielded.
+    let test_target_concurrency = forge_config
+        .test_runner_config
+        .test_target_concurrency
+        .get();
+    let mut test_target_runs = stream::iter(test_targets)
+        .map(|test_target| {
+            run_for_test_target(
+                test_target,
+                forge_config.clone(),
+                &tests_filter,
+                &skipped_forked,
+                &package_name,
+                &interrupt,
+                package_report.as_ref(),
+                compiled_tests_cache,
+                test_target_concurrency > 1,
+            )
+        })
+        .buffered(test_target_concurrency);
 
-    for test_target in test_targets {
-        pretty_printing::print_running_tests(
-            test_target.tests_location,
-            test_target.test_cases.len(),
-        );
-
-        let forge_config = forge_config.clone();
-
-        let summary =
-            run_for_test_target(test_target, forge_config, &tests_filter, &package_name).await?;
-
-        match summary {
+    while let Some(test_target_run) = test_target_runs.next().await {
+        let (result, deferred_output) = test_target_run?;
+        if let Some(output) = deferred_output {
+            print!("{output}");
+        }
+        match result {
             TestTargetRunResult::Ok(summary) => {
                 summaries.push(summary);
             }
             TestTargetRunResult::Interrupted(summary) => {
                 summaries.push(summary);
-                // Handle scenario for --exit-first flag.
-                // Because snforge runs test crates one by one synchronously.
-                // In case of test FAIL with --exit-first flag stops processing the next crates
+                // Handle scenario for --exit-first flag and Ctrl-C.
+                // In case of test FAIL with --exit-first flag or an interruption stops processing the next test targets,
+                // test targets that are already running are dropped
                 break;
             }
         }
     }
+    drop(test_target_runs);
+
+    if let (Some(output_dir), Some(package_report)) = (&output_dir, package_report) {
+        package_report
+            .into_inner()
+            .expect("Failed to lock package report")
+            .save(output_dir)?;
+    }
 
     pretty_printing::print_test_summary(&summaries, filtered);
 
-    let any_fuzz_test_was_run = summaries.iter().any(|test_target_summary| {
-        test_target_summary
-            .test_case_summaries
-            .iter()
-            .filter(|summary| matches!(summary, AnyTestCaseSummary::Fuzzing(_)))
-            .any(|summary| summary.is_passed() || summary.is_failed())
-    });
+    let any_fuzz_test_was_run = summaries
+        .iter()
+        .any(TestTargetSummary::any_fuzz_test_was_run);
 
     if any_fuzz_test_was_run {
         pretty_printing::print_test_seed(forge_config.test_runner_config.fuzzer_seed);
     }
 
-    Ok(summaries)
+    Ok(PackageSummary {
+        test_target_summaries: summaries,
+        filtered,
+    })
 }
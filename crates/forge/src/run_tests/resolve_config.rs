@@ -1,5 +1,9 @@
-use crate::{block_number_map::BlockNumberMap, scarb::config::ForkTarget};
-use anyhow::{anyhow, Result};
+use crate::{
+    block_id::{parse_block_hash, parse_block_number},
+    block_number_map::BlockNumberMap,
+    scarb::config::ForkTarget,
+};
+use anyhow::{anyhow, ensure, Context, Result};
 use forge_runner::package_tests::{
     raw::{RawForkConfig, RawForkParams},
     with_config::TestTargetWithConfig,
@@ -8,8 +12,6 @@ use forge_runner::package_tests::{
         TestTargetWithResolvedConfig,
     },
 };
-use num_bigint::BigInt;
-use starknet_api::block::BlockNumber;
 use url::Url;
 
 pub async fn resolve_config(
@@ -20,6 +22,16 @@ pub async fn resolve_config(
     let mut test_cases = Vec::with_capacity(test_target.test_cases.len());
 
     for case in test_target.test_cases {
+        let fork_config =
+            resolve_fork_config(&case.config.fork_config, block_number_map, fork_targets)
+                .await
+                .with_context(|| {
+                    format!(
+                        "Failed to resolve fork configuration of test = {}",
+                        case.name
+                    )
+                })?;
+
         test_cases.push(TestCaseWithResolvedConfig {
             name: case.name,
             test_details: case.test_details,
@@ -27,12 +39,7 @@ pub async fn resolve_config(
                 available_gas: case.config.available_gas,
                 ignored: case.config.ignored,
                 expected_result: case.config.expected_result,
-                fork_config: resolve_fork_config(
-                    &case.config.fork_config,
-                    block_number_map,
-                    fork_targets,
-                )
-                .await?,
+                fork_config,
                 fuzzer_config: case.config.fuzzer_config,
             },
         });
@@ -56,19 +63,22 @@ async fn resolve_fork_config(
 
     let raw_fork_params = replace_id_with_params(fc, fork_targets)?;
 
-    let url: Url = raw_fork_params.url.parse()?;
+    let url = parse_fork_url(&raw_fork_params.url)?;
+
+    let live = raw_fork_params.block_id_type.to_lowercase() == "tag"
+        && raw_fork_params.block_id_value == "Live";
 
     let block_number = match raw_fork_params.block_id_type.to_lowercase().as_str() {
-        "number" => BlockNumber(raw_fork_params.block_id_value.parse()?),
+        "number" => parse_block_number(&raw_fork_params.block_id_value)?,
         "hash" => {
-            let block_hash = raw_fork_params.block_id_value.parse::<BigInt>()?;
+            let block_hash = parse_block_hash(&raw_fork_params.block_id_value)?;
 
             block_number_map
-                .get_block_number_for_hash(url.clone(), block_hash.into())
+                .get_block_number_for_hash(url.clone(), block_hash)
                 .await?
         }
         "tag" => {
-            assert_eq!(raw_fork_params.block_id_value, "Latest");
+            assert!(["Latest", "Live"].contains(&raw_fork_params.block_id_value.as_str()));
 
             block_number_map
                 .get_latest_block_number(url.clone())
@@ -77,7 +87,23 @@ async fn resolve_fork_config(
         _ => panic!(),
     };
 
-    Ok(Some(ResolvedForkConfig { url, block_number }))
+    Ok(Some(ResolvedForkConfig {
+        url,
+        block_number,
+        live,
+    }))
+}
+
+/// Only http and https urls are accepted, as forking is done with a JSON-RPC client over HTTP
+pub(crate) fn parse_fork_url(value: &str) -> Result<Url> {
+    let url =
+        Url::parse(value).map_err(|error| anyhow!("url = {value} is not a valid URL: {error}"))?;
+    ensure!(
+        matches!(url.scheme(), "http" | "https"),
+        "url = {value} has an unsupported scheme = {}. Expected http or https",
+        url.scheme()
+    );
+    Ok(url)
 }
 
 fn replace_id_with_params<'a>(
@@ -154,13 +180,19 @@ mod tests {
             tests_location: TestTargetLocation::Lib,
         };
 
-        assert!(resolve_config(
+        let error = resolve_config(
             mocked_tests.with_config(),
             &[],
-            &mut BlockNumberMap::default()
+            &mut BlockNumberMap::default(),
         )
         .await
-        .is_err());
+        .unwrap_err();
+
+        assert_eq!(
+            format!("{error:#}"),
+            "Failed to resolve fork configuration of test = crate1::do_thing: \
+            url = unparsable_url is not a valid URL: relative URL without a base"
+        );
     }
 
     #[tokio::test]
@@ -205,4 +237,21 @@ mod tests {
         .await
         .is_err());
     }
+
+    #[test]
+    fn parse_fork_urls() {
+        assert_eq!(
+            parse_fork_url("https://example.com/rpc").unwrap(),
+            Url::parse("https://example.com/rpc").unwrap()
+        );
+        assert!(parse_fork_url("http://127.0.0.1:5050").is_ok());
+        assert_eq!(
+            parse_fork_url("ws://example.com").unwrap_err().to_string(),
+            "url = ws://example.com has an unsupported scheme = ws. Expected http or https"
+        );
+        assert_eq!(
+            parse_fork_url("htps:/example.com").unwrap_err().to_string(),
+            "url = htps:/example.com has an unsupported scheme = htps. Expected http or https"
+        );
+    }
 }
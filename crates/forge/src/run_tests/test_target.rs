@@ -1,20 +1,33 @@
-use anyhow::Result;
-use cairo_lang_runner::RunnerError;
-use cairo_lang_sierra::ids::ConcreteTypeId;
+use crate::pretty_printing;
+use crate::shared_cache::CompiledTestsCache;
+use crate::test_filter::SKIPPED_FORKED_REASON;
+use anyhow::{Context, Result};
+use cairo_lang_sierra::program::Program;
 use forge_runner::{
-    forge_config::ForgeConfig,
-    function_args, maybe_save_execution_data, maybe_save_versioned_program,
-    package_tests::with_config_resolved::TestTargetWithResolvedConfig,
-    printing::print_test_result,
+    find_test_function,
+    forge_config::{ForgeConfig, ResultFormat},
+    maybe_save_execution_data, maybe_save_versioned_program,
+    package_report::PackageReport,
+    package_tests::{with_config_resolved::TestTargetWithResolvedConfig, TestTargetLocation},
+    printing::{format_test_result, format_test_result_compact},
+    results_jsonl::ResultsJsonlWriter,
     run_for_test_case,
     test_case_summary::{AnyTestCaseSummary, TestCaseSummary},
+    test_function_args,
     test_target_summary::TestTargetSummary,
     TestCaseFilter,
 };
-use futures::{stream::FuturesUnordered, StreamExt};
-use std::sync::Arc;
+use futures::{stream, StreamExt};
+use std::collections::HashSet;
+use std::io::Write;
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex};
 use tokio::sync::mpsc::channel;
-use universal_sierra_compiler_api::compile_sierra_to_casm;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use universal_sierra_compiler_api::compile_sierra_to_casm_with_warnings;
+
+type PendingTask = Box<dyn FnOnce() -> JoinHandle<Result<AnyTestCaseSummary>> + Send>;
 
 #[non_exhaustive]
 pub enum TestTargetRunResult {
@@ -22,17 +35,52 @@ pub enum TestTargetRunResult {
     Interrupted(TestTargetSummary),
 }
 
+/// Runs test cases of the test target, printing their results as they finish.
+/// With `defer_output`, the output is returned instead, so that output of test targets
+/// running at the same time is not interleaved
+#[allow(clippy::too_many_arguments)]
 pub async fn run_for_test_target(
     tests: TestTargetWithResolvedConfig,
     forge_config: Arc<ForgeConfig>,
     tests_filter: &impl TestCaseFilter,
+    skipped_forked: &HashSet<String>,
     package_name: &str,
-) -> Result<TestTargetRunResult> {
+    interrupt: &CancellationToken,
+    package_report: Option<&Mutex<PackageReport>>,
+    compiled_tests_cache: Option<&CompiledTestsCache>,
+    defer_output: bool,
+) -> Result<(TestTargetRunResult, Option<String>)> {
+    let mut deferred_output = defer_output.then(String::new);
+    print_or_defer(
+        &mut deferred_output,
+        &format!(
+            "{}\n",
+            pretty_printing::format_running_tests(tests.tests_location, tests.test_cases.len())
+        ),
+    );
+
     let sierra_program = &tests.sierra_program.program;
-    let casm_program = Arc::new(compile_sierra_to_casm(sierra_program)?);
+    let test_runner_config = &forge_config.test_runner_config;
+    // Programs with warnings which fail the run are not cached, so they are reported again
+    let compile = |program: &Program| -> Result<_> {
+        let (casm_program, warnings) = compile_sierra_to_casm_with_warnings(program)?;
+        test_runner_config.compile_warnings.check(&warnings)?;
+        Ok(casm_program)
+    };
+    let casm_program = match compiled_tests_cache {
+        Some(cache) => cache.load_or_compile(sierra_program, compile),
+        None => compile(sierra_program),
+    };
+    let casm_program = Arc::new(casm_program.with_context(|| {
+        let dir_name = match tests.tests_location {
+            TestTargetLocation::Lib => "src",
+            TestTargetLocation::Tests => "tests",
+        };
+        format!("Failed to compile tests from {dir_name}/ of package {package_name} to CASM")
+    })?);
 
-    let mut tasks = FuturesUnordered::new();
-    // Initiate two channels to manage the `--exit-first` flag.
+    let mut pending_tasks: Vec<PendingTask> = vec![];
+    // Initiate two channels to manage the `--exit-first` flag and Ctrl-C.
     // Owing to `cheatnet` fork's utilization of its own Tokio runtime for RPC requests,
     // test execution must occur within a `tokio::spawn_blocking`.
     // As `spawn_blocking` can't be prematurely cancelled (refer: https://dtantsur.github.io/rust-openstack/tokio/task/fn.spawn_blocking.html),
@@ -49,61 +97,151 @@ pub async fn run_for_test_target(
     for case in tests.test_cases {
         let case_name = case.name.clone();
 
-        if !tests_filter.should_be_run(&case) {
-            tasks.push(tokio::task::spawn(async {
-                // TODO TestCaseType should also be encoded in the test case definition
-                Ok(AnyTestCaseSummary::Single(TestCaseSummary::Ignored {
-                    name: case_name,
-                }))
+        let ignored = !tests_filter.should_be_run(&case);
+        if ignored || skipped_forked.contains(&case_name) {
+            // Tests ignored due to `#[ignore]` attribute or `--ignored` flag are displayed without a reason
+            let reason = (!ignored).then(|| SKIPPED_FORKED_REASON.to_string());
+            pending_tasks.push(Box::new(move || {
+                tokio::task::spawn(async {
+                    // TODO TestCaseType should also be encoded in the test case definition
+                    Ok(AnyTestCaseSummary::Single(TestCaseSummary::Ignored {
+                        name: case_name,
+                        reason,
+                    }))
+                })
             }));
             continue;
         };
 
-        let function = sierra_program
-            .funcs
-            .iter()
-            .find(|f| f.id.debug_name.as_ref().unwrap().ends_with(&case_name))
-            .ok_or(RunnerError::MissingFunction { suffix: case_name })?;
+        let function = find_test_function(sierra_program, &case_name)?;
 
-        let args = function_args(function);
+        let args = test_function_args(sierra_program, function);
 
         let case = Arc::new(case);
-        let args: Vec<ConcreteTypeId> = args.into_iter().cloned().collect();
-
-        tasks.push(run_for_test_case(
-            args,
-            case,
-            casm_program.clone(),
-            forge_config.clone(),
-            maybe_versioned_program_path.clone(),
-            send.clone(),
-        ));
+
+        let casm_program = casm_program.clone();
+        let forge_config = forge_config.clone();
+        let maybe_versioned_program_path = maybe_versioned_program_path.clone();
+        let send = send.clone();
+        pending_tasks.push(Box::new(move || {
+            run_for_test_case(
+                args,
+                case,
+                casm_program,
+                forge_config,
+                maybe_versioned_program_path,
+                send,
+            )
+        }));
     }
 
-    let mut results = vec![];
+    // Tests are started lazily in the order of declaration,
+    // so that at most `max_concurrency` of them run at the same time
+    let max_concurrency = forge_config
+        .test_runner_config
+        .max_concurrency
+        .map_or(usize::MAX, NonZeroUsize::get);
+    let mut tasks = stream::iter(pending_tasks)
+        .map(|start| start())
+        .buffer_unordered(max_concurrency);
+
+    let mut summary = TestTargetSummary::new(forge_config.output_config.summary_mode);
+    let mut results_writer = forge_config
+        .output_config
+        .results_jsonl_path
+        .as_deref()
+        .map(ResultsJsonlWriter::open)
+        .transpose()?;
     let mut interrupted = false;
-
-    while let Some(task) = tasks.next().await {
+    // With compact format, details of failed test cases are printed after results of all test cases
+    let mut failure_details = vec![];
+
+    loop {
+        let task = tokio::select! {
+            task = tasks.next() => task,
+            () = interrupt.cancelled(), if !interrupted => {
+                // Tests which are already running can't be cancelled, the remaining ones are skipped
+                interrupted = true;
+                rec.close();
+                continue;
+            }
+        };
+        let Some(task) = task else {
+            break;
+        };
         let result = task??;
 
-        print_test_result(&result, forge_config.output_config.detailed_resources);
+        let output_config = &forge_config.output_config;
+        match output_config.result_format {
+            ResultFormat::Detailed => {
+                if let Some(line) = format_test_result(
+                    &result,
+                    output_config.detailed_resources,
+                    output_config.fuzzer_stats,
+                    output_config.gas_unit,
+                    output_config.hide_passed_output,
+                    output_config.quiet,
+                ) {
+                    print_or_defer(&mut deferred_output, &format!("{line}\n"));
+                }
+            }
+            ResultFormat::Compact => {
+                if let Some(character) = format_test_result_compact(&result) {
+                    print_or_defer(&mut deferred_output, &character);
+                }
+                if result.is_failed() {
+                    failure_details.extend(format_test_result(
+                        &result,
+                        output_config.detailed_resources,
+                        output_config.fuzzer_stats,
+                        output_config.gas_unit,
+                        output_config.hide_passed_output,
+                        output_config.quiet,
+                    ));
+                }
+            }
+        }
         maybe_save_execution_data(&result, forge_config.output_config.execution_data_to_save)?;
+        if let Some(results_writer) = &mut results_writer {
+            results_writer.write(&result)?;
+        }
+        if let Some(package_report) = package_report {
+            package_report
+                .lock()
+                .expect("Failed to lock package report")
+                .add(&result);
+        }
 
         if result.is_failed() && forge_config.test_runner_config.exit_first {
             interrupted = true;
             rec.close();
         }
 
-        results.push(result);
+        summary.add(result);
     }
 
-    let summary = TestTargetSummary {
-        test_case_summaries: results,
-    };
+    if forge_config.output_config.result_format == ResultFormat::Compact {
+        print_or_defer(&mut deferred_output, "\n");
+        for details in failure_details {
+            print_or_defer(&mut deferred_output, &format!("{details}\n"));
+        }
+    }
 
-    if interrupted {
-        Ok(TestTargetRunResult::Interrupted(summary))
+    let result = if interrupted {
+        TestTargetRunResult::Interrupted(summary)
     } else {
-        Ok(TestTargetRunResult::Ok(summary))
+        TestTargetRunResult::Ok(summary)
+    };
+    Ok((result, deferred_output))
+}
+
+fn print_or_defer(deferred_output: &mut Option<String>, text: &str) {
+    match deferred_output {
+        Some(output) => output.push_str(text),
+        None => {
+            print!("{text}");
+            // Compact results are displayed as soon as test cases finish, not when the line ends
+            let _ = std::io::stdout().flush();
+        }
     }
 }
@@ -1,16 +1,20 @@
-use super::package::RunForPackageArgs;
+use super::package::{PackageSummary, RunForPackageArgs};
 use crate::{
     block_number_map::BlockNumberMap,
-    pretty_printing,
-    run_tests::package::run_for_package,
+    pretty_printing::{self, MachineSummary},
+    run_tests::{list::list_for_workspace, package::run_for_package},
     scarb::{build_contracts_with_scarb, build_test_artifacts_with_scarb},
-    shared_cache::FailedTestsCache,
-    warn::warn_if_snforge_std_not_compatible,
+    shared_cache::{CompiledTestsCache, FailedTestsCache, FuzzerSeedCache},
+    snforge_config::load_snforge_config,
+    test_filter::TestListFilter,
+    warn::{warn_if_snforge_std_not_compatible, warn_if_unknown_tests_in_list},
     ColorOption, ExitStatus, TestArgs,
 };
 use anyhow::{Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
 use forge_runner::{
     build_trace_data::test_sierra_program_path::VERSIONED_PROGRAMS_DIR,
+    forge_config::ResultFormat,
     test_case_summary::{AnyTestCaseSummary, TestCaseSummary},
 };
 use forge_runner::{test_target_summary::TestTargetSummary, CACHE_DIR};
@@ -19,7 +23,10 @@ use scarb_api::{
     target_dir_for_workspace, ScarbCommand,
 };
 use scarb_ui::args::PackagesFilter;
-use std::env;
+use std::{collections::HashSet, env, fs};
+use tokio::signal;
+use tokio_util::sync::CancellationToken;
+use universal_sierra_compiler_api::compiler_version;
 
 #[allow(clippy::too_many_lines)]
 pub async fn run_for_workspace(args: TestArgs) -> Result<ExitStatus> {
@@ -32,6 +39,10 @@ pub async fn run_for_workspace(args: TestArgs) -> Result<ExitStatus> {
     let scarb_metadata = ScarbCommand::metadata().inherit_stderr().run()?;
     warn_if_snforge_std_not_compatible(&scarb_metadata)?;
 
+    let snforge_config = load_snforge_config(&scarb_metadata.workspace.root, &args.profile)?;
+    let mut args = args.with_snforge_config(snforge_config);
+    let result_format = ResultFormat::from(args.format);
+
     let snforge_target_dir_path = target_dir_for_workspace(&scarb_metadata)
         .join(&scarb_metadata.current_profile)
         .join("snforge");
@@ -44,16 +55,63 @@ pub async fn run_for_workspace(args: TestArgs) -> Result<ExitStatus> {
     let filter = PackagesFilter::generate_for::<Metadata>(packages.iter());
 
     build_test_artifacts_with_scarb(filter.clone())?;
+
+    // Listing needs only the collected tests, so contracts are not built
+    if args.list {
+        return list_for_workspace(&args, &packages, &scarb_metadata, &snforge_target_dir_path);
+    }
+
     build_contracts_with_scarb(filter)?;
 
     let mut block_number_map = BlockNumberMap::default();
     let mut all_failed_tests = vec![];
+    let mut tests_run = 0;
+    let mut machine_summary = MachineSummary::default();
 
     let workspace_root = &scarb_metadata.workspace.root;
     let cache_dir = workspace_root.join(CACHE_DIR);
     let versioned_programs_dir = workspace_root.join(VERSIONED_PROGRAMS_DIR);
+    if args.new_seed {
+        FuzzerSeedCache::new(&cache_dir).clear()?;
+    }
+    if args.rerun_failed
+        && !args.run_all_without_prior_run
+        && FailedTestsCache::new(&cache_dir).load()?.is_none()
+    {
+        pretty_printing::print_no_prior_run();
+    }
+    let compiled_tests_cache = if args.no_compile_cache {
+        None
+    } else {
+        Some(CompiledTestsCache::new(&cache_dir, compiler_version()?))
+    };
+    let results_jsonl_path = args
+        .results_jsonl
+        .as_deref()
+        .map(create_results_jsonl_file)
+        .transpose()?;
+    let test_list_filter = args
+        .test_list
+        .as_deref()
+        .map(TestListFilter::from_file)
+        .transpose()?;
+    let output_dir = args
+        .output_dir
+        .as_deref()
+        .map(|path| create_dir(path, "output directory"))
+        .transpose()?;
+    args.save_trace = args
+        .save_trace
+        .as_deref()
+        .map(|path| create_dir(path, "trace directory"))
+        .transpose()?;
+    let mut collected_test_names = HashSet::new();
+    let interrupt = handle_ctrl_c();
 
     for package in packages {
+        if interrupt.is_cancelled() {
+            break;
+        }
         env::set_current_dir(&package.root)?;
 
         let args = RunForPackageArgs::build(
@@ -63,23 +121,101 @@ pub async fn run_for_workspace(args: TestArgs) -> Result<ExitStatus> {
             &cache_dir,
             &snforge_target_dir_path,
             versioned_programs_dir.clone(),
+            results_jsonl_path.clone(),
+            test_list_filter.as_ref(),
+            interrupt.clone(),
+            output_dir.clone(),
         )?;
 
-        let tests_file_summaries = run_for_package(args, &mut block_number_map).await?;
-
+        collected_test_names.extend(
+            args.test_targets
+                .iter()
+                .flat_map(|test_target| &test_target.test_cases)
+                .map(|test_case| test_case.name.clone()),
+        );
+
+        let PackageSummary {
+            test_target_summaries: tests_file_summaries,
+            filtered,
+        } = run_for_package(args, &mut block_number_map, compiled_tests_cache.as_ref()).await?;
+        machine_summary.add(&tests_file_summaries, filtered);
+
+        tests_run += tests_file_summaries
+            .iter()
+            .map(|summary| summary.count_passed() + summary.count_failed())
+            .sum::<usize>();
         all_failed_tests.extend(extract_failed_tests(tests_file_summaries));
     }
 
+    if let Some(test_list_filter) = &test_list_filter {
+        warn_if_unknown_tests_in_list(test_list_filter, &collected_test_names);
+    }
+
     FailedTestsCache::new(&cache_dir).save_failed_tests(&all_failed_tests)?;
 
     pretty_printing::print_latest_blocks_numbers(block_number_map.get_url_to_latest_block_number());
     pretty_printing::print_failures(&all_failed_tests);
+    if result_format == ResultFormat::Compact {
+        pretty_printing::print_compact_results_legend();
+    }
+    if args.machine_summary {
+        pretty_printing::print_machine_summary(&machine_summary);
+    }
 
-    Ok(if all_failed_tests.is_empty() {
-        ExitStatus::Success
-    } else {
+    Ok(exit_status(
+        all_failed_tests.is_empty(),
+        interrupt.is_cancelled(),
+        tests_run,
+    ))
+}
+
+fn exit_status(all_passed: bool, interrupted: bool, tests_run: usize) -> ExitStatus {
+    if !all_passed || interrupted {
         ExitStatus::Failure
-    })
+    } else if tests_run == 0 {
+        ExitStatus::NoTestsRun
+    } else {
+        ExitStatus::Success
+    }
+}
+
+/// Cancels the returned token on the first Ctrl-C, so that no new tests are started
+/// and the summary of tests run so far is printed. Aborts the run on the second Ctrl-C.
+fn handle_ctrl_c() -> CancellationToken {
+    let interrupt = CancellationToken::new();
+    let token = interrupt.clone();
+
+    tokio::spawn(async move {
+        if signal::ctrl_c().await.is_err() {
+            return;
+        }
+        pretty_printing::print_interrupted();
+        token.cancel();
+
+        if signal::ctrl_c().await.is_ok() {
+            std::process::exit(130);
+        }
+    });
+
+    interrupt
+}
+
+/// Creates an empty results file and returns its absolute path,
+/// as the working directory changes when running tests for each package
+fn create_results_jsonl_file(path: &Utf8Path) -> Result<Utf8PathBuf> {
+    fs::File::create(path).with_context(|| format!("Failed to create results file at {path}"))?;
+
+    Ok(Utf8PathBuf::from_path_buf(fs::canonicalize(path)?)
+        .expect("Failed to convert results file path to Utf8PathBuf"))
+}
+
+/// Creates a directory for package reports or traces and returns its absolute path,
+/// so that relative paths are resolved against the directory snforge is run in
+fn create_dir(path: &Utf8Path, name: &str) -> Result<Utf8PathBuf> {
+    fs::create_dir_all(path).with_context(|| format!("Failed to create {name} at {path}"))?;
+
+    Ok(Utf8PathBuf::from_path_buf(fs::canonicalize(path)?)
+        .expect("Failed to convert directory path to Utf8PathBuf"))
 }
 
 fn extract_failed_tests(
@@ -96,3 +232,18 @@ fn extract_failed_tests(
             )
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exit_codes() {
+        assert_eq!(exit_status(true, false, 5).code(), 0);
+        assert_eq!(exit_status(false, false, 5).code(), 1);
+        assert_eq!(exit_status(true, true, 5).code(), 1);
+        assert_eq!(exit_status(true, false, 0).code(), 3);
+        // Interrupting the run before any test finished is not a successful empty run
+        assert_eq!(exit_status(true, true, 0), ExitStatus::Failure);
+    }
+}
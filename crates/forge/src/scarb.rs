@@ -63,11 +63,15 @@ mod tests {
     use assert_fs::TempDir;
     use camino::Utf8PathBuf;
     use configuration::load_package_config;
+    use conversions::string::TryFromHexStr;
+    use forge_runner::forge_config::BlockInfoOverrides;
     use forge_runner::package_tests::raw::RawForkParams;
     use indoc::{formatdoc, indoc};
     use scarb_api::metadata::MetadataCommandExt;
     use scarb_metadata::PackageId;
+    use starknet_api::core::ContractAddress;
     use std::env;
+    use std::num::NonZeroU128;
     use std::str::FromStr;
     use test_utils::tempdir_with_tool_versions;
 
@@ -106,12 +110,17 @@ mod tests {
                 [[tool.snforge.fork]]
                 name = "SECOND_FORK_NAME"
                 url = "http://some.rpc.url"
-                block_id.hash = "1"
+                block_id.hash = "0x1"
 
                 [[tool.snforge.fork]]
                 name = "THIRD_FORK_NAME"
                 url = "http://some.rpc.url"
                 block_id.tag = "Latest"
+
+                [[tool.snforge.fork]]
+                name = "FOURTH_FORK_NAME"
+                url = "http://some.rpc.url"
+                block_id.tag = "Live"
                 "#,
                 package_name,
                 snforge_std_path
@@ -154,7 +163,7 @@ mod tests {
                         RawForkParams {
                             url: "http://some.rpc.url".to_string(),
                             block_id_type: "hash".to_string(),
-                            block_id_value: "1".to_string(),
+                            block_id_value: "0x1".to_string(),
                         },
                     ),
                     ForkTarget::new(
@@ -164,14 +173,28 @@ mod tests {
                             block_id_type: "tag".to_string(),
                             block_id_value: "Latest".to_string(),
                         },
+                    ),
+                    ForkTarget::new(
+                        "FOURTH_FORK_NAME".to_string(),
+                        RawForkParams {
+                            url: "http://some.rpc.url".to_string(),
+                            block_id_type: "tag".to_string(),
+                            block_id_value: "Live".to_string(),
+                        },
                     )
                 ],
                 fuzzer_runs: None,
                 fuzzer_seed: None,
+                fuzzer_max_rejects: None,
+                fuzzer_max_length: None,
+                fuzzer_exhaustive_threshold: None,
                 max_n_steps: None,
+                contract_cache_size: None,
                 detailed_resources: false,
                 save_trace_data: false,
-                build_profile: false
+                build_profile: false,
+                block_info: Default::default(),
+                error_names: None,
             }
         );
     }
@@ -240,7 +263,7 @@ mod tests {
             [[tool.snforge.fork]]
             name = "SAME_NAME"
             url = "http://some.rpc.url"
-            block_id.hash = "1"
+            block_id.hash = "0x1"
             "#
         );
         temp.child("Scarb.toml").write_str(content).unwrap();
@@ -351,7 +374,115 @@ mod tests {
             &scarb_metadata.workspace.members[0],
         )
         .unwrap_err();
-        assert!(format!("{err:?}").contains("block_id.tag can only be equal to Latest"));
+        assert!(format!("{err:?}").contains("block_id.tag can only be equal to Latest or Live"));
+    }
+
+    #[test]
+    fn get_forge_config_for_package_fails_on_invalid_block_hash() {
+        let temp = setup_package("simple_package");
+        let content = indoc!(
+            r#"
+            [package]
+            name = "simple_package"
+            version = "0.1.0"
+
+            [[tool.snforge.fork]]
+            name = "SAME_NAME"
+            url = "http://some.rpc.url"
+            block_id.hash = "0xzz"
+            "#
+        );
+        temp.child("Scarb.toml").write_str(content).unwrap();
+
+        let scarb_metadata = ScarbCommand::metadata()
+            .inherit_stderr()
+            .current_dir(temp.path())
+            .run()
+            .unwrap();
+
+        let err = load_package_config::<ForgeConfigFromScarb>(
+            &scarb_metadata,
+            &scarb_metadata.workspace.members[0],
+        )
+        .unwrap_err();
+        assert!(format!("{err:?}").contains(
+            "block_id.hash = 0xzz is not a valid block hash. Expected only hex digits after 0x"
+        ));
+    }
+
+    #[test]
+    fn get_forge_config_for_package_with_block_info() {
+        let temp = setup_package("simple_package");
+        let content = indoc!(
+            r#"
+            [package]
+            name = "simple_package"
+            version = "0.1.0"
+
+            [tool.snforge.block_info]
+            block_number = 123
+            block_timestamp = 1000
+            sequencer_address = "0x1234"
+            eth_l1_gas_price = 10
+            strk_l1_data_gas_price = 20
+            "#
+        );
+        temp.child("Scarb.toml").write_str(content).unwrap();
+
+        let scarb_metadata = ScarbCommand::metadata()
+            .inherit_stderr()
+            .current_dir(temp.path())
+            .run()
+            .unwrap();
+
+        let config = load_package_config::<ForgeConfigFromScarb>(
+            &scarb_metadata,
+            &scarb_metadata.workspace.members[0],
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.block_info,
+            BlockInfoOverrides {
+                block_number: Some(123),
+                block_timestamp: Some(1000),
+                sequencer_address: Some(ContractAddress::try_from_hex_str("0x1234").unwrap()),
+                eth_l1_gas_price: NonZeroU128::new(10),
+                strk_l1_gas_price: None,
+                eth_l1_data_gas_price: None,
+                strk_l1_data_gas_price: NonZeroU128::new(20),
+            }
+        );
+    }
+
+    #[test]
+    fn get_forge_config_for_package_fails_on_invalid_sequencer_address() {
+        let temp = setup_package("simple_package");
+        let content = indoc!(
+            r#"
+            [package]
+            name = "simple_package"
+            version = "0.1.0"
+
+            [tool.snforge.block_info]
+            sequencer_address = "not_an_address"
+            "#
+        );
+        temp.child("Scarb.toml").write_str(content).unwrap();
+
+        let scarb_metadata = ScarbCommand::metadata()
+            .inherit_stderr()
+            .current_dir(temp.path())
+            .run()
+            .unwrap();
+
+        let err = load_package_config::<ForgeConfigFromScarb>(
+            &scarb_metadata,
+            &scarb_metadata.workspace.members[0],
+        )
+        .unwrap_err();
+        assert!(format!("{err:?}")
+            .contains("block_info.sequencer_address = not_an_address is not a valid address"));
     }
 
     #[test]
@@ -398,10 +529,16 @@ mod tests {
                 )],
                 fuzzer_runs: None,
                 fuzzer_seed: None,
+                fuzzer_max_rejects: None,
+                fuzzer_max_length: None,
+                fuzzer_exhaustive_threshold: None,
                 max_n_steps: None,
+                contract_cache_size: None,
                 detailed_resources: false,
                 save_trace_data: false,
-                build_profile: false
+                build_profile: false,
+                block_info: Default::default(),
+                error_names: None,
             }
         );
     }
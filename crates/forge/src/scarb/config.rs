@@ -1,10 +1,15 @@
-use anyhow::{bail, Result};
+use crate::block_id::{parse_block_hash, parse_block_number};
+use anyhow::{anyhow, bail, Result};
+use camino::Utf8PathBuf;
+use conversions::string::TryFromHexStr;
+use forge_runner::forge_config::BlockInfoOverrides;
 use forge_runner::package_tests::raw::RawForkParams;
 use itertools::Itertools;
 use serde::Deserialize;
+use starknet_api::core::ContractAddress;
 use std::{
     collections::{HashMap, HashSet},
-    num::NonZeroU32,
+    num::{NonZeroU128, NonZeroUsize},
 };
 
 #[allow(clippy::module_name_repetitions)]
@@ -14,9 +19,15 @@ pub struct ForgeConfigFromScarb {
     /// Should runner exit after first failed test
     pub exit_first: bool,
     /// How many runs should fuzzer execute
-    pub fuzzer_runs: Option<NonZeroU32>,
+    pub fuzzer_runs: Option<u32>,
     /// Seed to be used by fuzzer
     pub fuzzer_seed: Option<u64>,
+    /// How many consecutive runs of a fuzz test can be rejected with `assume`
+    pub fuzzer_max_rejects: Option<u32>,
+    /// Maximum length of arrays and byte arrays generated by the fuzzer
+    pub fuzzer_max_length: Option<u32>,
+    /// Largest number of combinations of fuzz test arguments for which all of them are run
+    pub fuzzer_exhaustive_threshold: Option<u32>,
     /// Display more detailed info about used resources
     pub detailed_resources: bool,
     /// Save execution traces of all test which have passed and are not fuzz tests
@@ -27,6 +38,12 @@ pub struct ForgeConfigFromScarb {
     pub fork: Vec<ForkTarget>,
     /// Limit of steps
     pub max_n_steps: Option<u32>,
+    /// Number of compiled contract classes kept in the cache of a single test
+    pub contract_cache_size: Option<NonZeroUsize>,
+    /// Values overriding block info of the test environment
+    pub block_info: BlockInfoOverrides,
+    /// File with names of custom errors, relative to the package root
+    pub error_names: Option<Utf8PathBuf>,
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -60,9 +77,15 @@ pub(crate) struct RawForgeConfig {
     /// Should runner exit after first failed test
     pub exit_first: bool,
     /// How many runs should fuzzer execute
-    pub fuzzer_runs: Option<NonZeroU32>,
+    pub fuzzer_runs: Option<u32>,
     /// Seed to be used by fuzzer
     pub fuzzer_seed: Option<u64>,
+    /// How many consecutive runs of a fuzz test can be rejected with `assume`
+    pub fuzzer_max_rejects: Option<u32>,
+    /// Maximum length of arrays and byte arrays generated by the fuzzer
+    pub fuzzer_max_length: Option<u32>,
+    /// Largest number of combinations of fuzz test arguments for which all of them are run
+    pub fuzzer_exhaustive_threshold: Option<u32>,
     #[serde(default)]
     // Display more detailed info about used resources
     pub detailed_resources: bool,
@@ -77,6 +100,13 @@ pub(crate) struct RawForgeConfig {
     pub fork: Vec<RawForkTarget>,
     /// Limit of steps
     pub max_n_steps: Option<u32>,
+    /// Number of compiled contract classes kept in the cache of a single test
+    pub contract_cache_size: Option<NonZeroUsize>,
+    #[serde(default)]
+    /// Values overriding block info of the test environment
+    pub block_info: RawBlockInfo,
+    /// File with names of custom errors, relative to the package root
+    pub error_names: Option<Utf8PathBuf>,
 }
 
 #[derive(Deserialize, Debug, PartialEq, Default, Clone)]
@@ -86,6 +116,43 @@ pub(crate) struct RawForkTarget {
     pub block_id: HashMap<String, String>,
 }
 
+#[derive(Deserialize, Debug, PartialEq, Default, Clone)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RawBlockInfo {
+    pub block_number: Option<u64>,
+    pub block_timestamp: Option<u64>,
+    pub sequencer_address: Option<String>,
+    pub eth_l1_gas_price: Option<NonZeroU128>,
+    pub strk_l1_gas_price: Option<NonZeroU128>,
+    pub eth_l1_data_gas_price: Option<NonZeroU128>,
+    pub strk_l1_data_gas_price: Option<NonZeroU128>,
+}
+
+impl TryFrom<RawBlockInfo> for BlockInfoOverrides {
+    type Error = anyhow::Error;
+
+    fn try_from(value: RawBlockInfo) -> Result<Self, Self::Error> {
+        let sequencer_address = value
+            .sequencer_address
+            .map(|address| {
+                ContractAddress::try_from_hex_str(&address).map_err(|_| {
+                    anyhow!("block_info.sequencer_address = {address} is not a valid address")
+                })
+            })
+            .transpose()?;
+
+        Ok(BlockInfoOverrides {
+            block_number: value.block_number,
+            block_timestamp: value.block_timestamp,
+            sequencer_address,
+            eth_l1_gas_price: value.eth_l1_gas_price,
+            strk_l1_gas_price: value.strk_l1_gas_price,
+            eth_l1_data_gas_price: value.eth_l1_data_gas_price,
+            strk_l1_data_gas_price: value.strk_l1_data_gas_price,
+        })
+    }
+}
+
 fn validate_raw_fork_config(raw_config: RawForgeConfig) -> Result<RawForgeConfig> {
     let forks = &raw_config.fork;
 
@@ -107,8 +174,17 @@ fn validate_raw_fork_config(raw_config: RawForgeConfig) -> Result<RawForgeConfig
             bail!("block_id = {block_id_key} is not valid. Possible values are = \"number\", \"hash\" and \"tag\"");
         }
 
-        if block_id_key == "tag" && block_id_value != "Latest" {
-            bail!("block_id.tag can only be equal to Latest");
+        match block_id_key.as_str() {
+            "tag" if !["Latest", "Live"].contains(&block_id_value.as_str()) => {
+                bail!("block_id.tag can only be equal to Latest or Live");
+            }
+            "number" => {
+                parse_block_number(block_id_value)?;
+            }
+            "hash" => {
+                parse_block_hash(block_id_value)?;
+            }
+            _ => {}
         }
     }
 
@@ -140,11 +216,17 @@ impl TryFrom<RawForgeConfig> for ForgeConfigFromScarb {
             exit_first: value.exit_first,
             fuzzer_runs: value.fuzzer_runs,
             fuzzer_seed: value.fuzzer_seed,
+            fuzzer_max_rejects: value.fuzzer_max_rejects,
+            fuzzer_max_length: value.fuzzer_max_length,
+            fuzzer_exhaustive_threshold: value.fuzzer_exhaustive_threshold,
             detailed_resources: value.detailed_resources,
             save_trace_data: value.save_trace_data,
             build_profile: value.build_profile,
             fork: fork_targets,
             max_n_steps: value.max_n_steps,
+            contract_cache_size: value.contract_cache_size,
+            block_info: value.block_info.try_into()?,
+            error_names: value.error_names,
         })
     }
 }
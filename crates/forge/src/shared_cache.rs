@@ -1,8 +1,14 @@
-use anyhow::Result;
+use crate::pretty_printing;
+use anyhow::{anyhow, Context, Result};
+use cairo_lang_sierra::program::Program;
 use camino::Utf8PathBuf;
 use forge_runner::test_case_summary::AnyTestCaseSummary;
+use rand::{thread_rng, RngCore};
+use sha2::{Digest, Sha256};
+use shared::print::print_as_warning;
 use std::fs::File;
 use std::io::{BufRead, BufReader, BufWriter, ErrorKind, Write};
+use universal_sierra_compiler_api::AssembledProgramWithDebugInfo;
 
 #[derive(Debug, PartialEq, Default, Clone)]
 pub struct FailedTestsCache {
@@ -18,17 +24,19 @@ impl FailedTestsCache {
         }
     }
 
-    pub fn load(&self) -> Result<Vec<String>> {
+    /// Returns names of tests that failed during the last run,
+    /// or `None` if there was no previous run
+    pub fn load(&self) -> Result<Option<Vec<String>>> {
         let file = match File::open(&self.cache_file) {
             Ok(file) => file,
-            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(vec![]),
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
             Err(err) => Err(err)?,
         };
         let buf: BufReader<File> = BufReader::new(file);
 
         let tests = buf.lines().collect::<Result<Vec<_>, _>>()?;
 
-        Ok(tests)
+        Ok(Some(tests))
     }
 
     pub fn save_failed_tests(&self, all_failed_tests: &[AnyTestCaseSummary]) -> Result<()> {
@@ -46,3 +54,225 @@ impl FailedTestsCache {
         Ok(())
     }
 }
+
+const FILE_WITH_FUZZER_SEED: &str = ".fuzzer_seed";
+
+/// Fuzzer seed used when no seed is set explicitly,
+/// persisted so it is reused by subsequent runs
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct FuzzerSeedCache {
+    cache_file: Utf8PathBuf,
+}
+
+impl FuzzerSeedCache {
+    pub fn new(cache_dir: &Utf8PathBuf) -> Self {
+        Self {
+            cache_file: cache_dir.join(FILE_WITH_FUZZER_SEED),
+        }
+    }
+
+    /// Returns the persisted seed, or generates and persists a new one if there is none
+    pub fn load_or_generate(&self) -> Result<u64> {
+        match std::fs::read_to_string(&self.cache_file) {
+            Ok(seed) => {
+                return seed.trim().parse().with_context(|| {
+                    format!("Failed to parse fuzzer seed from {}", self.cache_file)
+                })
+            }
+            Err(err) if err.kind() == ErrorKind::NotFound => {}
+            Err(err) => Err(err)?,
+        }
+
+        let seed = thread_rng().next_u64();
+        std::fs::create_dir_all(self.cache_file.parent().unwrap())?;
+        std::fs::write(&self.cache_file, seed.to_string())?;
+        pretty_printing::print_generated_fuzzer_seed(seed);
+
+        Ok(seed)
+    }
+
+    /// Removes the persisted seed, so a new one is generated by the next run
+    pub fn clear(&self) -> Result<()> {
+        match std::fs::remove_file(&self.cache_file) {
+            Err(err) if err.kind() != ErrorKind::NotFound => Err(err)?,
+            _ => Ok(()),
+        }
+    }
+}
+
+const COMPILED_TESTS_DIR: &str = "compiled_tests";
+
+/// Test targets compiled to CASM by previous runs, saved as `compiled_tests/<hash>.json`.
+/// The hash covers the Sierra program and the versions of snforge and universal-sierra-compiler,
+/// so a change of any of them compiles the tests again
+pub struct CompiledTestsCache {
+    dir: Utf8PathBuf,
+    compiler_version: String,
+}
+
+impl CompiledTestsCache {
+    pub fn new(cache_dir: &Utf8PathBuf, compiler_version: String) -> Self {
+        Self {
+            dir: cache_dir.join(COMPILED_TESTS_DIR),
+            compiler_version,
+        }
+    }
+
+    /// Returns the program compiled by a previous run, or compiles it with `compile` and saves it.
+    /// The cache is best-effort: files which can't be read as a compiled program are compiled again
+    /// and failing to save the program only prints a warning
+    pub fn load_or_compile(
+        &self,
+        sierra_program: &Program,
+        compile: impl FnOnce(&Program) -> Result<AssembledProgramWithDebugInfo>,
+    ) -> Result<AssembledProgramWithDebugInfo> {
+        let cache_file = self
+            .dir
+            .join(format!("{}.json", self.program_hash(sierra_program)?));
+
+        if let Ok(content) = std::fs::read(&cache_file) {
+            if let Ok(program) = serde_json::from_slice(&content) {
+                return Ok(program);
+            }
+        }
+
+        let program = compile(sierra_program)?;
+        if let Err(error) = self.save(&cache_file, &program) {
+            print_as_warning(&anyhow!(
+                "Failed to save compiled tests to {cache_file}: {error:#}"
+            ));
+        }
+
+        Ok(program)
+    }
+
+    fn save(
+        &self,
+        cache_file: &Utf8PathBuf,
+        program: &AssembledProgramWithDebugInfo,
+    ) -> Result<()> {
+        let content = serde_json::to_vec(program)?;
+        std::fs::create_dir_all(&self.dir)?;
+        std::fs::write(cache_file, content)?;
+        Ok(())
+    }
+
+    fn program_hash(&self, sierra_program: &Program) -> Result<String> {
+        let hash = Sha256::new()
+            .chain_update(env!("CARGO_PKG_VERSION"))
+            .chain_update([0])
+            .chain_update(&self.compiler_version)
+            .chain_update([0])
+            .chain_update(serde_json::to_vec(sierra_program)?)
+            .finalize();
+
+        Ok(format!("{hash:x}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cairo_lang_sierra::program::Statement;
+    use tempfile::tempdir;
+    use universal_sierra_compiler_api::AssembledCairoProgramWithSerde;
+
+    #[test]
+    fn seed_is_reused() {
+        let cache_dir = Utf8PathBuf::from_path_buf(tempdir().unwrap().into_path()).unwrap();
+        let cache = FuzzerSeedCache::new(&cache_dir);
+
+        let seed = cache.load_or_generate().unwrap();
+
+        assert_eq!(cache.load_or_generate().unwrap(), seed);
+        assert_eq!(
+            std::fs::read_to_string(cache_dir.join(FILE_WITH_FUZZER_SEED)).unwrap(),
+            seed.to_string()
+        );
+    }
+
+    #[test]
+    fn cleared_seed_is_regenerated() {
+        let cache_dir = Utf8PathBuf::from_path_buf(tempdir().unwrap().into_path()).unwrap();
+        let cache = FuzzerSeedCache::new(&cache_dir);
+        std::fs::write(cache_dir.join(FILE_WITH_FUZZER_SEED), "123").unwrap();
+
+        assert_eq!(cache.load_or_generate().unwrap(), 123);
+        cache.clear().unwrap();
+        assert!(!cache_dir.join(FILE_WITH_FUZZER_SEED).exists());
+        cache.clear().unwrap();
+    }
+
+    fn sierra_program(n_statements: usize) -> Program {
+        Program {
+            type_declarations: vec![],
+            libfunc_declarations: vec![],
+            statements: vec![Statement::Return(vec![]); n_statements],
+            funcs: vec![],
+        }
+    }
+
+    /// Returns whether the program had to be compiled
+    fn load_or_compile(cache: &CompiledTestsCache, program: &Program) -> bool {
+        let mut compiled = false;
+        cache
+            .load_or_compile(program, |_| {
+                compiled = true;
+                Ok(AssembledProgramWithDebugInfo {
+                    assembled_cairo_program: AssembledCairoProgramWithSerde {
+                        bytecode: vec![1.into()],
+                        hints: vec![],
+                    },
+                    debug_info: vec![],
+                })
+            })
+            .unwrap();
+        compiled
+    }
+
+    #[test]
+    fn compiled_program_is_reused() {
+        let cache_dir = Utf8PathBuf::from_path_buf(tempdir().unwrap().into_path()).unwrap();
+        let cache = CompiledTestsCache::new(&cache_dir, "1.0.0".to_string());
+
+        assert!(load_or_compile(&cache, &sierra_program(1)));
+        assert!(!load_or_compile(&cache, &sierra_program(1)));
+        assert!(load_or_compile(&cache, &sierra_program(2)));
+    }
+
+    #[test]
+    fn compiler_version_change_invalidates_cache() {
+        let cache_dir = Utf8PathBuf::from_path_buf(tempdir().unwrap().into_path()).unwrap();
+
+        let cache = CompiledTestsCache::new(&cache_dir, "1.0.0".to_string());
+        assert!(load_or_compile(&cache, &sierra_program(1)));
+
+        let cache = CompiledTestsCache::new(&cache_dir, "2.0.0".to_string());
+        assert!(load_or_compile(&cache, &sierra_program(1)));
+    }
+
+    #[test]
+    fn corrupted_cache_file_is_compiled_again() {
+        let cache_dir = Utf8PathBuf::from_path_buf(tempdir().unwrap().into_path()).unwrap();
+        let cache = CompiledTestsCache::new(&cache_dir, "1.0.0".to_string());
+        load_or_compile(&cache, &sierra_program(1));
+
+        for entry in std::fs::read_dir(cache_dir.join(COMPILED_TESTS_DIR)).unwrap() {
+            std::fs::write(entry.unwrap().path(), "{").unwrap();
+        }
+
+        assert!(load_or_compile(&cache, &sierra_program(1)));
+        assert!(!load_or_compile(&cache, &sierra_program(1)));
+    }
+
+    #[test]
+    fn failing_to_save_program_is_not_an_error() {
+        let cache_dir = Utf8PathBuf::from_path_buf(tempdir().unwrap().into_path()).unwrap();
+        // The cache directory can't be created where a file exists
+        std::fs::write(cache_dir.join(COMPILED_TESTS_DIR), "").unwrap();
+        let cache = CompiledTestsCache::new(&cache_dir, "1.0.0".to_string());
+
+        assert!(load_or_compile(&cache, &sierra_program(1)));
+        assert!(load_or_compile(&cache, &sierra_program(1)));
+    }
+}
@@ -0,0 +1,152 @@
+use crate::TestArgs;
+use anyhow::Result;
+use camino::Utf8PathBuf;
+use configuration::{load_global_config, GlobalConfig};
+use serde::Deserialize;
+use std::num::NonZeroUsize;
+
+/// Runner options defined in `[snforge.<profile>]` table of snfoundry.toml,
+/// used when they are not passed as flags
+#[derive(Deserialize, Debug, Default, PartialEq)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct SnforgeConfig {
+    #[serde(default)]
+    pub exit_first: bool,
+    pub fuzzer_runs: Option<u32>,
+    pub fuzzer_seed: Option<u64>,
+    pub fuzzer_max_rejects: Option<u32>,
+    pub fuzzer_max_length: Option<u32>,
+    pub fuzzer_exhaustive_threshold: Option<u32>,
+    pub max_n_steps: Option<u32>,
+    pub contract_cache_size: Option<NonZeroUsize>,
+    pub test_target_concurrency: Option<NonZeroUsize>,
+}
+
+impl GlobalConfig for SnforgeConfig {
+    #[must_use]
+    fn tool_name() -> &'static str {
+        "snforge"
+    }
+
+    fn from_raw(config: serde_json::Value) -> Result<Self> {
+        Ok(serde_json::from_value::<SnforgeConfig>(config)?)
+    }
+}
+
+impl TestArgs {
+    /// Fills the options which were not passed as flags with the values from the profile
+    pub(crate) fn with_snforge_config(mut self, config: SnforgeConfig) -> Self {
+        self.exit_first |= config.exit_first;
+        self.fuzzer_runs = self.fuzzer_runs.or(config.fuzzer_runs);
+        self.fuzzer_seed = self.fuzzer_seed.or(config.fuzzer_seed);
+        self.fuzzer_max_rejects = self.fuzzer_max_rejects.or(config.fuzzer_max_rejects);
+        self.fuzzer_max_length = self.fuzzer_max_length.or(config.fuzzer_max_length);
+        self.fuzzer_exhaustive_threshold = self
+            .fuzzer_exhaustive_threshold
+            .or(config.fuzzer_exhaustive_threshold);
+        self.max_n_steps = self.max_n_steps.or(config.max_n_steps);
+        self.contract_cache_size = self.contract_cache_size.or(config.contract_cache_size);
+        self.test_target_concurrency = self
+            .test_target_concurrency
+            .or(config.test_target_concurrency);
+        self
+    }
+}
+
+/// Loads the profile from snfoundry.toml found in the workspace root or its parents.
+/// Like in sncast, a profile other than `default` which is not defined is an error.
+pub(crate) fn load_snforge_config(
+    workspace_root: &Utf8PathBuf,
+    profile: &Option<String>,
+) -> Result<SnforgeConfig> {
+    load_global_config::<SnforgeConfig>(&Some(workspace_root.clone()), profile)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use configuration::CONFIG_FILENAME;
+    use indoc::indoc;
+    use tempfile::tempdir;
+
+    fn workspace_with_config(config: &str) -> (tempfile::TempDir, Utf8PathBuf) {
+        let temp_dir = tempdir().unwrap();
+        let workspace_root = Utf8PathBuf::try_from(temp_dir.path().to_path_buf()).unwrap();
+        std::fs::write(workspace_root.join(CONFIG_FILENAME), config).unwrap();
+        (temp_dir, workspace_root)
+    }
+
+    #[test]
+    fn load_profile() {
+        let (_temp_dir, workspace_root) = workspace_with_config(indoc! {r"
+            [snforge.default]
+            fuzzer-runs = 10
+
+            [snforge.ci]
+            exit-first = true
+            fuzzer-runs = 1000
+            fuzzer-seed = 123
+            test-target-concurrency = 4
+
+            [sncast.default]
+            url = 'http://127.0.0.1:5050/rpc'
+        "});
+
+        assert_eq!(
+            load_snforge_config(&workspace_root, &None).unwrap(),
+            SnforgeConfig {
+                fuzzer_runs: Some(10),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            load_snforge_config(&workspace_root, &Some("ci".to_string())).unwrap(),
+            SnforgeConfig {
+                exit_first: true,
+                fuzzer_runs: Some(1000),
+                fuzzer_seed: Some(123),
+                test_target_concurrency: Some(NonZeroUsize::new(4).unwrap()),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn flags_override_profile() {
+        let args = TestArgs::parse_from(["snforge", "--fuzzer-runs", "5"]).with_snforge_config(
+            SnforgeConfig {
+                exit_first: true,
+                fuzzer_runs: Some(1000),
+                fuzzer_seed: Some(123),
+                ..Default::default()
+            },
+        );
+
+        assert!(args.exit_first);
+        assert_eq!(args.fuzzer_runs, Some(5));
+        assert_eq!(args.fuzzer_seed, Some(123));
+        assert_eq!(args.test_target_concurrency, None);
+    }
+
+    #[test]
+    fn missing_profile_is_rejected() {
+        let (_temp_dir, workspace_root) = workspace_with_config(indoc! {r"
+            [snforge.default]
+            fuzzer-runs = 10
+        "});
+
+        let error = load_snforge_config(&workspace_root, &Some("missing".to_string())).unwrap_err();
+        assert_eq!(error.to_string(), "Profile [missing] not found in config");
+    }
+
+    #[test]
+    fn unknown_option_is_rejected() {
+        let (_temp_dir, workspace_root) = workspace_with_config(indoc! {r"
+            [snforge.default]
+            fuzzer_runs = 10
+        "});
+
+        assert!(load_snforge_config(&workspace_root, &None).is_err());
+    }
+}
@@ -1,7 +1,12 @@
 use crate::shared_cache::FailedTestsCache;
-use anyhow::Result;
+use crate::TestArgs;
+use anyhow::{Context, Result};
+use camino::Utf8Path;
+use forge_runner::package_tests::with_config::TestCaseWithConfig;
 use forge_runner::package_tests::with_config_resolved::TestCaseWithResolvedConfig;
+use forge_runner::test_case_filter::{And, BoxedTestCaseFilter, Predicate};
 use forge_runner::TestCaseFilter;
+use std::collections::HashSet;
 
 #[derive(Debug, PartialEq)]
 // Specifies what tests should be included
@@ -12,6 +17,12 @@ pub struct TestsFilter {
     ignored_filter: IgnoredFilter,
     // based on rerun_failed flag
     last_failed_filter: bool,
+    // run all tests with rerun_failed flag if there was no previous run
+    run_all_without_prior_run: bool,
+    // based on `--test-list` file
+    test_list_filter: Option<TestListFilter>,
+    // based on `#[fork]` attribute
+    fork_filter: ForkFilter,
 
     failed_tests_cache: FailedTestsCache,
 }
@@ -30,21 +41,130 @@ pub(crate) enum IgnoredFilter {
     All,
 }
 
+#[derive(Debug, PartialEq)]
+pub(crate) enum ForkFilter {
+    All,
+    SkipForked,
+    OnlyForked,
+}
+
+/// Flags of `snforge test` selecting which tests are run
+#[derive(Debug, Default, Clone)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct TestsFilterFlags {
+    pub test_name_filter: Option<String>,
+    pub exact_match: bool,
+    pub only_ignored: bool,
+    pub include_ignored: bool,
+    pub rerun_failed: bool,
+    pub run_all_without_prior_run: bool,
+    pub skip_forked: bool,
+    pub only_forked: bool,
+}
+
+impl From<&TestArgs> for TestsFilterFlags {
+    fn from(args: &TestArgs) -> Self {
+        Self {
+            test_name_filter: args.test_filter.clone(),
+            exact_match: args.exact,
+            only_ignored: args.only_ignored,
+            include_ignored: args.include_ignored,
+            rerun_failed: args.rerun_failed,
+            run_all_without_prior_run: args.run_all_without_prior_run,
+            skip_forked: args.skip_forked,
+            only_forked: args.only_forked,
+        }
+    }
+}
+
+/// Reason displayed for tests which are not run due to `--skip-forked` flag
+pub const SKIPPED_FORKED_REASON: &str = "requires a fork";
+
+/// Filters tests based on a list of fully qualified test names read from a file
+#[derive(Debug, PartialEq, Clone)]
+pub struct TestListFilter {
+    test_names: HashSet<String>,
+}
+
+impl TestListFilter {
+    /// Reads test names from a file, one per line.
+    /// Empty lines and everything following `#` are ignored.
+    pub fn from_file(path: &Utf8Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read test list file at {path}"))?;
+
+        Ok(Self::from_contents(&contents))
+    }
+
+    fn from_contents(contents: &str) -> Self {
+        let test_names = contents
+            .lines()
+            .map(|line| line.split('#').next().unwrap_or_default().trim())
+            .filter(|name| !name.is_empty())
+            .map(ToString::to_string)
+            .collect();
+
+        Self { test_names }
+    }
+
+    /// Returns sorted names from the list which do not match any of `known_test_names`
+    #[must_use]
+    pub fn unknown_test_names(&self, known_test_names: &HashSet<String>) -> Vec<&str> {
+        let mut unknown: Vec<&str> = self
+            .test_names
+            .iter()
+            .filter(|name| !known_test_names.contains(*name))
+            .map(String::as_str)
+            .collect();
+        unknown.sort_unstable();
+        unknown
+    }
+}
+
+impl TestCaseFilter for TestListFilter {
+    fn should_be_run(&self, test_case: &TestCaseWithResolvedConfig) -> bool {
+        self.test_names.contains(&test_case.name)
+    }
+}
+
+/// Filters tests that failed during the previous run
+#[derive(Debug, PartialEq, Clone)]
+struct FailedTestsFilter {
+    failed_tests: Vec<String>,
+}
+
+impl TestCaseFilter for FailedTestsFilter {
+    fn should_be_run(&self, test_case: &TestCaseWithResolvedConfig) -> bool {
+        self.failed_tests.contains(&test_case.name)
+    }
+}
+
 impl TestsFilter {
     #[must_use]
-    #[allow(clippy::fn_params_excessive_bools)]
     pub fn from_flags(
-        test_name_filter: Option<String>,
-        exact_match: bool,
-        only_ignored: bool,
-        include_ignored: bool,
-        rerun_failed: bool,
+        flags: TestsFilterFlags,
+        test_list_filter: Option<TestListFilter>,
         failed_tests_cache: FailedTestsCache,
     ) -> Self {
+        let TestsFilterFlags {
+            test_name_filter,
+            exact_match,
+            only_ignored,
+            include_ignored,
+            rerun_failed,
+            run_all_without_prior_run,
+            skip_forked,
+            only_forked,
+        } = flags;
+
         assert!(
             !(only_ignored && include_ignored),
             "Arguments only_ignored and include_ignored cannot be both true"
         );
+        assert!(
+            !(skip_forked && only_forked),
+            "Arguments skip_forked and only_forked cannot be both true"
+        );
 
         let ignored_filter = if include_ignored {
             IgnoredFilter::All
@@ -65,34 +185,81 @@ impl TestsFilter {
             NameFilter::All
         };
 
+        let fork_filter = if skip_forked {
+            ForkFilter::SkipForked
+        } else if only_forked {
+            ForkFilter::OnlyForked
+        } else {
+            ForkFilter::All
+        };
+
         Self {
             name_filter,
             ignored_filter,
             last_failed_filter: rerun_failed,
+            run_all_without_prior_run,
+            test_list_filter,
+            fork_filter,
             failed_tests_cache,
         }
     }
 
+    /// Applies `--only-forked` and `--skip-forked` flags before forks of the tests are resolved,
+    /// so that skipped tests don't require access to the network.
+    /// Tests without `#[fork]` attribute are removed with `--only-forked`.
+    /// Tests with `#[fork]` attribute have their fork removed with `--skip-forked`
+    /// and their names are returned, so they are reported as ignored if the other filters include them.
+    pub(crate) fn filter_by_fork(
+        &self,
+        test_cases: &mut Vec<TestCaseWithConfig>,
+    ) -> HashSet<String> {
+        match self.fork_filter {
+            ForkFilter::All => HashSet::new(),
+            ForkFilter::OnlyForked => {
+                test_cases.retain(|tc| tc.config.fork_config.is_some());
+                HashSet::new()
+            }
+            ForkFilter::SkipForked => test_cases
+                .iter_mut()
+                .filter_map(|tc| tc.config.fork_config.take().map(|_| tc.name.clone()))
+                .collect(),
+        }
+    }
+
     pub(crate) fn filter_tests(
         &self,
         test_cases: &mut Vec<TestCaseWithResolvedConfig>,
     ) -> Result<()> {
+        let mut filters: Vec<BoxedTestCaseFilter> = vec![];
+
         match &self.name_filter {
             NameFilter::All => {}
             NameFilter::Match(filter) => {
-                test_cases.retain(|tc| tc.name.contains(filter));
+                filters.push(Box::new(Predicate(|tc: &TestCaseWithResolvedConfig| {
+                    tc.name.contains(filter)
+                })));
             }
-
             NameFilter::ExactMatch(name) => {
-                test_cases.retain(|tc| tc.name == *name);
+                filters.push(Box::new(Predicate(|tc: &TestCaseWithResolvedConfig| {
+                    tc.name == *name
+                })));
             }
         };
 
+        if let Some(test_list_filter) = &self.test_list_filter {
+            filters.push(Box::new(test_list_filter));
+        }
+
         if self.last_failed_filter {
-            match self.failed_tests_cache.load()?.as_slice() {
-                [] => {}
-                result => {
-                    test_cases.retain(|tc| result.iter().any(|name| name == &tc.name));
+            match self.failed_tests_cache.load()? {
+                Some(failed_tests) => {
+                    filters.push(Box::new(FailedTestsFilter { failed_tests }));
+                }
+                None if self.run_all_without_prior_run => {}
+                // The message is printed once for the whole run in `run_for_workspace`
+                None => {
+                    test_cases.clear();
+                    return Ok(());
                 }
             }
         }
@@ -101,10 +268,15 @@ impl TestsFilter {
             // if NotIgnored (default) we filter ignored tests later and display them as ignored
             IgnoredFilter::All | IgnoredFilter::NotIgnored => {}
             IgnoredFilter::Ignored => {
-                test_cases.retain(|tc| tc.config.ignored);
+                filters.push(Box::new(Predicate(|tc: &TestCaseWithResolvedConfig| {
+                    tc.config.ignored
+                })));
             }
         };
 
+        let filter = And(filters);
+        test_cases.retain(|tc| filter.should_be_run(tc));
+
         Ok(())
     }
 }
@@ -123,13 +295,20 @@ impl TestCaseFilter for TestsFilter {
 
 #[cfg(test)]
 mod tests {
-    use crate::test_filter::TestsFilter;
+    use crate::shared_cache::FailedTestsCache;
+    use crate::test_filter::{TestListFilter, TestsFilter, TestsFilterFlags};
     use cairo_lang_sierra::program::{Program, ProgramArtifact, Version, VersionedProgram};
+    use camino::Utf8PathBuf;
     use forge_runner::expected_result::ExpectedTestResult;
+    use forge_runner::package_tests::raw::RawForkConfig;
+    use forge_runner::package_tests::with_config::{TestCaseConfig, TestCaseWithConfig};
     use forge_runner::package_tests::with_config_resolved::{
         TestCaseResolvedConfig, TestCaseWithResolvedConfig, TestTargetWithResolvedConfig,
     };
     use forge_runner::package_tests::{TestDetails, TestTargetLocation};
+    use indoc::indoc;
+    use std::collections::HashSet;
+    use tempfile::tempdir;
 
     fn program_for_testing() -> VersionedProgram {
         VersionedProgram::V1 {
@@ -149,13 +328,28 @@ mod tests {
     #[test]
     #[should_panic(expected = "Arguments only_ignored and include_ignored cannot be both true")]
     fn from_flags_only_ignored_and_include_ignored_both_true() {
-        let _ = TestsFilter::from_flags(None, false, true, true, false, Default::default());
+        let _ = TestsFilter::from_flags(
+            TestsFilterFlags {
+                only_ignored: true,
+                include_ignored: true,
+                ..Default::default()
+            },
+            None,
+            Default::default(),
+        );
     }
 
     #[test]
     #[should_panic(expected = "Argument test_name_filter cannot be None with exact_match")]
     fn from_flags_exact_match_true_without_test_filter_name() {
-        let _ = TestsFilter::from_flags(None, true, false, false, false, Default::default());
+        let _ = TestsFilter::from_flags(
+            TestsFilterFlags {
+                exact_match: true,
+                ..Default::default()
+            },
+            None,
+            Default::default(),
+        );
     }
 
     #[test]
@@ -217,11 +411,11 @@ mod tests {
         };
 
         let tests_filter = TestsFilter::from_flags(
-            Some("do".to_string()),
-            false,
-            false,
-            false,
-            false,
+            TestsFilterFlags {
+                test_name_filter: Some("do".to_string()),
+                ..Default::default()
+            },
+            None,
             Default::default(),
         );
 
@@ -246,11 +440,11 @@ mod tests {
         );
 
         let tests_filter = TestsFilter::from_flags(
-            Some("te2::run".to_string()),
-            false,
-            false,
-            false,
-            false,
+            TestsFilterFlags {
+                test_name_filter: Some("te2::run".to_string()),
+                ..Default::default()
+            },
+            None,
             Default::default(),
         );
 
@@ -274,11 +468,11 @@ mod tests {
         );
 
         let tests_filter = TestsFilter::from_flags(
-            Some("thing".to_string()),
-            false,
-            false,
-            false,
-            false,
+            TestsFilterFlags {
+                test_name_filter: Some("thing".to_string()),
+                ..Default::default()
+            },
+            None,
             Default::default(),
         );
 
@@ -340,11 +534,11 @@ mod tests {
         );
 
         let tests_filter = TestsFilter::from_flags(
-            Some("nonexistent".to_string()),
-            false,
-            false,
-            false,
-            false,
+            TestsFilterFlags {
+                test_name_filter: Some("nonexistent".to_string()),
+                ..Default::default()
+            },
+            None,
             Default::default(),
         );
 
@@ -354,11 +548,11 @@ mod tests {
         assert_eq!(filtered.test_cases, vec![]);
 
         let tests_filter = TestsFilter::from_flags(
-            Some(String::new()),
-            false,
-            false,
-            false,
-            false,
+            TestsFilterFlags {
+                test_name_filter: Some(String::new()),
+                ..Default::default()
+            },
+            None,
             Default::default(),
         );
 
@@ -429,11 +623,11 @@ mod tests {
         };
 
         let tests_filter = TestsFilter::from_flags(
-            Some(String::new()),
-            false,
-            false,
-            false,
-            false,
+            TestsFilterFlags {
+                test_name_filter: Some(String::new()),
+                ..Default::default()
+            },
+            None,
             Default::default(),
         );
 
@@ -443,11 +637,11 @@ mod tests {
         assert_eq!(filtered.test_cases, vec![]);
 
         let tests_filter = TestsFilter::from_flags(
-            Some("thing".to_string()),
-            false,
-            false,
-            false,
-            false,
+            TestsFilterFlags {
+                test_name_filter: Some("thing".to_string()),
+                ..Default::default()
+            },
+            None,
             Default::default(),
         );
 
@@ -516,11 +710,12 @@ mod tests {
         };
 
         let tests_filter = TestsFilter::from_flags(
-            Some(String::new()),
-            true,
-            false,
-            false,
-            false,
+            TestsFilterFlags {
+                test_name_filter: Some(String::new()),
+                exact_match: true,
+                ..Default::default()
+            },
+            None,
             Default::default(),
         );
 
@@ -530,11 +725,12 @@ mod tests {
         assert_eq!(filtered.test_cases, vec![]);
 
         let tests_filter = TestsFilter::from_flags(
-            Some("thing".to_string()),
-            true,
-            false,
-            false,
-            false,
+            TestsFilterFlags {
+                test_name_filter: Some("thing".to_string()),
+                exact_match: true,
+                ..Default::default()
+            },
+            None,
             Default::default(),
         );
 
@@ -544,11 +740,12 @@ mod tests {
         assert_eq!(filtered.test_cases, vec![]);
 
         let tests_filter = TestsFilter::from_flags(
-            Some("do_thing".to_string()),
-            true,
-            false,
-            false,
-            false,
+            TestsFilterFlags {
+                test_name_filter: Some("do_thing".to_string()),
+                exact_match: true,
+                ..Default::default()
+            },
+            None,
             Default::default(),
         );
 
@@ -572,11 +769,12 @@ mod tests {
         );
 
         let tests_filter = TestsFilter::from_flags(
-            Some("crate1::do_thing".to_string()),
-            true,
-            false,
-            false,
-            false,
+            TestsFilterFlags {
+                test_name_filter: Some("crate1::do_thing".to_string()),
+                exact_match: true,
+                ..Default::default()
+            },
+            None,
             Default::default(),
         );
 
@@ -600,11 +798,12 @@ mod tests {
         );
 
         let tests_filter = TestsFilter::from_flags(
-            Some("crate3::run_other_thing".to_string()),
-            true,
-            false,
-            false,
-            false,
+            TestsFilterFlags {
+                test_name_filter: Some("crate3::run_other_thing".to_string()),
+                exact_match: true,
+                ..Default::default()
+            },
+            None,
             Default::default(),
         );
 
@@ -614,11 +813,12 @@ mod tests {
         assert_eq!(filtered.test_cases, vec![]);
 
         let tests_filter = TestsFilter::from_flags(
-            Some("outer::crate3::run_other_thing".to_string()),
-            true,
-            false,
-            false,
-            false,
+            TestsFilterFlags {
+                test_name_filter: Some("outer::crate3::run_other_thing".to_string()),
+                exact_match: true,
+                ..Default::default()
+            },
+            None,
             Default::default(),
         );
 
@@ -699,8 +899,14 @@ mod tests {
             tests_location: TestTargetLocation::Tests,
         };
 
-        let tests_filter =
-            TestsFilter::from_flags(None, false, true, false, false, Default::default());
+        let tests_filter = TestsFilter::from_flags(
+            TestsFilterFlags {
+                only_ignored: true,
+                ..Default::default()
+            },
+            None,
+            Default::default(),
+        );
         let mut filtered = mocked_tests;
         tests_filter.filter_tests(&mut filtered.test_cases).unwrap();
 
@@ -793,8 +999,14 @@ mod tests {
             tests_location: TestTargetLocation::Tests,
         };
 
-        let tests_filter =
-            TestsFilter::from_flags(None, false, false, true, false, Default::default());
+        let tests_filter = TestsFilter::from_flags(
+            TestsFilterFlags {
+                include_ignored: true,
+                ..Default::default()
+            },
+            None,
+            Default::default(),
+        );
         let mut filtered = mocked_tests;
         tests_filter.filter_tests(&mut filtered.test_cases).unwrap();
 
@@ -852,4 +1064,161 @@ mod tests {
             ]
         );
     }
+
+    fn test_case(name: &str) -> TestCaseWithResolvedConfig {
+        TestCaseWithResolvedConfig {
+            name: name.to_string(),
+            test_details: TestDetails::default(),
+
+            config: TestCaseResolvedConfig {
+                available_gas: None,
+                ignored: false,
+                expected_result: ExpectedTestResult::Success,
+                fork_config: None,
+                fuzzer_config: None,
+            },
+        }
+    }
+
+    #[test]
+    fn filtering_with_rerun_failed() {
+        let test_cases = vec![test_case("crate1::failing"), test_case("crate1::passing")];
+        let cache_dir = Utf8PathBuf::from_path_buf(tempdir().unwrap().into_path()).unwrap();
+        let filter_names = |run_all_without_prior_run: bool| {
+            let tests_filter = TestsFilter::from_flags(
+                TestsFilterFlags {
+                    rerun_failed: true,
+                    run_all_without_prior_run,
+                    ..Default::default()
+                },
+                None,
+                FailedTestsCache::new(&cache_dir),
+            );
+            let mut filtered = test_cases.clone();
+            tests_filter.filter_tests(&mut filtered).unwrap();
+            filtered.into_iter().map(|tc| tc.name).collect::<Vec<_>>()
+        };
+
+        assert!(filter_names(false).is_empty());
+        assert_eq!(
+            filter_names(true),
+            vec!["crate1::failing", "crate1::passing"]
+        );
+
+        std::fs::write(cache_dir.join(".prev_tests_failed"), "crate1::failing\n").unwrap();
+
+        assert_eq!(filter_names(false), vec!["crate1::failing"]);
+        assert_eq!(filter_names(true), vec!["crate1::failing"]);
+    }
+
+    #[test]
+    fn filtering_with_test_list() {
+        let test_cases = vec![
+            test_case("crate1::first"),
+            test_case("crate1::second"),
+            test_case("crate1::third"),
+        ];
+        let test_list_filter = TestListFilter::from_contents(indoc! {"
+            # tests to run
+            crate1::first
+            crate1::third # slow one
+
+            crate1::nonexistent
+        "});
+
+        let tests_filter = TestsFilter::from_flags(
+            TestsFilterFlags::default(),
+            Some(test_list_filter.clone()),
+            Default::default(),
+        );
+        let mut filtered = test_cases.clone();
+        tests_filter.filter_tests(&mut filtered).unwrap();
+
+        assert_eq!(
+            filtered.into_iter().map(|tc| tc.name).collect::<Vec<_>>(),
+            vec!["crate1::first", "crate1::third"]
+        );
+
+        let known_test_names = test_cases.into_iter().map(|tc| tc.name).collect();
+        assert_eq!(
+            test_list_filter.unknown_test_names(&known_test_names),
+            vec!["crate1::nonexistent"]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Arguments skip_forked and only_forked cannot be both true")]
+    fn from_flags_skip_forked_and_only_forked_both_true() {
+        let _ = TestsFilter::from_flags(
+            TestsFilterFlags {
+                skip_forked: true,
+                only_forked: true,
+                ..Default::default()
+            },
+            None,
+            Default::default(),
+        );
+    }
+
+    #[test]
+    fn filtering_by_fork() {
+        let test_case = |name: &str, forked: bool| TestCaseWithConfig {
+            name: name.to_string(),
+            test_details: TestDetails::default(),
+            config: TestCaseConfig {
+                available_gas: None,
+                ignored: false,
+                expected_result: ExpectedTestResult::Success,
+                fork_config: forked.then(|| RawForkConfig::Id("MAINNET".to_string())),
+                fuzzer_config: None,
+            },
+        };
+        let test_cases = vec![
+            test_case("crate1::forked", true),
+            test_case("crate1::not_forked", false),
+        ];
+        let filter_by_fork = |skip_forked: bool, only_forked: bool| {
+            let tests_filter = TestsFilter::from_flags(
+                TestsFilterFlags {
+                    skip_forked,
+                    only_forked,
+                    ..Default::default()
+                },
+                None,
+                Default::default(),
+            );
+            let mut filtered = test_cases.clone();
+            let skipped = tests_filter.filter_by_fork(&mut filtered);
+            let forked: Vec<_> = filtered
+                .into_iter()
+                .map(|tc| (tc.name, tc.config.fork_config.is_some()))
+                .collect();
+            (forked, skipped)
+        };
+
+        let (forked, skipped) = filter_by_fork(false, false);
+        assert_eq!(
+            forked,
+            vec![
+                ("crate1::forked".to_string(), true),
+                ("crate1::not_forked".to_string(), false)
+            ]
+        );
+        assert!(skipped.is_empty());
+
+        let (forked, skipped) = filter_by_fork(false, true);
+        assert_eq!(forked, vec![("crate1::forked".to_string(), true)]);
+        assert!(skipped.is_empty());
+
+        // Skipped tests are kept to be filtered with the other filters, without a fork to resolve
+        let (forked, skipped) = filter_by_fork(true, false);
+        assert_eq!(
+            forked,
+            vec![
+                ("crate1::forked".to_string(), false),
+                ("crate1::not_forked".to_string(), false)
+            ]
+        );
+        assert_eq!(skipped, HashSet::from(["crate1::forked".to_string()]));
+    }
 }
@@ -1,3 +1,4 @@
+use crate::test_filter::TestListFilter;
 use anyhow::{anyhow, Result};
 use forge_runner::package_tests::with_config_resolved::TestTargetWithResolvedConfig;
 use scarb_api::{package_matches_version_requirement, ScarbCommand};
@@ -86,3 +87,17 @@ pub fn warn_if_snforge_std_not_compatible(scarb_metadata: &Metadata) -> Result<(
     }
     Ok(())
 }
+
+pub(crate) fn warn_if_unknown_tests_in_list(
+    test_list_filter: &TestListFilter,
+    collected_test_names: &HashSet<String>,
+) {
+    let unknown_test_names = test_list_filter.unknown_test_names(collected_test_names);
+
+    if !unknown_test_names.is_empty() {
+        print_as_warning(&anyhow!(
+            "The following tests from the test list were not found: {}",
+            unknown_test_names.join(", ")
+        ));
+    }
+}
@@ -271,6 +271,25 @@ pub fn assert_case_output_contains(
     }));
 }
 
+pub fn assert_case_printed_output_contains(
+    result: &[TestTargetSummary],
+    test_case_name: &str,
+    asserted_output: &str,
+) {
+    let test_name_suffix = format!("::{test_case_name}");
+
+    let result = TestCase::find_test_result(result);
+
+    assert!(result.test_case_summaries.iter().any(|any_case| {
+        any_case
+            .name()
+            .is_some_and(|name| name.ends_with(test_name_suffix.as_str()))
+            && any_case
+                .printed_output()
+                .is_some_and(|output| output.contains(asserted_output))
+    }));
+}
+
 pub fn assert_gas(result: &[TestTargetSummary], test_case_name: &str, asserted_gas: u128) {
     let test_name_suffix = format!("::{test_case_name}");
 
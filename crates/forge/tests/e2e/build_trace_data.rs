@@ -1,4 +1,4 @@
-use super::common::runner::{setup_package, test_runner};
+use super::common::runner::{setup_hello_workspace, setup_package, test_runner};
 use crate::e2e::common::get_trace_from_trace_node;
 use cairo_lang_sierra::program::VersionedProgram;
 use cairo_lang_starknet_classes::contract_class::ContractClass;
@@ -163,3 +163,75 @@ fn trace_has_deploy_with_no_constructor_phantom_nodes() {
         trace_data::CallTraceNode::DeployWithoutConstructor
     );
 }
+
+#[test]
+fn save_vm_trace_of_failed_tests() {
+    let temp = setup_package("simple_package");
+    test_runner(&temp)
+        .args(["--save-trace", "vm_traces"])
+        .assert()
+        .code(1);
+
+    let traces_dir = temp.join("vm_traces");
+    let trace =
+        fs::read(traces_dir.join("tests::test_simple::test_failing.trace")).expect("Missing trace");
+    // Each trace entry consists of 3 64-bit numbers
+    assert!(!trace.is_empty());
+    assert_eq!(trace.len() % 24, 0);
+    let memory = fs::read(traces_dir.join("tests::test_simple::test_failing.memory"))
+        .expect("Missing memory");
+    // Each memory cell consists of a 64-bit address and a 256-bit value
+    assert!(!memory.is_empty());
+    assert_eq!(memory.len() % 40, 0);
+
+    assert!(traces_dir
+        .join("tests::test_simple::test_another_failing.trace")
+        .exists());
+    assert!(!traces_dir
+        .join("tests::test_simple::test_simple.trace")
+        .exists());
+}
+
+#[test]
+fn save_vm_trace_of_all_tests() {
+    let temp = setup_package("simple_package");
+    test_runner(&temp)
+        .args(["--save-trace", "vm_traces", "--trace-all"])
+        .assert()
+        .code(1);
+
+    let traces_dir = temp.join("vm_traces");
+    assert!(traces_dir
+        .join("tests::test_simple::test_failing.trace")
+        .exists());
+    assert!(traces_dir
+        .join("tests::test_simple::test_simple.trace")
+        .exists());
+    assert!(traces_dir
+        .join("tests::test_simple::test_simple.memory")
+        .exists());
+    assert!(!traces_dir
+        .join("simple_package::tests::ignored_test.trace")
+        .exists());
+}
+
+#[test]
+fn save_vm_trace_relative_to_invocation_dir() {
+    let temp = setup_hello_workspace();
+    test_runner(&temp)
+        .args([
+            "--package",
+            "addition",
+            "--save-trace",
+            "vm_traces",
+            "--trace-all",
+        ])
+        .assert()
+        .success();
+
+    assert!(temp
+        .join("vm_traces")
+        .join("addition::tests::it_works.trace")
+        .exists());
+    assert!(!temp.join("crates/addition/vm_traces").exists());
+}
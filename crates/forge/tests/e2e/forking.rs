@@ -1,6 +1,7 @@
 use super::common::runner::{
-    runner, setup_package_with_file_patterns, test_runner, BASE_FILE_PATTERNS,
+    runner, setup_package, setup_package_with_file_patterns, test_runner, BASE_FILE_PATTERNS,
 };
+use assert_fs::fixture::{FileWriteStr, PathChild};
 use forge_runner::CACHE_DIR;
 use indoc::{formatdoc, indoc};
 use shared::test_utils::node_url::node_rpc_url;
@@ -130,3 +131,52 @@ fn printing_latest_block_number() {
         "},
     );
 }
+
+#[test]
+fn with_skip_forked_flag() {
+    let temp = setup_package_with_file_patterns("forking", BASE_FILE_PATTERNS);
+
+    // Forks are not resolved, so the tests can be collected without access to the network
+    let output = test_runner(&temp)
+        .args(["test_fork_simple_hash", "--skip-forked"])
+        .assert()
+        .code(3);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+
+
+        Collected 2 test(s) from forking package
+        Running 2 test(s) from src/
+        [IGNORE] forking::tests::test_fork_simple_hash_hex (requires a fork)
+        [IGNORE] forking::tests::test_fork_simple_hash_number (requires a fork)
+        Tests: 0 passed, 0 failed, 0 skipped, 0 ignored, 5 filtered out
+        "},
+    );
+}
+
+#[test]
+fn invalid_fork_url() {
+    let temp = setup_package("empty");
+    temp.child("tests/test.cairo")
+        .write_str(indoc! {r#"
+            #[fork(url: "ftp://127.0.0.1:5050", block_id: BlockId::Number(1))]
+            #[test]
+            fn with_ftp_url() {
+                assert!(true);
+            }
+        "#})
+        .unwrap();
+
+    let output = test_runner(&temp).assert().code(2);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [ERROR] Failed to resolve fork configuration of test = tests::test::with_ftp_url: url = ftp://127.0.0.1:5050 has an unsupported scheme = ftp. Expected http or https
+        "},
+    );
+}
@@ -1,6 +1,7 @@
 use super::common::runner::{setup_package, test_runner};
+use assert_fs::fixture::{FileWriteStr, PathChild};
 use indoc::indoc;
-use shared::test_utils::output_assert::{assert_stderr_contains, assert_stdout_contains};
+use shared::test_utils::output_assert::assert_stdout_contains;
 
 #[test]
 fn fuzzing() {
@@ -135,20 +136,105 @@ fn fuzzing_set_seed() {
 }
 
 #[test]
-fn fuzzing_incorrect_runs() {
+fn fuzzing_seed_is_reused() {
+    let temp = setup_package("fuzzing");
+    let fuzzer_seed = |args: &[&str]| {
+        let output = test_runner(&temp)
+            .arg("fuzzed_argument")
+            .args(args)
+            .assert()
+            .success();
+        let stdout = String::from_utf8_lossy(&output.get_output().stdout).into_owned();
+        let seed_line = stdout
+            .lines()
+            .find(|line| line.starts_with("Fuzzer seed: "))
+            .unwrap()
+            .to_string();
+        (stdout, seed_line)
+    };
+
+    let (stdout, seed) = fuzzer_seed(&[]);
+    assert!(stdout.contains("Generated fuzzer seed"));
+
+    let (stdout, reused_seed) = fuzzer_seed(&[]);
+    assert!(!stdout.contains("Generated fuzzer seed"));
+    assert_eq!(reused_seed, seed);
+
+    let (stdout, _) = fuzzer_seed(&["--new-seed"]);
+    assert!(stdout.contains("Generated fuzzer seed"));
+
+    let (stdout, explicit_seed) = fuzzer_seed(&["--fuzzer-seed", "1234"]);
+    assert!(!stdout.contains("Generated fuzzer seed"));
+    assert_eq!(explicit_seed, "Fuzzer seed: 1234");
+}
+
+#[test]
+fn fuzzing_zero_runs() {
     let temp = setup_package("fuzzing");
 
     let output = test_runner(&temp)
         .args(["fuzzing", "--fuzzer-runs", "0"])
         .assert()
-        .code(2);
+        .code(1);
 
-    assert_stderr_contains(
+    assert_stdout_contains(
         output,
         indoc! {r"
-        error: invalid value '0' for '--fuzzer-runs <FUZZER_RUNS>': number would be zero for non-zero type
+        [..]Compiling[..]
+        [..]Finished[..]
+
+
+        Collected 13 test(s) from fuzzing package
+        Running 13 test(s) from src/
+        [PASS] fuzzing::tests::adding [..]
+        [PASS] fuzzing::tests::fuzzed_argument (gas: ~[..])
+        [PASS] fuzzing::tests::fuzzed_both_arguments (gas: ~[..])
+        [PASS] fuzzing::tests::passing [..]
+        [FAIL] fuzzing::tests::failing_fuzz
+
+        Failure data:
+            0x726573756c74203d3d2061202b2062 ('result == a + b')
+
+        [PASS] fuzzing::tests::custom_fuzzer_config (runs: 10, [..]
+        [PASS] fuzzing::tests::uint8_arg (gas: ~[..])
+        [PASS] fuzzing::tests::fuzzed_while_loop (runs: 256, [..]
+        [PASS] fuzzing::tests::uint16_arg (gas: ~[..])
+        [PASS] fuzzing::tests::uint32_arg (gas: ~[..])
+        [PASS] fuzzing::tests::uint64_arg (gas: ~[..])
+        [PASS] fuzzing::tests::uint128_arg (gas: ~[..])
+        [PASS] fuzzing::tests::uint256_arg (gas: ~[..])
+        Running 0 test(s) from tests/
+        Tests: 12 passed, 1 failed, 0 skipped, 0 ignored, 6 filtered out
 
-        For more information, try '--help'.
+        Failures:
+            fuzzing::tests::failing_fuzz
+        "},
+    );
+}
+
+#[test]
+fn fuzzing_gas_unit_steps() {
+    let temp = setup_package("fuzzing");
+
+    let output = test_runner(&temp)
+        .args(["fuzzing::tests::fuzzed_while_loop", "--exact"])
+        .args(["--gas-unit", "steps"])
+        .assert()
+        .success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+
+
+        Collected 1 test(s) from fuzzing package
+        Running 1 test(s) from src/
+        [PASS] fuzzing::tests::fuzzed_while_loop (runs: 256, steps: {max: [..], min: [..], mean: ~[..], std deviation: ~[..]})
+        Running 0 test(s) from tests/
+        Tests: 1 passed, 0 failed, 0 skipped, 0 ignored, 18 filtered out
+        Fuzzer seed: [..]
         "},
     );
 }
@@ -238,3 +324,89 @@ fn fuzzing_exit_first_single_fail() {
         "},
     );
 }
+
+#[test]
+fn fuzzing_with_fuzzer_stats() {
+    let temp = setup_package("fuzzing");
+
+    let output = test_runner(&temp)
+        .args([
+            "fuzzing::tests::fuzzed_argument",
+            "--exact",
+            "--fuzzer-stats",
+        ])
+        .assert()
+        .success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+
+
+        Collected 1 test(s) from fuzzing package
+        Running 1 test(s) from src/
+        [PASS] fuzzing::tests::fuzzed_argument (runs: 256, unique arguments: [..], runs with boundary values: 2, gas: [..]
+        Running 0 test(s) from tests/
+        Tests: 1 passed, 0 failed, 0 skipped, 0 ignored, [..] filtered out
+        "},
+    );
+}
+
+#[test]
+fn fuzzing_exhaustive() {
+    let temp = setup_package("fuzzing");
+    temp.child("tests/exhaustive.cairo")
+        .write_str(indoc! {r"
+            #[derive(Drop, PartialEq)]
+            enum Direction {
+                Left,
+                Right,
+                Up,
+                Down,
+            }
+
+            #[test]
+            fn all_flags(flag: bool) {
+                assert(flag || !flag, 'flag is neither');
+            }
+
+            #[test]
+            fn single_failing_combination(flag: bool, direction: Direction) {
+                assert(!(flag && direction == Direction::Down), 'flag with down');
+            }
+        "})
+        .unwrap();
+
+    let output = test_runner(&temp)
+        .args([
+            "exhaustive",
+            "--fuzzer-runs",
+            "3",
+            "--fuzzer-exhaustive-threshold",
+            "8",
+        ])
+        .assert()
+        .code(1);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+
+
+        Collected 2 test(s) from fuzzing package
+        Running 0 test(s) from src/
+        Running 2 test(s) from tests/
+        [PASS] tests::exhaustive::all_flags (runs: 2, [..]
+        [FAIL] tests::exhaustive::single_failing_combination (runs: [..], arguments: [..])
+
+        Failure data:
+            0x666c6167207769746820646f776e ('flag with down')
+
+        Tests: 1 passed, 1 failed, 0 skipped, 0 ignored, [..] filtered out
+        "},
+    );
+}
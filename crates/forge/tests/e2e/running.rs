@@ -4,6 +4,7 @@ use super::common::runner::{
 use assert_fs::fixture::{FileWriteStr, PathChild, PathCopy};
 use camino::Utf8PathBuf;
 use forge::CAIRO_EDITION;
+use forge_runner::CACHE_DIR;
 use indoc::{formatdoc, indoc};
 use shared::test_utils::output_assert::assert_stdout_contains;
 use std::{fs, path::Path, str::FromStr};
@@ -55,6 +56,86 @@ fn simple_package() {
     );
 }
 
+#[test]
+fn simple_package_with_test_target_concurrency() {
+    let temp = setup_package("simple_package");
+    let output = test_runner(&temp)
+        .args(["--test-target-concurrency", "2"])
+        .assert()
+        .code(1);
+
+    // Output of each test target is printed after its header, not interleaved with the other one
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    let lib_result = stdout.find("simple_package::tests::test_fib").unwrap();
+    let tests_header = stdout.find("Running 11 test(s) from tests/").unwrap();
+    assert!(lib_result < tests_header);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+    Collected 13 test(s) from simple_package package
+    Running 2 test(s) from src/
+    Running 11 test(s) from tests/
+    [PASS] simple_package::tests::test_fib [..]
+    [FAIL] tests::test_simple::test_failing
+    [FAIL] tests::test_simple::test_another_failing
+    Tests: 9 passed, 2 failed, 0 skipped, 2 ignored, 0 filtered out
+
+    Failures:
+        tests::test_simple::test_failing
+        tests::test_simple::test_another_failing
+    "},
+    );
+}
+
+#[test]
+fn simple_package_with_single_test_thread() {
+    let temp = setup_package("simple_package");
+    let output = test_runner(&temp)
+        .args(["--test-threads", "1"])
+        .assert()
+        .code(1);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+    [..]Compiling[..]
+    [..]Finished[..]
+
+
+    Collected 13 test(s) from simple_package package
+    Running 2 test(s) from src/
+    [PASS] simple_package::tests::test_fib [..]
+    [IGNORE] simple_package::tests::ignored_test
+    Running 11 test(s) from tests/
+    [PASS] tests::contract::call_and_invoke [..]
+    [PASS] tests::ext_function_test::test_my_test [..]
+    [IGNORE] tests::ext_function_test::ignored_test
+    [PASS] tests::ext_function_test::test_simple [..]
+    [PASS] tests::test_simple::test_simple [..]
+    [PASS] tests::test_simple::test_simple2 [..]
+    [PASS] tests::test_simple::test_two [..]
+    [PASS] tests::test_simple::test_two_and_two [..]
+    [FAIL] tests::test_simple::test_failing
+    
+    Failure data:
+        0x6661696c696e6720636865636b ('failing check')
+    
+    [FAIL] tests::test_simple::test_another_failing
+    
+    Failure data:
+        0x6661696c696e6720636865636b ('failing check')
+    
+    [PASS] tests::without_prefix::five [..]
+    Tests: 9 passed, 2 failed, 0 skipped, 2 ignored, 0 filtered out
+    
+    Failures:
+        tests::test_simple::test_failing
+        tests::test_simple::test_another_failing
+    "},
+    );
+}
+
 #[test]
 fn simple_package_with_git_dependency() {
     let temp = tempdir_with_tool_versions().unwrap();
@@ -221,6 +302,41 @@ fn with_exact_filter() {
         "},
     );
 }
+#[test]
+fn with_test_list() {
+    let temp = setup_package("simple_package");
+    temp.child("test_list.txt")
+        .write_str(indoc! {r"
+            # tests to run
+            tests::test_simple::test_two
+            tests::test_simple::test_two_and_two # another one
+            tests::test_simple::nonexistent_test
+        "})
+        .unwrap();
+
+    let output = test_runner(&temp)
+        .args(["--test-list", "test_list.txt"])
+        .assert()
+        .success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+
+
+        Collected 2 test(s) from simple_package package
+        Running 0 test(s) from src/
+        Running 2 test(s) from tests/
+        [PASS] tests::test_simple::test_two [..]
+        [PASS] tests::test_simple::test_two_and_two [..]
+        Tests: 2 passed, 0 failed, 0 skipped, 0 ignored, 11 filtered out
+        [WARNING] The following tests from the test list were not found: tests::test_simple::nonexistent_test
+        "},
+    );
+}
+
 #[test]
 fn with_gas_usage() {
     let temp = setup_package("simple_package");
@@ -247,11 +363,38 @@ fn with_gas_usage() {
     );
 }
 
+#[test]
+fn with_gas_unit_all() {
+    let temp = setup_package("simple_package");
+
+    let output = test_runner(&temp)
+        .arg("tests::test_simple::test_two")
+        .arg("--exact")
+        .args(["--gas-unit", "all"])
+        .assert()
+        .success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+
+
+        Collected 1 test(s) from simple_package package
+        Running 0 test(s) from src/
+        Running 1 test(s) from tests/
+        [PASS] tests::test_simple::test_two (gas: ~1, steps: [..])
+        Tests: 1 passed, 0 failed, 0 skipped, 0 ignored, 12 filtered out
+        "},
+    );
+}
+
 #[test]
 fn with_non_matching_filter() {
     let temp = setup_package("simple_package");
 
-    let output = test_runner(&temp).arg("qwerty").assert().success();
+    let output = test_runner(&temp).arg("qwerty").assert().code(3);
 
     assert_stdout_contains(
         output,
@@ -420,7 +563,31 @@ fn with_include_ignored_flag_and_filter() {
 fn with_rerun_failed_flag_without_cache() {
     let temp = setup_package("simple_package");
 
-    let output = test_runner(&temp).arg("--rerun-failed").assert().code(1);
+    // The message is printed once, not for each test target
+    test_runner(&temp)
+        .arg("--rerun-failed")
+        .assert()
+        .code(3)
+        .stdout_matches(indoc! {r"
+        ...
+        No previous test run found, no tests will be run. Use --run-all-without-prior-run to run all tests instead
+
+
+        Collected 0 test(s) from simple_package package
+        Running 0 test(s) from src/
+        Running 0 test(s) from tests/
+        Tests: 0 passed, 0 failed, 0 skipped, 0 ignored, 13 filtered out
+        "});
+}
+
+#[test]
+fn with_rerun_failed_flag_without_cache_run_all() {
+    let temp = setup_package("simple_package");
+
+    let output = test_runner(&temp)
+        .args(["--rerun-failed", "--run-all-without-prior-run"])
+        .assert()
+        .code(1);
 
     assert_stdout_contains(
         output,
@@ -570,9 +737,9 @@ fn with_panic_data_decoding() {
         [FAIL] tests::test_panic_decoding::test_assert_eq
         
         Failure data:
-            "assertion `x == y` failed.
-            x: 5
-            y: 6"
+            assertion `x == y` failed.
+            left (x):  5
+            right (y): 6
         
         [FAIL] tests::test_panic_decoding::test_assert_message
         
@@ -582,9 +749,9 @@ fn with_panic_data_decoding() {
         [FAIL] tests::test_panic_decoding::test_assert_eq_message
         
         Failure data:
-            "assertion `x == y` failed: An identifiable and meaningful error message
-            x: 5
-            y: 6"
+            assertion `x == y` failed: An identifiable and meaningful error message
+            left (x):  5
+            right (y): 6
         
         Tests: 2 passed, 6 failed, 0 skipped, 0 ignored, 0 filtered out
         
@@ -599,6 +766,45 @@ fn with_panic_data_decoding() {
     );
 }
 
+#[test]
+fn with_error_names() {
+    let temp = setup_package("panic_decoding");
+    let manifest_path = temp.child("Scarb.toml");
+    let mut scarb_toml = fs::read_to_string(&manifest_path)
+        .unwrap()
+        .parse::<DocumentMut>()
+        .unwrap();
+    scarb_toml["tool"]["snforge"]["error_names"] = value("errors.json");
+    manifest_path.write_str(&scarb_toml.to_string()).unwrap();
+    temp.child("errors.json")
+        .write_str(r#"{ "0x80": "InvalidAmount" }"#)
+        .unwrap();
+
+    let output = test_runner(&temp)
+        .arg("test_panic_decoding2")
+        .assert()
+        .code(1);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+
+
+        Collected 1 test(s) from panic_decoding package
+        Running 0 test(s) from src/
+        Running 1 test(s) from tests/
+        [FAIL] tests::test_panic_decoding::test_panic_decoding2
+
+        Failure data:
+            0x80 (InvalidAmount)
+
+        Tests: 0 passed, 1 failed, 0 skipped, 0 ignored, 7 filtered out
+        "},
+    );
+}
+
 #[test]
 fn with_exit_first() {
     let temp = setup_package("exit_first");
@@ -680,6 +886,163 @@ fn with_exit_first_flag() {
     );
 }
 
+#[test]
+fn streaming_summary_with_results_jsonl() {
+    let temp = setup_package("exit_first");
+
+    let output = test_runner(&temp)
+        .args(["--streaming-summary", "--results-jsonl", "results.jsonl"])
+        .assert()
+        .code(1);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+
+
+        Collected 2 test(s) from exit_first package
+        Running 0 test(s) from src/
+        Running 2 test(s) from tests/
+        [PASS] tests::ext_function_test::hard_test [..]
+        [FAIL] tests::ext_function_test::simple_test
+
+        Failure data:
+            0x73696d706c6520636865636b ('simple check')
+
+        Tests: 1 passed, 1 failed, 0 skipped, 0 ignored, 0 filtered out
+
+        Failures:
+            tests::ext_function_test::simple_test
+        "},
+    );
+
+    let results = fs::read_to_string(temp.path().join("results.jsonl")).unwrap();
+    let mut lines: Vec<serde_json::Value> = results
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    lines.sort_by_key(|line| line["name"].as_str().unwrap().to_string());
+
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0]["name"], "tests::ext_function_test::hard_test");
+    assert_eq!(lines[0]["status"], "passed");
+    assert_eq!(lines[1]["name"], "tests::ext_function_test::simple_test");
+    assert_eq!(lines[1]["status"], "failed");
+}
+
+#[test]
+fn with_output_dir() {
+    let temp = setup_package("exit_first");
+
+    test_runner(&temp)
+        .args(["--output-dir", "reports"])
+        .assert()
+        .code(1);
+
+    let report = fs::read_to_string(temp.path().join("reports/exit_first.json")).unwrap();
+    let report: serde_json::Value = serde_json::from_str(&report).unwrap();
+
+    assert_eq!(report["version"], 1);
+    assert_eq!(report["package"], "exit_first");
+
+    let test_cases = report["test_cases"].as_array().unwrap();
+    assert_eq!(test_cases.len(), 2);
+    assert_eq!(test_cases[0]["name"], "tests::ext_function_test::hard_test");
+    assert_eq!(test_cases[0]["status"], "passed");
+    assert!(test_cases[0]["resources"]["l1_gas"].is_u64());
+    assert!(test_cases[0]["resources"]["steps"].is_u64());
+    assert_eq!(
+        test_cases[1]["name"],
+        "tests::ext_function_test::simple_test"
+    );
+    assert_eq!(test_cases[1]["status"], "failed");
+    assert!(test_cases[1]["msg"].is_string());
+    // Package has no fuzz tests
+    assert!(report.get("fuzzing").is_none());
+}
+
+#[test]
+fn with_machine_summary() {
+    let temp = setup_package("exit_first");
+
+    let output = test_runner(&temp)
+        .args(["hard_test", "--machine-summary"])
+        .assert()
+        .success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+
+
+        Collected 1 test(s) from exit_first package
+        Running 0 test(s) from src/
+        Running 1 test(s) from tests/
+        [PASS] tests::ext_function_test::hard_test [..]
+        Tests: 1 passed, 0 failed, 0 skipped, 0 ignored, 1 filtered out
+        SNFORGE_RESULT passed=1 failed=0 ignored=0 filtered=1 gas_total=[..]
+        "},
+    );
+}
+
+#[test]
+fn list_tests() {
+    let temp = setup_package("fuzzing");
+
+    let output = test_runner(&temp)
+        .args(["fuzzed_argument", "--list"])
+        .assert()
+        .success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+        fuzzing::tests::fuzzed_argument (fuzzed)
+        Tests: 1 test(s) listed
+        "},
+    );
+}
+
+#[test]
+fn list_tests_json() {
+    let temp = setup_package("fuzzing");
+
+    let output = test_runner(&temp)
+        .args(["fuzzing::tests::passing", "--exact", "--list", "--json"])
+        .assert()
+        .success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r#"
+        [..]Compiling[..]
+        [..]Finished[..]
+        [
+          {
+            "name": "fuzzing::tests::passing",
+            "package": "fuzzing",
+            "fuzzed": false,
+            "forked": false,
+            "ignored": false,
+            "config": {
+              "available_gas": [..],
+              "ignored": false,
+              "expected_result": "Success",
+              "fork_config": null,
+              "fuzzer_config": null
+            }
+          }
+        ]
+        "#},
+    );
+}
+
 #[test]
 fn init_new_project_test() {
     let temp = tempdir_with_tool_versions().unwrap();
@@ -853,14 +1216,284 @@ fn printing_in_contracts() {
         Collected 2 test(s) from contract_printing package
         Running 0 test(s) from src/
         Running 2 test(s) from tests/
-        Hello world!
         [PASS] tests::test_contract::test_increase_balance [..]
+
+        Printed output:
+            Hello world!
+
         [PASS] tests::test_contract::test_cannot_increase_balance_with_zero_value [..]
         Tests: 2 passed, 0 failed, 0 skipped, 0 ignored, 0 filtered out
         "#},
     );
 }
 
+#[test]
+fn printing_in_contracts_with_hide_passed_output() {
+    let temp = setup_package("contract_printing");
+
+    let output = test_runner(&temp)
+        .arg("--hide-passed-output")
+        .assert()
+        .success();
+
+    let stdout = String::from_utf8_lossy(&output.get_output().stdout);
+    assert!(!stdout.contains("Hello world!"));
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+
+
+        Collected 2 test(s) from contract_printing package
+        Running 0 test(s) from src/
+        Running 2 test(s) from tests/
+        [PASS] tests::test_contract::test_increase_balance [..]
+        [PASS] tests::test_contract::test_cannot_increase_balance_with_zero_value [..]
+        Tests: 2 passed, 0 failed, 0 skipped, 0 ignored, 0 filtered out
+        "},
+    );
+}
+
+#[test]
+fn quiet_prints_only_failures() {
+    let temp = setup_package("simple_package");
+    let output = test_runner(&temp).arg("--quiet").assert().code(1);
+
+    let stdout = String::from_utf8_lossy(&output.get_output().stdout);
+    assert!(!stdout.contains("[PASS]"));
+    assert!(!stdout.contains("[IGNORE]"));
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+    [..]Compiling[..]
+    [..]Finished[..]
+
+
+    Collected 13 test(s) from simple_package package
+    Running 2 test(s) from src/
+    Running 11 test(s) from tests/
+    [FAIL] tests::test_simple::test_failing
+    
+    Failure data:
+        0x6661696c696e6720636865636b ('failing check')
+    
+    [FAIL] tests::test_simple::test_another_failing
+    
+    Failure data:
+        0x6661696c696e6720636865636b ('failing check')
+    
+    Tests: 9 passed, 2 failed, 0 skipped, 2 ignored, 0 filtered out
+    
+    Failures:
+        tests::test_simple::test_failing
+        tests::test_simple::test_another_failing
+    "},
+    );
+}
+
+#[test]
+fn compact_format() {
+    let temp = setup_package("simple_package");
+    let output = test_runner(&temp)
+        .args(["--format", "compact"])
+        .assert()
+        .code(1);
+
+    let stdout = String::from_utf8_lossy(&output.get_output().stdout);
+    assert!(!stdout.contains("[PASS]"));
+    assert!(!stdout.contains("[IGNORE]"));
+    let results: String = stdout
+        .lines()
+        .filter(|line| !line.is_empty() && line.chars().all(|c| ".Fi".contains(c)))
+        .collect();
+    assert_eq!(results.matches('.').count(), 9);
+    assert_eq!(results.matches('F').count(), 2);
+    assert_eq!(results.matches('i').count(), 2);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+    [..]Compiling[..]
+    [..]Finished[..]
+
+
+    Collected 13 test(s) from simple_package package
+    Running 2 test(s) from src/
+    [..]
+    Running 11 test(s) from tests/
+    [..]
+    [FAIL] tests::test_simple::test_failing
+    
+    Failure data:
+        0x6661696c696e6720636865636b ('failing check')
+    
+    [FAIL] tests::test_simple::test_another_failing
+    
+    Failure data:
+        0x6661696c696e6720636865636b ('failing check')
+    
+    Tests: 9 passed, 2 failed, 0 skipped, 2 ignored, 0 filtered out
+    
+    Failures:
+        tests::test_simple::test_failing
+        tests::test_simple::test_another_failing
+
+    Legend: . passed, F failed, i ignored
+    "},
+    );
+}
+
+#[test]
+fn storage_snapshot() {
+    let temp = setup_package("simple_package");
+    let test_file = temp.child("tests/snapshot.cairo");
+    let test_code = |amount: u32| {
+        formatdoc! {r#"
+            use snforge_std::{{declare, ContractClassTrait, snapshot_storage}};
+            use simple_package::hello_starknet::{{IHelloStarknetDispatcher, IHelloStarknetDispatcherTrait}};
+
+            #[test]
+            fn test_balance_snapshot() {{
+                let contract = declare("HelloStarknet").unwrap();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                IHelloStarknetDispatcher {{ contract_address }}.increase_balance({amount});
+
+                snapshot_storage(contract_address, array![selector!("balance")].span());
+            }}
+        "#}
+    };
+
+    test_file.write_str(&test_code(100)).unwrap();
+    test_runner(&temp)
+        .arg("test_balance_snapshot")
+        .assert()
+        .code(0);
+    assert!(temp
+        .child("snapshots/tests.snapshot.test_balance_snapshot.json")
+        .exists());
+
+    test_file.write_str(&test_code(200)).unwrap();
+    let output = test_runner(&temp)
+        .arg("test_balance_snapshot")
+        .assert()
+        .code(1);
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+
+
+        Collected 1 test(s) from simple_package package
+        Running 0 test(s) from src/
+        Running 1 test(s) from tests/
+        [FAIL] tests::snapshot::test_balance_snapshot
+
+        Failure data:
+            Storage does not match the snapshot [..]tests.snapshot.test_balance_snapshot.json:
+            contract 0x[..], storage address 0x[..]: expected 0x64, got 0xc8
+            Run with --update-snapshots to update the snapshot
+
+        Tests: 0 passed, 1 failed, 0 skipped, 0 ignored, 13 filtered out
+        [..]
+        "},
+    );
+
+    test_runner(&temp)
+        .arg("test_balance_snapshot")
+        .arg("--update-snapshots")
+        .assert()
+        .code(0);
+    test_runner(&temp)
+        .arg("test_balance_snapshot")
+        .assert()
+        .code(0);
+}
+
+#[test]
+fn small_contract_cache_size() {
+    let temp = setup_package("simple_package");
+    let output = test_runner(&temp)
+        .args(["--contract-cache-size", "1"])
+        .assert()
+        .code(1);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+        [..]
+        Tests: 9 passed, 2 failed, 0 skipped, 2 ignored, 0 filtered out
+        [..]
+        "},
+    );
+}
+
+#[test]
+fn snforge_profile() {
+    let temp = setup_package("exit_first");
+    temp.child("snfoundry.toml")
+        .write_str(indoc! {r"
+            [snforge.ci]
+            exit-first = true
+        "})
+        .unwrap();
+
+    let output = test_runner(&temp)
+        .args(["--profile", "ci"])
+        .assert()
+        .code(1);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+
+
+        Collected 2 test(s) from exit_first package
+        Running 0 test(s) from src/
+        Running 2 test(s) from tests/
+        [FAIL] tests::ext_function_test::simple_test
+
+        Failure data:
+            0x73696d706c6520636865636b ('simple check')
+
+        Tests: 0 passed, 1 failed, 1 skipped, 0 ignored, 0 filtered out
+
+        Failures:
+            tests::ext_function_test::simple_test
+        "},
+    );
+}
+
+#[test]
+fn missing_snforge_profile() {
+    let temp = setup_package("simple_package");
+    temp.child("snfoundry.toml")
+        .write_str(indoc! {r"
+            [snforge.default]
+            exit-first = true
+        "})
+        .unwrap();
+
+    let output = test_runner(&temp)
+        .args(["--profile", "missing"])
+        .assert()
+        .code(2);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [ERROR] Profile [missing] not found in config
+        "},
+    );
+}
+
 #[test]
 fn incompatible_snforge_std_version_warning() {
     let temp = setup_package("steps");
@@ -933,6 +1566,8 @@ fn detailed_resources_flag() {
                 memory holes: [..]
                 builtins: ([..])
                 syscalls: ([..])
+                deployments: (gas: ~[..], steps: [..])
+                test body: (gas: ~[..], steps: [..])
                 
         Tests: 1 passed, 0 failed, 0 skipped, 0 ignored, 0 filtered out
         "},
@@ -973,3 +1608,36 @@ fn catch_runtime_errors() {
         ),
     );
 }
+
+#[test]
+fn compiled_tests_are_cached() {
+    let temp = setup_package("simple_package");
+    let compiled_tests_dir = temp.path().join(CACHE_DIR).join("compiled_tests");
+    let stdout = |args: &[&str]| {
+        let output = test_runner(&temp).args(args).assert().code(1);
+        String::from_utf8_lossy(&output.get_output().stdout).into_owned()
+    };
+
+    let uncached = stdout(&["--no-compile-cache"]);
+    assert!(!compiled_tests_dir.exists());
+
+    let compiled = stdout(&[]);
+    let cached_files = fs::read_dir(&compiled_tests_dir).unwrap().count();
+    assert_eq!(cached_files, 2);
+
+    let cached = stdout(&[]);
+    assert_eq!(
+        fs::read_dir(&compiled_tests_dir).unwrap().count(),
+        cached_files
+    );
+
+    let results = |stdout: &str| {
+        stdout
+            .lines()
+            .filter(|line| line.starts_with("[PASS]") || line.starts_with("[FAIL]"))
+            .map(|line| line.split(" (").next().unwrap().to_string())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(results(&compiled), results(&uncached));
+    assert_eq!(results(&cached), results(&uncached));
+}
@@ -17,79 +17,81 @@ fn trace_info_print() {
         Collected 1 test(s) from trace_info package
         Running 0 test(s) from src/
         Running 1 test(s) from tests/
-        Entry point type: External
-        Selector: [..]
-        Calldata: []
-        Storage address: [..]
-        Caller address: 0
-        Call type: Call
-        Nested Calls: [
-            (
-                Entry point type: External
-                Selector: [..]
-                Calldata: [..]
-                Storage address: [..]
-                Caller address: [..]
-                Call type: Call
-                Nested Calls: [
-                    (
-                        Entry point type: External
-                        Selector: [..]
-                        Calldata: [..]
-                        Storage address: [..]
-                        Caller address: [..]
-                        Call type: Call
-                        Nested Calls: [
-                            (
-                                Entry point type: External
-                                Selector: [..]
-                                Calldata: [0]
-                                Storage address: [..]
-                                Caller address: [..]
-                                Call type: Call
-                                Nested Calls: []
-                                Call Result: Success: []
-                            ),
-                            (
-                                Entry point type: External
-                                Selector: [..]
-                                Calldata: [0]
-                                Storage address: [..]
-                                Caller address: [..]
-                                Call type: Call
-                                Nested Calls: []
-                                Call Result: Success: []
-                            )
-                        ]
-                        Call Result: Success: []
-                    ),
-                    (
-                        Entry point type: External
-                        Selector: [..]
-                        Calldata: [0]
-                        Storage address: [..]
-                        Caller address: [..]
-                        Call type: Call
-                        Nested Calls: []
-                        Call Result: Success: []
-                    )
-                ]
-                Call Result: Success: []
-            ),
-            (
-                Entry point type: External
-                Selector: 1423007881864269398513176851135908567621420218646181695002463829511917924133
-                Calldata: [5, 1, 2, 3, 4, 5]
-                Storage address: 3447179351737797591242797233952749538061496839896319750579571119352735823363
-                Caller address: 469394814521890341860918960550914
-                Call type: Call
-                Nested Calls: []
-                Call Result: Failure: [1, 2, 3, 4, 5]
-            )
-        ]
-        Call Result: Success: []
-        
         [PASS] tests::test_trace::test_trace (gas: [..]
+
+        Printed output:
+            Entry point type: External
+            Selector: [..]
+            Calldata: []
+            Storage address: [..]
+            Caller address: 0
+            Call type: Call
+            Nested Calls: [
+                (
+                    Entry point type: External
+                    Selector: [..]
+                    Calldata: [..]
+                    Storage address: [..]
+                    Caller address: [..]
+                    Call type: Call
+                    Nested Calls: [
+                        (
+                            Entry point type: External
+                            Selector: [..]
+                            Calldata: [..]
+                            Storage address: [..]
+                            Caller address: [..]
+                            Call type: Call
+                            Nested Calls: [
+                                (
+                                    Entry point type: External
+                                    Selector: [..]
+                                    Calldata: [0]
+                                    Storage address: [..]
+                                    Caller address: [..]
+                                    Call type: Call
+                                    Nested Calls: []
+                                    Call Result: Success: []
+                                ),
+                                (
+                                    Entry point type: External
+                                    Selector: [..]
+                                    Calldata: [0]
+                                    Storage address: [..]
+                                    Caller address: [..]
+                                    Call type: Call
+                                    Nested Calls: []
+                                    Call Result: Success: []
+                                )
+                            ]
+                            Call Result: Success: []
+                        ),
+                        (
+                            Entry point type: External
+                            Selector: [..]
+                            Calldata: [0]
+                            Storage address: [..]
+                            Caller address: [..]
+                            Call type: Call
+                            Nested Calls: []
+                            Call Result: Success: []
+                        )
+                    ]
+                    Call Result: Success: []
+                ),
+                (
+                    Entry point type: External
+                    Selector: 1423007881864269398513176851135908567621420218646181695002463829511917924133
+                    Calldata: [5, 1, 2, 3, 4, 5]
+                    Storage address: 3447179351737797591242797233952749538061496839896319750579571119352735823363
+                    Caller address: 469394814521890341860918960550914
+                    Call type: Call
+                    Nested Calls: []
+                    Call Result: Failure: [1, 2, 3, 4, 5]
+                )
+            ]
+            Call Result: Success: []
+
         Tests: 1 passed, 0 failed, 0 skipped, 0 ignored, 0 filtered out
         "},
     );
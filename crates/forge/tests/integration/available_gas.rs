@@ -57,3 +57,63 @@ fn available_gas_fuzzing() {
 
     assert_passed(&result);
 }
+
+#[test]
+fn available_gas_not_checked_for_expected_panic() {
+    let test = test_utils::test_case!(indoc!(
+        r"
+            #[test]
+            #[available_gas(5)]
+            #[should_panic]
+            fn keccak_cost() {
+                keccak::keccak_u256s_le_inputs(array![1].span());
+                panic_with_felt252('error');
+            }
+        "
+    ));
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn should_panic_test_out_of_gas() {
+    let test = test_utils::test_case!(indoc!(
+        r"
+            #[test]
+            #[should_panic]
+            fn out_of_gas() {
+                panic_with_felt252('Out of gas');
+            }
+        "
+    ));
+
+    let result = run_test_case(&test);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "out_of_gas",
+        "Expected to panic but ran out of gas instead",
+    );
+}
+
+#[test]
+fn should_panic_test_expecting_out_of_gas() {
+    let test = test_utils::test_case!(indoc!(
+        r"
+            #[test]
+            #[available_gas(5)]
+            #[should_panic(expected: 'Out of gas')]
+            fn keccak_cost() {
+                keccak::keccak_u256s_le_inputs(array![1].span());
+                panic_with_felt252('Out of gas');
+            }
+        "
+    ));
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
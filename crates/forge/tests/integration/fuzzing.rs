@@ -1,6 +1,6 @@
 use forge_runner::test_case_summary::{AnyTestCaseSummary, TestCaseSummary};
 use indoc::indoc;
-use test_utils::runner::{assert_passed, TestCase};
+use test_utils::runner::{assert_case_output_contains, assert_failed, assert_passed, TestCase};
 use test_utils::running_tests::run_test_case;
 use test_utils::test_case;
 
@@ -72,8 +72,128 @@ fn fuzzed_while_loop() {
         panic!()
     };
 
-    assert_eq!(gas_info.min, 1);
-    assert_eq!(gas_info.max, 28);
-    assert!((gas_info.mean - 14.).abs() < f64::EPSILON);
-    assert!((gas_info.std_deviation - 8.21).abs() < 0.01);
+    assert_eq!(gas_info.l1_gas.min, 1);
+    assert_eq!(gas_info.l1_gas.max, 28);
+    assert!((gas_info.l1_gas.mean - 14.).abs() < f64::EPSILON);
+    assert!((gas_info.l1_gas.std_deviation - 8.21).abs() < 0.01);
+}
+
+#[test]
+fn fuzzed_with_assume() {
+    let test = test_case!(indoc!(
+        r"
+        use snforge_std::assume;
+
+        #[test]
+        #[fuzzer(runs: 64, seed: 100)]
+        fn fuzzed_with_assume(a: u8) {
+            assume(a % 2 == 0);
+            assert(a % 2 == 0, 'a is odd');
+        }
+    "
+    ));
+
+    let result = run_test_case(&test);
+
+    let test_target_summary = TestCase::find_test_result(&result);
+    let AnyTestCaseSummary::Fuzzing(TestCaseSummary::Passed {
+        test_statistics, ..
+    }) = &test_target_summary.test_case_summaries[0]
+    else {
+        panic!()
+    };
+    assert_eq!(test_statistics.runs, 64);
+}
+
+#[test]
+fn fuzzed_with_too_strict_assume() {
+    let test = test_case!(indoc!(
+        r"
+        use snforge_std::assume;
+
+        #[test]
+        fn fuzzed_with_too_strict_assume(a: felt252) {
+            assume(a == 'impossible');
+        }
+    "
+    ));
+
+    let result = run_test_case(&test);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "fuzzed_with_too_strict_assume",
+        "consecutive runs were rejected with `assume`, the precondition is too strict",
+    );
+}
+
+#[test]
+fn fuzzed_collections() {
+    let test = test_case!(indoc!(
+        r"
+        #[test]
+        #[fuzzer(runs: 64, seed: 100)]
+        fn fuzzed_collections(a: Array<felt252>, b: ByteArray) {
+            assert(a.len() <= 16, 'array too long');
+            assert(b.len() <= 16, 'byte array too long');
+        }
+    "
+    ));
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn printed_output_of_failing_run_only() {
+    let test = test_case!(indoc!(
+        r#"
+        #[test]
+        #[fuzzer(runs: 256, seed: 100)]
+        fn printed_output_of_failing_run_only(a: u8) {
+            println!("a = {}", a);
+            assert(a <= 100, 'a too big');
+        }
+    "#
+    ));
+
+    let result = run_test_case(&test);
+
+    assert_failed(&result);
+    let printed_output = TestCase::find_test_result(&result).test_case_summaries[0]
+        .printed_output()
+        .expect("Failing run printed its argument");
+    let printed_arguments: Vec<u8> = printed_output
+        .lines()
+        .map(|line| line.strip_prefix("a = ").unwrap().parse().unwrap())
+        .collect();
+    assert_eq!(printed_arguments.len(), 1);
+    assert!(printed_arguments[0] > 100);
+}
+
+#[test]
+fn fuzzed_address_and_class_hash() {
+    let test = test_case!(indoc!(
+        r"
+        use starknet::{ClassHash, ContractAddress};
+
+        #[test]
+        #[fuzzer(runs: 256, seed: 100)]
+        fn fuzzed_address_and_class_hash(address: ContractAddress, class_hash: ClassHash) {
+            let address: felt252 = address.into();
+            let address: u256 = address.into();
+            let class_hash: felt252 = class_hash.into();
+            let class_hash: u256 = class_hash.into();
+            let bound: u256 = 0x800000000000000000000000000000000000000000000000000000000000000;
+            assert(address < bound, 'address out of range');
+            assert(class_hash < bound, 'class hash out of range');
+        }
+    "
+    ));
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
 }
@@ -1,7 +1,7 @@
 use indoc::indoc;
 use test_utils::running_tests::run_test_case;
 use test_utils::{
-    runner::{assert_case_output_contains, assert_failed},
+    runner::{assert_case_output_contains, assert_case_printed_output_contains, assert_failed},
     test_case,
 };
 
@@ -90,3 +90,48 @@ fn cheatcode_invalid_args() {
     );
     assert_failed(&result);
 }
+
+#[test]
+fn printed_output_before_panic() {
+    let test = test_case!(indoc!(
+        r#"
+            #[test]
+            fn printed_output_before_panic() {
+                println!("state before panic");
+                assert(1 == 2, 'panicked');
+                println!("not printed");
+            }
+        "#
+    ));
+    let result = run_test_case(&test);
+
+    assert_failed(&result);
+    assert_case_printed_output_contains(
+        &result,
+        "printed_output_before_panic",
+        "state before panic",
+    );
+}
+
+#[test]
+fn printed_output_before_runtime_error() {
+    let test = test_case!(indoc!(
+        r#"
+            use starknet::testing::cheatcode;
+
+            #[test]
+            fn printed_output_before_runtime_error() {
+                println!("state before error");
+                cheatcode::<'not_existing123'>(array![1, 2].span());
+            }
+        "#
+    ));
+    let result = run_test_case(&test);
+
+    assert_failed(&result);
+    assert_case_printed_output_contains(
+        &result,
+        "printed_output_before_runtime_error",
+        "state before error",
+    );
+}
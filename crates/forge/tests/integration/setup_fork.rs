@@ -1,5 +1,5 @@
 use indoc::{formatdoc, indoc};
-use std::num::NonZeroU32;
+use std::num::NonZeroUsize;
 use std::path::Path;
 use std::process::Command;
 use std::process::Stdio;
@@ -9,7 +9,7 @@ use camino::Utf8PathBuf;
 use forge::block_number_map::BlockNumberMap;
 use forge::run_tests::package::run_for_package;
 use forge::scarb::config::ForkTarget;
-use forge::test_filter::TestsFilter;
+use forge::test_filter::{TestsFilter, TestsFilterFlags};
 use tempfile::tempdir;
 use tokio::runtime::Runtime;
 
@@ -18,7 +18,8 @@ use forge::run_tests::package::RunForPackageArgs;
 use forge::scarb::load_test_artifacts;
 use forge_runner::build_trace_data::test_sierra_program_path::VERSIONED_PROGRAMS_DIR;
 use forge_runner::forge_config::{
-    ExecutionDataToSave, ForgeConfig, OutputConfig, TestRunnerConfig,
+    CompileWarnings, ExecutionDataToSave, ForgeConfig, GasUnit, OutputConfig, ResultFormat,
+    TestRunnerConfig, TestSummaryMode, DEFAULT_CONTRACT_CACHE_SIZE,
 };
 use forge_runner::package_tests::raw::RawForkParams;
 use forge_runner::package_tests::raw::TestTargetRaw;
@@ -133,34 +134,50 @@ fn fork_aliased_decorator() {
                     .collect(),
                 package_name: "test_package".to_string(),
                 tests_filter: TestsFilter::from_flags(
+                    TestsFilterFlags::default(),
                     None,
-                    false,
-                    false,
-                    false,
-                    false,
                     Default::default(),
                 ),
                 forge_config: Arc::new(ForgeConfig {
                     test_runner_config: Arc::new(TestRunnerConfig {
                         exit_first: false,
-                        fuzzer_runs: NonZeroU32::new(256).unwrap(),
+                        fuzzer_runs: 256,
                         fuzzer_seed: 12345,
+                        fuzzer_max_rejects: 1000,
+                        fuzzer_max_length: 16,
+                        fuzzer_exhaustive_threshold: None,
+                        contract_cache_size: DEFAULT_CONTRACT_CACHE_SIZE,
                         max_n_steps: None,
+                        test_target_concurrency: NonZeroUsize::new(1).unwrap(),
+                        max_concurrency: None,
                         is_vm_trace_needed: false,
                         cache_dir: Utf8PathBuf::from_path_buf(tempdir().unwrap().into_path())
                             .unwrap()
                             .join(CACHE_DIR),
                         contracts_data: ContractsData::try_from(test.contracts().unwrap()).unwrap(),
                         environment_variables: test.env().clone(),
+                        block_info_overrides: Default::default(),
+                        snapshots_dir: Default::default(),
+                        update_snapshots: false,
+                        vm_trace_output: None,
+                        compile_warnings: CompileWarnings::Warn,
+                        error_names: Default::default(),
                     }),
                     output_config: Arc::new(OutputConfig {
                         detailed_resources: false,
+                        gas_unit: GasUnit::default(),
+                        hide_passed_output: false,
+                        fuzzer_stats: false,
+                        quiet: false,
+                        result_format: ResultFormat::default(),
                         execution_data_to_save: ExecutionDataToSave::None,
                         versioned_programs_dir: Utf8PathBuf::from_path_buf(
                             tempdir().unwrap().into_path(),
                         )
                         .unwrap()
                         .join(VERSIONED_PROGRAMS_DIR),
+                        summary_mode: TestSummaryMode::Full,
+                        results_jsonl_path: None,
                     }),
                 }),
                 fork_targets: vec![ForkTarget::new(
@@ -171,10 +188,14 @@ fn fork_aliased_decorator() {
                         block_id_value: "Latest".to_string(),
                     },
                 )],
+                interrupt: Default::default(),
+                output_dir: None,
             },
             &mut BlockNumberMap::default(),
+            None,
         ))
-        .expect("Runner fail");
+        .expect("Runner fail")
+        .test_target_summaries;
 
     assert_passed(&result);
 }
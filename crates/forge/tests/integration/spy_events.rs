@@ -436,6 +436,26 @@ fn event_emitted_wrong_data_asserted() {
         "event_emitted_wrong_data_asserted",
         "keys was not emitted from",
     );
+    assert_case_output_contains(
+        &result,
+        "event_emitted_wrong_data_asserted",
+        "Expected event:",
+    );
+    assert_case_output_contains(
+        &result,
+        "event_emitted_wrong_data_asserted",
+        "data: [124]",
+    );
+    assert_case_output_contains(
+        &result,
+        "event_emitted_wrong_data_asserted",
+        "Events emitted from the contract:",
+    );
+    assert_case_output_contains(
+        &result,
+        "event_emitted_wrong_data_asserted",
+        "data: [123]",
+    );
 }
 
 #[test]
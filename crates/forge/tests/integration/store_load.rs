@@ -247,6 +247,40 @@ fn store_load_max_boundaries_input() {
     );
 }
 
+#[test]
+fn store_load_not_deployed_contract() {
+    let test = test_utils::test_case!(indoc!(
+        r#"
+            use starknet::contract_address_const;
+            use snforge_std::{ store, load };
+
+            #[test]
+            fn store_not_deployed() {
+                store(contract_address_const::<0x123>(), selector!("balance"), array![420].span());
+            }
+
+            #[test]
+            fn load_not_deployed() {
+                load(contract_address_const::<0x123>(), selector!("balance"), 1);
+            }
+        "#
+    ));
+
+    let result = run_test_case(&test);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "store_not_deployed",
+        "Contract not deployed at address: 0x0000000000000000000000000000000000000000000000000000000000000123",
+    );
+    assert_case_output_contains(
+        &result,
+        "load_not_deployed",
+        "Contract not deployed at address: 0x0000000000000000000000000000000000000000000000000000000000000123",
+    );
+}
+
 #[test]
 fn store_load_structure() {
     let test = test_utils::test_case!(
@@ -5,7 +5,7 @@ use blockifier::execution::syscalls::hint_processor::SyscallHintProcessor;
 use blockifier::execution::syscalls::SyscallResult;
 use blockifier::state::errors::StateError;
 use cairo_felt::Felt252;
-use cairo_lang_casm::hints::{Hint, StarknetHint};
+use cairo_lang_casm::hints::{CoreHint, CoreHintBase, Hint, StarknetHint};
 use cairo_lang_casm::operand::{CellRef, ResOperand};
 use cairo_lang_runner::casm_run::{
     extract_buffer, extract_relocatable, get_ptr, vm_get_range, MemBuffer,
@@ -212,6 +212,19 @@ impl<Extension: ExtensionLogic> HintProcessorLogic for ExtendedRuntime<Extension
                     self.execute_syscall_hint(vm, exec_scopes, hint_data, constants, system)
                 }
             },
+            Some(Hint::Core(CoreHintBase::Core(CoreHint::DebugPrint { start, end }))) => {
+                let data = fetch_cheatcode_input(vm, start, end)?;
+
+                if self
+                    .extension
+                    .handle_debug_print(&data, &mut self.extended_runtime)
+                {
+                    Ok(())
+                } else {
+                    self.extended_runtime
+                        .execute_hint(vm, exec_scopes, hint_data, constants)
+                }
+            }
             _ => self
                 .extended_runtime
                 .execute_hint(vm, exec_scopes, hint_data, constants),
@@ -424,6 +437,16 @@ pub trait ExtensionLogic {
         Ok(CheatcodeHandlingResult::Forwarded)
     }
 
+    /// Handles data printed with the `print` hint.
+    /// Returns `true` if the data was captured and should not be printed by the inner runtime
+    fn handle_debug_print(
+        &mut self,
+        _data: &[Felt252],
+        _extended_runtime: &mut Self::Runtime,
+    ) -> bool {
+        false
+    }
+
     /// Different from `override_system_call` because it cannot be overridden,
     /// always receives a signal and cannot return an error
     /// Signals are executed in reverse order to normal syscall handlers
@@ -1,5 +1,5 @@
-use crate::consts::EXPECTED_RPC_VERSION;
-use anyhow::{Context, Result};
+use crate::consts::{EXPECTED_RPC_VERSION, RPC_URL_VERSION};
+use anyhow::{bail, Context, Result};
 use semver::{Version, VersionReq};
 use starknet::providers::jsonrpc::HttpTransport;
 use starknet::providers::{JsonRpcClient, Provider};
@@ -27,3 +27,93 @@ pub async fn get_rpc_version(client: &JsonRpcClient<HttpTransport>) -> Result<Ve
         .parse::<Version>()
         .context("Failed to parse RPC spec version")
 }
+
+/// Finds the endpoint of the node serving the expected RPC version, given the URL of the node.
+/// The URL is used as it is if it already serves the expected version,
+/// otherwise the versioned path segment is appended (`/rpc/v0_7` or `/v0_7`, depending on the node).
+pub async fn resolve_versioned_rpc_url(url: &Url) -> Result<Url> {
+    let mut reported_version = None;
+
+    for candidate in versioned_url_candidates(url) {
+        let client = JsonRpcClient::new(HttpTransport::new(candidate.clone()));
+        let Ok(version) = get_rpc_version(&client).await else {
+            continue;
+        };
+        if is_expected_version(&version) {
+            return Ok(candidate);
+        }
+        reported_version.get_or_insert(version);
+    }
+
+    match reported_version {
+        Some(version) => bail!(
+            "RPC node with the url {url} uses incompatible version {version}. Expected version: {EXPECTED_RPC_VERSION}"
+        ),
+        None => bail!("Failed to get RPC spec version from the node with the url {url}"),
+    }
+}
+
+/// URLs under which nodes serve the expected RPC version, in the order they are tried
+fn versioned_url_candidates(url: &Url) -> Vec<Url> {
+    let base_path = url.path().trim_end_matches('/');
+    if base_path.ends_with(RPC_URL_VERSION) {
+        return vec![url.clone()];
+    }
+
+    let mut candidates = vec![url.clone()];
+    let suffixes = if base_path.ends_with("/rpc") {
+        vec![RPC_URL_VERSION.to_string()]
+    } else {
+        vec![
+            format!("rpc/{RPC_URL_VERSION}"),
+            RPC_URL_VERSION.to_string(),
+        ]
+    };
+    for suffix in suffixes {
+        let mut candidate = url.clone();
+        candidate.set_path(&format!("{base_path}/{suffix}"));
+        candidates.push(candidate);
+    }
+
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidates(url: &str) -> Vec<String> {
+        versioned_url_candidates(&Url::parse(url).unwrap())
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn bare_url() {
+        assert_eq!(
+            candidates("http://127.0.0.1:5050"),
+            vec![
+                "http://127.0.0.1:5050/",
+                "http://127.0.0.1:5050/rpc/v0_7",
+                "http://127.0.0.1:5050/v0_7",
+            ]
+        );
+    }
+
+    #[test]
+    fn url_with_rpc_path() {
+        assert_eq!(
+            candidates("https://example.com/rpc/"),
+            vec!["https://example.com/rpc/", "https://example.com/rpc/v0_7"]
+        );
+    }
+
+    #[test]
+    fn versioned_url() {
+        assert_eq!(
+            candidates("https://example.com/rpc/v0_7"),
+            vec!["https://example.com/rpc/v0_7"]
+        );
+    }
+}
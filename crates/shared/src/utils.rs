@@ -1,5 +1,6 @@
 use cairo_felt::Felt252;
 use cairo_lang_runner::casm_run::format_next_item;
+use regex::{Captures, Regex};
 
 /// Helper function to build readable text from a run data.
 #[must_use]
@@ -26,6 +27,64 @@ pub fn build_readable_text(data: &[Felt252]) -> Option<String> {
     Some(result)
 }
 
+/// Formats data printed with the `print` hint, every printed item ends with a newline.
+#[must_use]
+pub fn format_debug_print(data: &[Felt252]) -> String {
+    let mut data_iter = data.iter().cloned();
+    let mut output = String::new();
+
+    while let Some(item) = format_next_item(&mut data_iter) {
+        output.push_str(&item.get());
+        if !output.ends_with('\n') {
+            output.push('\n');
+        }
+    }
+
+    output
+}
+
+/// Decodes felts in a revert reason returned by the node into readable text.
+/// Felts encoding short strings are followed by the text, e.g. `0x6f6f7073 ('oops')`,
+/// and panic data in parentheses, e.g. `(0x46a6...79a3, 0x0, 0x6f6f7073, 0x4)`, is formatted as in test failures.
+/// Felts which are already decoded or do not encode text are left as they are.
+#[must_use]
+pub fn decode_revert_reason(reason: &str) -> String {
+    let felts = Regex::new(
+        r"\((0x[0-9a-fA-F]{1,64}(?:, 0x[0-9a-fA-F]{1,64})+)\)|\b0x[0-9a-fA-F]{1,64}\b( \(')?",
+    )
+    .expect("Revert reason regex is valid");
+
+    felts
+        .replace_all(reason, |captures: &Captures| {
+            let matched = &captures[0];
+            if let Some(panic_data) = captures.get(1) {
+                let data: Vec<Felt252> = panic_data
+                    .as_str()
+                    .split(", ")
+                    .filter_map(parse_felt)
+                    .collect();
+                let mut data_iter = data.into_iter();
+                let mut items = Vec::new();
+                while let Some(item) = format_next_item(&mut data_iter) {
+                    items.push(item.quote_if_string());
+                }
+                format!("({})", items.join(", "))
+            } else if captures.get(2).is_some() {
+                matched.to_string()
+            } else {
+                parse_felt(matched)
+                    .filter(|felt| *felt != Felt252::from(0_u8))
+                    .and_then(|felt| format_next_item(&mut std::iter::once(felt)))
+                    .map_or_else(|| matched.to_string(), |item| item.get())
+            }
+        })
+        .into_owned()
+}
+
+fn parse_felt(hex: &str) -> Option<Felt252> {
+    Felt252::parse_bytes(hex.trim_start_matches("0x").as_bytes(), 16)
+}
+
 fn indent_string(string: &str) -> String {
     let mut modified_string = string.to_string();
     let trailing_newline = if string.ends_with('\n') {
@@ -45,7 +104,7 @@ fn indent_string(string: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::indent_string;
+    use super::{decode_revert_reason, indent_string};
 
     #[test]
     fn test_indent_string() {
@@ -58,4 +117,33 @@ mod tests {
         let s = indent_string("\nabc\nd");
         assert_eq!(s, "\n    abc\n    d");
     }
+
+    #[test]
+    fn decode_short_string_in_revert_reason() {
+        assert_eq!(
+            decode_revert_reason("Execution failed. Failure reason: 0x6f6f7073."),
+            "Execution failed. Failure reason: 0x6f6f7073 ('oops')."
+        );
+    }
+
+    #[test]
+    fn decode_panic_data_in_revert_reason() {
+        assert_eq!(
+            decode_revert_reason("Failure reason: (0x6f6f7073, 0x1)"),
+            "Failure reason: (0x6f6f7073 ('oops'), 0x1)"
+        );
+        assert_eq!(
+            decode_revert_reason(
+                "Failure reason: (0x46a6158a16a947e5916b2a2ca68501a45e93d7110e81aa2d6438b1c57c879a3, 0x0, 0x6f6f7073, 0x4)"
+            ),
+            "Failure reason: (\"oops\")"
+        );
+    }
+
+    #[test]
+    fn keep_felts_not_decoded() {
+        let reason =
+            "Error in the called contract (0x01): Failure reason: 0x0, 0x6f6f7073 ('oops').";
+        assert_eq!(decode_revert_reason(reason), reason);
+    }
 }
@@ -1,7 +1,8 @@
+use crate::response::errors::StarknetCommandError;
+use crate::response::structs::{CallResponse, Felt};
+use crate::retry_on_transient_error;
 use anyhow::Result;
 use clap::Args;
-use sncast::response::errors::StarknetCommandError;
-use sncast::response::structs::{CallResponse, Felt};
 use starknet::core::types::{BlockId, FieldElement, FunctionCall};
 use starknet::providers::jsonrpc::HttpTransport;
 use starknet::providers::{JsonRpcClient, Provider};
@@ -26,8 +27,14 @@ pub struct Call {
     /// and block number (u64)
     #[clap(short, long, default_value = "pending")]
     pub block_id: String,
+
+    /// Number of times the call is retried when it fails with a transport or rate limit error.
+    /// Delay between attempts starts at 0.5s and is doubled after each attempt
+    #[clap(long, default_value_t = 0)]
+    pub retry: u32,
 }
 
+/// Calls the function of the contract at the block, returning the raw felts of its result
 #[allow(clippy::ptr_arg)]
 pub async fn call(
     contract_address: FieldElement,
@@ -35,19 +42,18 @@ pub async fn call(
     calldata: Vec<FieldElement>,
     provider: &JsonRpcClient<HttpTransport>,
     block_id: &BlockId,
+    retries: u32,
 ) -> Result<CallResponse, StarknetCommandError> {
     let function_call = FunctionCall {
         contract_address,
         entry_point_selector,
         calldata,
     };
-    let res = provider
-        .call(function_call, block_id)
-        .await
-        .map(|v| v.into_iter().map(Felt).collect());
+    let response =
+        retry_on_transient_error(retries, || provider.call(function_call.clone(), block_id))
+            .await?;
 
-    match res {
-        Ok(response) => Ok(CallResponse { response }),
-        Err(error) => Err(StarknetCommandError::ProviderError(error.into())),
-    }
+    Ok(CallResponse {
+        response: response.into_iter().map(Felt).collect(),
+    })
 }
@@ -0,0 +1,369 @@
+use crate::helpers::fee::{estimated_overall_fee, resolve_max_fee, MaxFee};
+use crate::response::errors::handle_starknet_command_error;
+use crate::response::structs::{DeclareAllResponse, DeclareResponse};
+use crate::response::structs::{DryRunResponse, Felt, TransactionResponse};
+use crate::{apply_optional, handle_wait_for_tx, max_fee_from_estimate, ErrorData, WaitForTx};
+use anyhow::{anyhow, ensure, Context, Result};
+use camino::Utf8PathBuf;
+use clap::Args;
+use scarb_api::StarknetContractArtifacts;
+use starknet::accounts::AccountError::Provider;
+use starknet::accounts::{ConnectedAccount, Declaration};
+
+use crate::response::errors::StarknetCommandError;
+use starknet::core::types::{
+    BlockId, BlockTag, FieldElement, FlattenedSierraClass, StarknetError::ClassHashNotFound,
+};
+use starknet::providers::{Provider as _, ProviderError::StarknetError};
+use starknet::{
+    accounts::{Account, SingleOwnerAccount},
+    core::types::contract::{CompiledClass, CompiledClassEntrypoint, SierraClass},
+    core::types::SierraEntryPoint,
+    providers::jsonrpc::{HttpTransport, JsonRpcClient},
+    signers::LocalWallet,
+};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+#[derive(Args)]
+#[command(about = "Declare a contract to starknet", long_about = None)]
+pub struct Declare {
+    /// Contract name
+    #[clap(
+        short = 'c',
+        long = "contract-name",
+        required_unless_present_any = ["sierra_file", "all"]
+    )]
+    pub contract: Option<String>,
+
+    /// Declare all contracts of the package one by one, skipping the ones which are already declared
+    #[clap(long, conflicts_with_all = ["contract", "sierra_file"])]
+    pub all: bool,
+
+    /// Path to the compiled Sierra contract class to declare instead of building the package
+    #[clap(long, requires = "casm_file", conflicts_with_all = ["contract", "package"])]
+    pub sierra_file: Option<Utf8PathBuf>,
+
+    /// Path to the CASM compiled from the Sierra passed with `--sierra-file`
+    #[clap(long, requires = "sierra_file")]
+    pub casm_file: Option<Utf8PathBuf>,
+
+    /// Max fee for the transaction, in WEI or as a percentage of the ETH balance of the account, e.g. `80%`.
+    /// If not provided, max fee will be automatically estimated
+    #[clap(short, long)]
+    pub max_fee: Option<MaxFee>,
+
+    /// Nonce of the transaction. If not provided, nonce will be set automatically
+    #[clap(short, long)]
+    pub nonce: Option<FieldElement>,
+
+    /// Specifies scarb package to be used
+    #[clap(long)]
+    pub package: Option<String>,
+}
+
+/// Declares the contract from the artifacts built by Scarb.
+/// With `dry_run`, the signed transaction is returned without sending it
+pub async fn declare(
+    contract_name: &str,
+    max_fee: Option<MaxFee>,
+    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+    nonce: Option<FieldElement>,
+    artifacts: &HashMap<String, StarknetContractArtifacts>,
+    wait_config: WaitForTx,
+    dry_run: bool,
+) -> Result<TransactionResponse<DeclareResponse>, StarknetCommandError> {
+    let contract_name: String = contract_name.to_string();
+    let contract_artifacts =
+        artifacts
+            .get(&contract_name)
+            .ok_or(StarknetCommandError::ContractArtifactsNotFound(
+                ErrorData::new(contract_name),
+            ))?;
+    let (contract_class, casm_contract_definition) = parse_artifacts(contract_artifacts)?;
+
+    declare_classes(
+        contract_class,
+        &casm_contract_definition,
+        max_fee,
+        account,
+        nonce,
+        wait_config,
+        dry_run,
+    )
+    .await
+}
+
+pub async fn declare_from_files(
+    sierra_file: &Utf8PathBuf,
+    casm_file: &Utf8PathBuf,
+    max_fee: Option<MaxFee>,
+    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+    nonce: Option<FieldElement>,
+    wait_config: WaitForTx,
+    dry_run: bool,
+) -> Result<TransactionResponse<DeclareResponse>, StarknetCommandError> {
+    let sierra = std::fs::read_to_string(sierra_file)
+        .with_context(|| format!("Failed to read sierra file = {sierra_file}"))?;
+    let casm = std::fs::read_to_string(casm_file)
+        .with_context(|| format!("Failed to read casm file = {casm_file}"))?;
+
+    let contract_definition: SierraClass = serde_json::from_str(&sierra)
+        .with_context(|| format!("Failed to parse sierra file = {sierra_file}"))?;
+    let casm_contract_definition: CompiledClass = serde_json::from_str(&casm)
+        .with_context(|| format!("Failed to parse casm file = {casm_file}"))?;
+
+    check_casm_matches_sierra(&contract_definition, &casm_contract_definition).with_context(
+        || format!("Casm file = {casm_file} was not compiled from sierra file = {sierra_file}"),
+    )?;
+
+    declare_classes(
+        contract_definition.flatten().map_err(anyhow::Error::from)?,
+        &casm_contract_definition,
+        max_fee,
+        account,
+        nonce,
+        wait_config,
+        dry_run,
+    )
+    .await
+}
+
+/// Declares all contracts from the artifacts built by Scarb one by one, in the alphabetical order of their names.
+/// Classes which are already declared are skipped. Nonces of the declarations are consecutive, starting from `nonce`
+/// if provided. After a failed declaration the nonce is fetched again, as the transaction may not have been accepted
+pub async fn declare_all(
+    max_fee: Option<MaxFee>,
+    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+    nonce: Option<FieldElement>,
+    artifacts: &HashMap<String, StarknetContractArtifacts>,
+    wait_config: WaitForTx,
+    dry_run: bool,
+) -> Result<DeclareAllResponse> {
+    ensure!(!dry_run, "`--all` can't be used with `--dry-run`");
+    ensure!(!artifacts.is_empty(), "No contracts found in the package");
+
+    let mut nonce = match nonce {
+        Some(nonce) => nonce,
+        None => account.get_nonce().await.context("Failed to get a nonce")?,
+    };
+    let mut response = DeclareAllResponse {
+        contracts: vec![],
+        class_hashes: vec![],
+        transaction_hashes: vec![],
+        already_declared_contracts: vec![],
+        failed_contracts: vec![],
+    };
+    let sorted_artifacts: BTreeMap<_, _> = artifacts.iter().collect();
+    for (name, contract_artifacts) in sorted_artifacts {
+        let result =
+            declare_if_not_declared(contract_artifacts, max_fee, account, nonce, wait_config).await;
+
+        match result {
+            Ok((class_hash, transaction_hash)) => {
+                response.contracts.push(name.clone());
+                response.class_hashes.push(Felt(class_hash));
+                match transaction_hash {
+                    Some(transaction_hash) => {
+                        response.transaction_hashes.push(Felt(transaction_hash));
+                        nonce += FieldElement::ONE;
+                    }
+                    None => response.already_declared_contracts.push(name.clone()),
+                }
+            }
+            Err(error) => {
+                let error = handle_starknet_command_error(error);
+                response.failed_contracts.push(format!("{name}: {error:#}"));
+                nonce = account.get_nonce().await.context("Failed to get a nonce")?;
+            }
+        }
+    }
+
+    Ok(response)
+}
+
+/// Returns the class hash of the contract and the hash of the declaration transaction,
+/// which is not sent if the class is already declared
+async fn declare_if_not_declared(
+    contract_artifacts: &StarknetContractArtifacts,
+    max_fee: Option<MaxFee>,
+    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+    nonce: FieldElement,
+    wait_config: WaitForTx,
+) -> Result<(FieldElement, Option<FieldElement>), StarknetCommandError> {
+    let (contract_class, casm_contract_definition) = parse_artifacts(contract_artifacts)?;
+    let class_hash = contract_class.class_hash();
+
+    match account
+        .provider()
+        .get_class(BlockId::Tag(BlockTag::Pending), class_hash)
+        .await
+    {
+        Ok(_) => return Ok((class_hash, None)),
+        Err(StarknetError(ClassHashNotFound)) => {}
+        Err(error) => return Err(StarknetCommandError::ProviderError(error.into())),
+    }
+
+    let declared = declare_classes(
+        contract_class,
+        &casm_contract_definition,
+        max_fee,
+        account,
+        Some(nonce),
+        wait_config,
+        false,
+    )
+    .await?
+    .expect_sent();
+    Ok((class_hash, Some(declared.transaction_hash.0)))
+}
+
+fn parse_artifacts(
+    contract_artifacts: &StarknetContractArtifacts,
+) -> Result<(FlattenedSierraClass, CompiledClass)> {
+    let contract_definition: SierraClass = serde_json::from_str(&contract_artifacts.sierra)
+        .context("Failed to parse sierra artifact")?;
+    let casm_contract_definition: CompiledClass =
+        serde_json::from_str(&contract_artifacts.casm).context("Failed to parse casm artifact")?;
+
+    Ok((
+        contract_definition.flatten().map_err(anyhow::Error::from)?,
+        casm_contract_definition,
+    ))
+}
+
+/// Checks that every entry point of the Sierra class has its compiled counterpart in the CASM
+fn check_casm_matches_sierra(
+    contract_definition: &SierraClass,
+    casm_contract_definition: &CompiledClass,
+) -> Result<()> {
+    let sierra_entry_points = &contract_definition.entry_points_by_type;
+    let casm_entry_points = &casm_contract_definition.entry_points_by_type;
+
+    for (entry_point_type, sierra, casm) in [
+        (
+            "external",
+            &sierra_entry_points.external,
+            &casm_entry_points.external,
+        ),
+        (
+            "l1_handler",
+            &sierra_entry_points.l1_handler,
+            &casm_entry_points.l1_handler,
+        ),
+        (
+            "constructor",
+            &sierra_entry_points.constructor,
+            &casm_entry_points.constructor,
+        ),
+    ] {
+        ensure!(
+            sorted_sierra_selectors(sierra) == sorted_casm_selectors(casm),
+            "Selectors of {entry_point_type} entry points differ"
+        );
+    }
+
+    Ok(())
+}
+
+fn sorted_sierra_selectors(entry_points: &[SierraEntryPoint]) -> Vec<FieldElement> {
+    let mut selectors: Vec<_> = entry_points.iter().map(|entry| entry.selector).collect();
+    selectors.sort();
+    selectors
+}
+
+fn sorted_casm_selectors(entry_points: &[CompiledClassEntrypoint]) -> Vec<FieldElement> {
+    let mut selectors: Vec<_> = entry_points.iter().map(|entry| entry.selector).collect();
+    selectors.sort();
+    selectors
+}
+
+async fn declare_classes(
+    contract_class: FlattenedSierraClass,
+    casm_contract_definition: &CompiledClass,
+    max_fee: Option<MaxFee>,
+    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+    nonce: Option<FieldElement>,
+    wait_config: WaitForTx,
+    dry_run: bool,
+) -> Result<TransactionResponse<DeclareResponse>, StarknetCommandError> {
+    let casm_class_hash = casm_contract_definition
+        .class_hash()
+        .map_err(anyhow::Error::from)?;
+
+    let contract_class = Arc::new(contract_class);
+    let class_hash = contract_class.class_hash();
+    let declaration = account.declare(contract_class, casm_class_hash);
+    let max_fee = resolve_max_fee(account.provider(), account.address(), max_fee, async {
+        estimated_overall_fee(declaration.estimate_fee().await)
+    })
+    .await?;
+
+    if dry_run {
+        let mut response = sign_declaration(declaration, account, max_fee, nonce).await?;
+        response.class_hash = Some(Felt(class_hash));
+        return Ok(TransactionResponse::DryRun(response));
+    }
+
+    let declaration = apply_optional(declaration, max_fee, Declaration::max_fee);
+    let declaration = apply_optional(declaration, nonce, Declaration::nonce);
+    let declared = declaration.send().await;
+    match declared {
+        Ok(result) => handle_wait_for_tx(
+            account.provider(),
+            result.transaction_hash,
+            DeclareResponse {
+                class_hash: Felt(result.class_hash),
+                transaction_hash: Felt(result.transaction_hash),
+            },
+            wait_config,
+        )
+        .await
+        .map(TransactionResponse::Sent)
+        .map_err(StarknetCommandError::from),
+        Err(Provider(error)) => Err(StarknetCommandError::ProviderError(error.into())),
+        _ => Err(anyhow!("Unknown RPC error").into()),
+    }
+}
+
+/// Signs the transaction which `send` would send for the declaration, without sending it.
+/// Nonce and max fee which were not provided are resolved the same way as when sending
+async fn sign_declaration(
+    declaration: Declaration<'_, SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>>,
+    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+    max_fee: Option<FieldElement>,
+    nonce: Option<FieldElement>,
+) -> Result<DryRunResponse, StarknetCommandError> {
+    let nonce = match nonce {
+        Some(nonce) => nonce,
+        None => account
+            .get_nonce()
+            .await
+            .map_err(|error| StarknetCommandError::ProviderError(error.into()))?,
+    };
+    let declaration = declaration.nonce(nonce);
+
+    let max_fee = match max_fee {
+        Some(max_fee) => max_fee,
+        None => match declaration.estimate_fee().await {
+            Ok(fee_estimate) => max_fee_from_estimate(fee_estimate.overall_fee),
+            Err(Provider(error)) => return Err(StarknetCommandError::ProviderError(error.into())),
+            _ => return Err(anyhow!("Unknown RPC error").into()),
+        },
+    };
+
+    let prepared = declaration
+        .max_fee(max_fee)
+        .prepared()
+        .expect("Nonce and max fee are set");
+    let transaction = prepared
+        .get_declare_request(false)
+        .await
+        .map_err(|error| anyhow!("Failed to sign the transaction: {error}"))?;
+
+    Ok(DryRunResponse::new(
+        "DECLARE",
+        prepared.transaction_hash(false),
+        &transaction,
+    )?)
+}
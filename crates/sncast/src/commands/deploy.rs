@@ -1,18 +1,22 @@
+use crate::commands::invoke::dry_run_calls;
+use crate::helpers::constants::UDC_ADDRESS;
+use crate::response::structs::{DeployResponse, Felt, TransactionResponse};
 use anyhow::{anyhow, Result};
 use clap::Args;
-use sncast::response::structs::{DeployResponse, Felt};
 use starknet::accounts::AccountError::Provider;
-use starknet::accounts::{Account, ConnectedAccount, SingleOwnerAccount};
+use starknet::accounts::{Account, Call, ConnectedAccount, SingleOwnerAccount};
 use starknet::contract::ContractFactory;
 use starknet::core::types::FieldElement;
-use starknet::core::utils::get_udc_deployed_address;
+use starknet::core::utils::get_selector_from_name;
 use starknet::providers::jsonrpc::HttpTransport;
 use starknet::providers::JsonRpcClient;
 use starknet::signers::LocalWallet;
 
-use sncast::response::errors::StarknetCommandError;
-use sncast::{extract_or_generate_salt, udc_uniqueness};
-use sncast::{handle_wait_for_tx, WaitForTx};
+use crate::extract_or_generate_salt;
+use crate::helpers::address::udc_deployed_address;
+use crate::helpers::fee::{estimated_overall_fee, resolve_max_fee, MaxFee};
+use crate::response::errors::StarknetCommandError;
+use crate::{handle_wait_for_tx, WaitForTx};
 
 #[derive(Args)]
 #[command(about = "Deploy a contract on Starknet")]
@@ -33,29 +37,57 @@ pub struct Deploy {
     #[clap(short, long)]
     pub unique: bool,
 
-    /// Max fee for the transaction. If not provided, max fee will be automatically estimated
+    /// Max fee for the transaction, in WEI or as a percentage of the ETH balance of the account, e.g. `80%`.
+    /// If not provided, max fee will be automatically estimated
     #[clap(short, long)]
-    pub max_fee: Option<FieldElement>,
+    pub max_fee: Option<MaxFee>,
 
     /// Nonce of the transaction. If not provided, nonce will be set automatically
     #[clap(short, long)]
     pub nonce: Option<FieldElement>,
 }
 
+/// Deploys an instance of the class with the Universal Deployer Contract.
+/// With `dry_run`, the signed transaction is returned without sending it
 #[allow(clippy::too_many_arguments)]
 pub async fn deploy(
     class_hash: FieldElement,
     constructor_calldata: Vec<FieldElement>,
     salt: Option<FieldElement>,
     unique: bool,
-    max_fee: Option<FieldElement>,
+    max_fee: Option<MaxFee>,
     account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
     nonce: Option<FieldElement>,
     wait_config: WaitForTx,
-) -> Result<DeployResponse, StarknetCommandError> {
+    dry_run: bool,
+) -> Result<TransactionResponse<DeployResponse>, StarknetCommandError> {
     let salt = extract_or_generate_salt(salt);
     let factory = ContractFactory::new(class_hash, account);
     let execution = factory.deploy(constructor_calldata.clone(), salt, unique);
+    let max_fee = resolve_max_fee(account.provider(), account.address(), max_fee, async {
+        estimated_overall_fee(execution.estimate_fee().await)
+    })
+    .await?;
+
+    if dry_run {
+        // Same call of the Universal Deployer Contract as the one `ContractFactory` sends
+        let mut calldata = vec![
+            class_hash,
+            salt,
+            FieldElement::from(u8::from(unique)),
+            constructor_calldata.len().into(),
+        ];
+        calldata.extend(constructor_calldata);
+        let call = Call {
+            to: UDC_ADDRESS,
+            selector: get_selector_from_name("deployContract").map_err(anyhow::Error::from)?,
+            calldata,
+        };
+
+        return dry_run_calls(account, vec![call], max_fee, nonce)
+            .await
+            .map(TransactionResponse::DryRun);
+    }
 
     // TODO(#1396): use apply_optional here when `Deployment` in starknet-rs is public
     //  otherwise we cannot pass the necessary reference to a function
@@ -77,17 +109,19 @@ pub async fn deploy(
             account.provider(),
             result.transaction_hash,
             DeployResponse {
-                contract_address: Felt(get_udc_deployed_address(
+                contract_address: Felt(udc_deployed_address(
                     salt,
                     class_hash,
-                    &udc_uniqueness(unique, account.address()),
                     &constructor_calldata,
+                    unique,
+                    account.address(),
                 )),
                 transaction_hash: Felt(result.transaction_hash),
             },
             wait_config,
         )
         .await
+        .map(TransactionResponse::Sent)
         .map_err(StarknetCommandError::from),
         Err(Provider(error)) => Err(StarknetCommandError::ProviderError(error.into())),
         _ => Err(anyhow!("Unknown RPC error").into()),
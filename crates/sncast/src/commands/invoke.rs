@@ -0,0 +1,462 @@
+use anyhow::{anyhow, Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
+use clap::Args;
+use serde::Deserialize;
+
+use crate::helpers::fee::{estimated_overall_fee, resolve_max_fee, MaxFee};
+use crate::response::errors::{
+    handle_starknet_command_error, SNCastProviderError, SNCastStarknetError, StarknetCommandError,
+};
+use crate::response::structs::{
+    DryRunResponse, FeeEstimateResponse, Felt, InvokeExportResponse, InvokeResponse,
+    InvokeSimulateResponse, TransactionResponse,
+};
+use crate::{apply_optional, get_chain_id, handle_wait_for_tx, max_fee_from_estimate, WaitForTx};
+use shared::utils::decode_revert_reason;
+use starknet::accounts::AccountError::Provider;
+use starknet::accounts::{Account, Call, ConnectedAccount, Execution, SingleOwnerAccount};
+use starknet::core::crypto::compute_hash_on_elements;
+use starknet::core::types::{
+    BlockId, BlockTag, BroadcastedInvokeTransaction, ExecuteInvocation, FieldElement, FunctionCall,
+    FunctionInvocation, StarknetError, TransactionTrace,
+};
+use starknet::core::utils::{cairo_short_string_to_felt, get_selector_from_name};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider as _, ProviderError};
+use starknet::signers::LocalWallet;
+
+#[derive(Args)]
+#[command(about = "Invoke a contract on Starknet")]
+pub struct Invoke {
+    /// Address of contract to invoke
+    #[clap(short = 'a', long, required_unless_present = "from_file")]
+    pub contract_address: Option<FieldElement>,
+
+    /// Name of the function to invoke
+    #[clap(short, long, required_unless_present = "from_file")]
+    pub function: Option<String>,
+
+    /// Calldata for the invoked function
+    #[clap(short, long, value_delimiter = ' ', num_args = 1..)]
+    pub calldata: Vec<FieldElement>,
+
+    /// Max fee for the transaction, in WEI or as a percentage of the ETH balance of the account, e.g. `80%`.
+    /// If not provided, max fee will be automatically estimated
+    #[clap(short, long)]
+    pub max_fee: Option<MaxFee>,
+
+    /// Nonce of the transaction. If not provided, nonce will be set automatically
+    #[clap(short, long)]
+    pub nonce: Option<FieldElement>,
+
+    /// Simulate the transaction without sending it and print its trace and fee estimate
+    #[clap(long)]
+    pub simulate: bool,
+
+    /// Sign the transaction and save it to the file instead of sending it
+    #[clap(
+        long,
+        value_name = "PATH",
+        requires = "max_fee",
+        conflicts_with = "simulate"
+    )]
+    pub export: Option<Utf8PathBuf>,
+
+    /// Send a signed transaction saved with `--export`
+    #[clap(
+        long,
+        value_name = "PATH",
+        conflicts_with_all = ["contract_address", "function", "calldata", "max_fee", "nonce", "simulate", "export"]
+    )]
+    pub from_file: Option<Utf8PathBuf>,
+}
+
+/// Fields of a signed version 1 invoke transaction needed to validate it before sending
+#[derive(Deserialize)]
+struct SignedInvokeV1 {
+    sender_address: FieldElement,
+    calldata: Vec<FieldElement>,
+    max_fee: FieldElement,
+    signature: Vec<FieldElement>,
+    nonce: FieldElement,
+    version: FieldElement,
+}
+
+impl SignedInvokeV1 {
+    fn transaction_hash(&self, chain_id: FieldElement) -> FieldElement {
+        compute_hash_on_elements(&[
+            cairo_short_string_to_felt("invoke").expect("Prefix is a valid short string"),
+            FieldElement::ONE,
+            self.sender_address,
+            FieldElement::ZERO,
+            compute_hash_on_elements(&self.calldata),
+            self.max_fee,
+            chain_id,
+            self.nonce,
+        ])
+    }
+}
+
+/// Invokes the function of the contract from the account.
+/// With `dry_run`, the signed transaction is returned without sending it
+#[allow(clippy::too_many_arguments)]
+pub async fn invoke(
+    contract_address: FieldElement,
+    function_selector: FieldElement,
+    calldata: Vec<FieldElement>,
+    max_fee: Option<MaxFee>,
+    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+    nonce: Option<FieldElement>,
+    wait_config: WaitForTx,
+    dry_run: bool,
+) -> Result<TransactionResponse<InvokeResponse>, StarknetCommandError> {
+    let call = Call {
+        to: contract_address,
+        selector: function_selector,
+        calldata,
+    };
+
+    execute_calls(account, vec![call], max_fee, nonce, wait_config, dry_run).await
+}
+
+pub async fn execute_calls(
+    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+    calls: Vec<Call>,
+    max_fee: Option<MaxFee>,
+    nonce: Option<FieldElement>,
+    wait_config: WaitForTx,
+    dry_run: bool,
+) -> Result<TransactionResponse<InvokeResponse>, StarknetCommandError> {
+    // Nonce is resolved upfront so it can be reported in the response
+    let nonce = match nonce {
+        Some(nonce) => nonce,
+        None => account
+            .get_nonce()
+            .await
+            .map_err(|error| StarknetCommandError::ProviderError(error.into()))?,
+    };
+
+    let execution = account.execute(calls.clone()).nonce(nonce);
+    let max_fee = resolve_max_fee(account.provider(), account.address(), max_fee, async {
+        estimated_overall_fee(execution.estimate_fee().await)
+    })
+    .await?;
+
+    if dry_run {
+        return dry_run_calls(account, calls, max_fee, Some(nonce))
+            .await
+            .map(TransactionResponse::DryRun);
+    }
+
+    let execution = apply_optional(execution, max_fee, Execution::max_fee);
+
+    match execution.send().await {
+        Ok(result) => handle_wait_for_tx(
+            account.provider(),
+            result.transaction_hash,
+            InvokeResponse {
+                transaction_hash: Felt(result.transaction_hash),
+                nonce: Felt(nonce),
+            },
+            wait_config,
+        )
+        .await
+        .map(TransactionResponse::Sent)
+        .map_err(StarknetCommandError::from),
+        Err(Provider(error)) => Err(StarknetCommandError::ProviderError(error.into())),
+        _ => Err(anyhow!("Unknown RPC error").into()),
+    }
+}
+
+/// Signs the transaction which `execute_calls` would send, without sending it.
+/// Nonce and max fee which were not provided are resolved the same way as when sending
+pub async fn dry_run_calls(
+    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+    calls: Vec<Call>,
+    max_fee: Option<FieldElement>,
+    nonce: Option<FieldElement>,
+) -> Result<DryRunResponse, StarknetCommandError> {
+    let nonce = match nonce {
+        Some(nonce) => nonce,
+        None => account
+            .get_nonce()
+            .await
+            .map_err(|error| StarknetCommandError::ProviderError(error.into()))?,
+    };
+    let execution = account.execute(calls).nonce(nonce);
+
+    let max_fee = match max_fee {
+        Some(max_fee) => max_fee,
+        None => match execution.estimate_fee().await {
+            Ok(fee_estimate) => max_fee_from_estimate(fee_estimate.overall_fee),
+            Err(Provider(error)) => return Err(StarknetCommandError::ProviderError(error.into())),
+            _ => return Err(anyhow!("Unknown RPC error").into()),
+        },
+    };
+
+    let prepared = execution
+        .max_fee(max_fee)
+        .prepared()
+        .expect("Nonce and max fee are set");
+    let transaction = prepared
+        .get_invoke_request(false)
+        .await
+        .map_err(|error| anyhow!("Failed to sign the transaction: {error}"))?;
+
+    Ok(DryRunResponse::new(
+        "INVOKE",
+        prepared.transaction_hash(false),
+        &transaction,
+    )?)
+}
+
+/// Signs the invoke transaction with the account and saves it to the file in the JSON-RPC format
+#[allow(clippy::too_many_arguments)]
+pub async fn export_invoke(
+    contract_address: FieldElement,
+    function_selector: FieldElement,
+    calldata: Vec<FieldElement>,
+    max_fee: MaxFee,
+    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+    nonce: Option<FieldElement>,
+    output: &Utf8Path,
+) -> Result<InvokeExportResponse, StarknetCommandError> {
+    let nonce = match nonce {
+        Some(nonce) => nonce,
+        None => account
+            .get_nonce()
+            .await
+            .map_err(|error| StarknetCommandError::ProviderError(error.into()))?,
+    };
+    let call = Call {
+        to: contract_address,
+        selector: function_selector,
+        calldata,
+    };
+
+    let execution = account.execute(vec![call]).nonce(nonce);
+    let max_fee = resolve_max_fee(
+        account.provider(),
+        account.address(),
+        Some(max_fee),
+        async { estimated_overall_fee(execution.estimate_fee().await) },
+    )
+    .await?
+    .expect("Max fee is provided");
+
+    let prepared = execution
+        .max_fee(max_fee)
+        .prepared()
+        .expect("Nonce and max fee are set");
+    let transaction = prepared
+        .get_invoke_request(false)
+        .await
+        .map_err(|error| anyhow!("Failed to sign the transaction: {error}"))?;
+
+    std::fs::write(output, serde_json::to_string_pretty(&transaction)?)
+        .with_context(|| format!("Failed to write transaction to file = {output}"))?;
+
+    Ok(InvokeExportResponse {
+        transaction_hash: Felt(prepared.transaction_hash(false)),
+        nonce: Felt(nonce),
+        path: output.to_path_buf(),
+    })
+}
+
+/// Sends the signed invoke transaction saved with `--export`,
+/// after checking its nonce and signature against the network
+pub async fn invoke_from_file(
+    provider: &JsonRpcClient<HttpTransport>,
+    path: &Utf8Path,
+    wait_config: WaitForTx,
+) -> Result<InvokeResponse, StarknetCommandError> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read transaction file = {path}"))?;
+    let json: serde_json::Value = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse transaction file = {path}"))?;
+    let signed: SignedInvokeV1 = serde_json::from_value(json.clone())
+        .with_context(|| format!("File = {path} does not contain a signed invoke transaction"))?;
+    if signed.version != FieldElement::ONE {
+        return Err(anyhow!(
+            "Only version 1 invoke transactions are supported, got version = {:#x}",
+            signed.version
+        )
+        .into());
+    }
+    let transaction: BroadcastedInvokeTransaction = serde_json::from_value(json)
+        .with_context(|| format!("File = {path} does not contain a signed invoke transaction"))?;
+
+    let account_nonce = provider
+        .get_nonce(BlockId::Tag(BlockTag::Pending), signed.sender_address)
+        .await
+        .map_err(|error| StarknetCommandError::ProviderError(error.into()))?;
+    if signed.nonce != account_nonce {
+        return Err(anyhow!(
+            "Invalid transaction nonce = {:#x}, the node expects nonce = {account_nonce:#x}",
+            signed.nonce
+        )
+        .into());
+    }
+
+    let chain_id = get_chain_id(provider).await?;
+    let transaction_hash = signed.transaction_hash(chain_id);
+    if !is_valid_signature(provider, &signed, transaction_hash).await? {
+        return Err(anyhow!(
+            "Invalid signature of transaction = {transaction_hash:#x}, it was not signed by account = {:#x} for chain = {chain_id:#x}",
+            signed.sender_address
+        )
+        .into());
+    }
+
+    match provider.add_invoke_transaction(transaction).await {
+        Ok(result) => handle_wait_for_tx(
+            provider,
+            result.transaction_hash,
+            InvokeResponse {
+                transaction_hash: Felt(result.transaction_hash),
+                nonce: Felt(signed.nonce),
+            },
+            wait_config,
+        )
+        .await
+        .map_err(StarknetCommandError::from),
+        Err(error) => Err(StarknetCommandError::ProviderError(error.into())),
+    }
+}
+
+/// Asks the account contract whether the signature is valid, with the SNIP-6 `is_valid_signature`
+/// entrypoint or `isValidSignature` of Cairo 0 accounts.
+/// Only a failed execution or a value other than `VALID` means the signature is invalid,
+/// other provider errors are returned
+async fn is_valid_signature(
+    provider: &JsonRpcClient<HttpTransport>,
+    signed: &SignedInvokeV1,
+    transaction_hash: FieldElement,
+) -> Result<bool, StarknetCommandError> {
+    let valid = cairo_short_string_to_felt("VALID").expect("VALID is a valid short string");
+    let calldata: Vec<FieldElement> =
+        [transaction_hash, FieldElement::from(signed.signature.len())]
+            .into_iter()
+            .chain(signed.signature.iter().copied())
+            .collect();
+
+    for entrypoint in ["is_valid_signature", "isValidSignature"] {
+        let function_call = FunctionCall {
+            contract_address: signed.sender_address,
+            entry_point_selector: get_selector_from_name(entrypoint)
+                .expect("Failed to compute selector of signature validation"),
+            calldata: calldata.clone(),
+        };
+        // Invalid signatures either make the execution fail or return another value
+        match provider
+            .call(function_call, BlockId::Tag(BlockTag::Pending))
+            .await
+        {
+            Ok(result) => {
+                return Ok(
+                    matches!(result.first(), Some(value) if *value == valid || *value == FieldElement::ONE),
+                );
+            }
+            Err(ProviderError::StarknetError(StarknetError::ContractError(_))) => {}
+            Err(error) => return Err(StarknetCommandError::ProviderError(error.into())),
+        }
+    }
+    Ok(false)
+}
+
+/// Explains why the nonce supplied by the user was rejected, other errors are handled as usual
+pub async fn handle_invalid_nonce_error(
+    error: StarknetCommandError,
+    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+    nonce: FieldElement,
+) -> anyhow::Error {
+    if matches!(
+        error,
+        StarknetCommandError::ProviderError(SNCastProviderError::StarknetError(
+            SNCastStarknetError::InvalidTransactionNonce
+        ))
+    ) {
+        if let Ok(account_nonce) = account.get_nonce().await {
+            return anyhow!(
+                "Invalid transaction nonce = {nonce:#x}, the node expects nonce = {account_nonce:#x}"
+            );
+        }
+    }
+    handle_starknet_command_error(error)
+}
+
+pub async fn simulate_invoke(
+    contract_address: FieldElement,
+    function_selector: FieldElement,
+    calldata: Vec<FieldElement>,
+    max_fee: Option<MaxFee>,
+    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+    nonce: Option<FieldElement>,
+) -> Result<InvokeSimulateResponse, StarknetCommandError> {
+    let call = Call {
+        to: contract_address,
+        selector: function_selector,
+        calldata,
+    };
+    let execution = account.execute(vec![call]);
+    let max_fee = resolve_max_fee(account.provider(), account.address(), max_fee, async {
+        estimated_overall_fee(execution.estimate_fee().await)
+    })
+    .await?;
+    let execution = apply_optional(execution, max_fee, Execution::max_fee);
+    let execution = apply_optional(execution, nonce, Execution::nonce);
+
+    // Fee charge is skipped, so the simulation does not depend on the account balance
+    let simulated = match execution.simulate(false, true).await {
+        Ok(simulated) => simulated,
+        Err(Provider(error)) => return Err(StarknetCommandError::ProviderError(error.into())),
+        _ => return Err(anyhow!("Unknown RPC error").into()),
+    };
+
+    let TransactionTrace::Invoke(trace) = simulated.transaction_trace else {
+        return Err(anyhow!("Simulation returned a trace of unexpected transaction type").into());
+    };
+
+    let mut call_trace = vec![];
+    let revert_reason = match trace.execute_invocation {
+        ExecuteInvocation::Success(invocation) => {
+            flatten_invocation(&invocation, 0, &mut call_trace);
+            None
+        }
+        ExecuteInvocation::Reverted(reverted) => {
+            Some(decode_revert_reason(&reverted.revert_reason))
+        }
+    };
+
+    let fee_estimation = simulated.fee_estimation;
+    Ok(InvokeSimulateResponse {
+        fee_estimate: FeeEstimateResponse {
+            overall_fee: Felt(fee_estimation.overall_fee),
+            gas_consumed: Felt(fee_estimation.gas_consumed),
+            gas_price: Felt(fee_estimation.gas_price),
+        },
+        revert_reason,
+        call_trace,
+    })
+}
+
+/// Flattens the invocation tree into lines, nested calls are indented according to their depth
+fn flatten_invocation(invocation: &FunctionInvocation, depth: usize, lines: &mut Vec<String>) {
+    let calldata = invocation
+        .calldata
+        .iter()
+        .map(|felt| format!("{felt:#x}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    lines.push(format!(
+        "{}{:#x}::{:#x}({calldata})",
+        "  ".repeat(depth),
+        invocation.contract_address,
+        invocation.entry_point_selector,
+    ));
+
+    for call in &invocation.calls {
+        flatten_invocation(call, depth + 1, lines);
+    }
+}
@@ -0,0 +1,6 @@
+//! Commands interacting with the network, which return typed responses instead of printing them,
+//! so that they can be used by other tools as well as by the `sncast` binary
+pub mod call;
+pub mod declare;
+pub mod deploy;
+pub mod invoke;
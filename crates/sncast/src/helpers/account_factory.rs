@@ -0,0 +1,79 @@
+use async_trait::async_trait;
+use starknet::{
+    accounts::{AccountFactory, PreparedAccountDeployment, RawAccountDeployment},
+    core::types::{BlockId, BlockTag, FieldElement},
+    providers::Provider,
+    signers::Signer,
+};
+
+/// Factory of accounts deployed with custom constructor calldata, e.g. with a guardian,
+/// whose deployment is signed with a single signature of the transaction hash
+pub struct CustomCalldataAccountFactory<S, P> {
+    class_hash: FieldElement,
+    constructor_calldata: Vec<FieldElement>,
+    chain_id: FieldElement,
+    signer: S,
+    provider: P,
+    block_id: BlockId,
+}
+
+impl<S, P> CustomCalldataAccountFactory<S, P> {
+    pub fn new(
+        class_hash: FieldElement,
+        constructor_calldata: Vec<FieldElement>,
+        chain_id: FieldElement,
+        signer: S,
+        provider: P,
+    ) -> Self {
+        Self {
+            class_hash,
+            constructor_calldata,
+            chain_id,
+            signer,
+            provider,
+            block_id: BlockId::Tag(BlockTag::Latest),
+        }
+    }
+}
+
+#[async_trait]
+impl<S, P> AccountFactory for CustomCalldataAccountFactory<S, P>
+where
+    S: Signer + Sync + Send,
+    P: Provider + Sync + Send,
+{
+    type Provider = P;
+    type SignError = S::SignError;
+
+    fn class_hash(&self) -> FieldElement {
+        self.class_hash
+    }
+
+    fn calldata(&self) -> Vec<FieldElement> {
+        self.constructor_calldata.clone()
+    }
+
+    fn chain_id(&self) -> FieldElement {
+        self.chain_id
+    }
+
+    fn provider(&self) -> &Self::Provider {
+        &self.provider
+    }
+
+    fn block_id(&self) -> BlockId {
+        self.block_id
+    }
+
+    async fn sign_deployment(
+        &self,
+        deployment: &RawAccountDeployment,
+    ) -> Result<Vec<FieldElement>, Self::SignError> {
+        let tx_hash =
+            PreparedAccountDeployment::from_raw(deployment.clone(), self).transaction_hash();
+
+        let signature = self.signer.sign_hash(&tx_hash).await?;
+
+        Ok(vec![signature.r, signature.s])
+    }
+}
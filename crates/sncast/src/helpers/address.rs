@@ -0,0 +1,158 @@
+use crate::helpers::constants::{
+    ARGENT_CLASS_HASH, BRAAVOS_BASE_ACCOUNT_CLASS_HASH, OZ_CLASS_HASH,
+};
+use crate::{udc_uniqueness, AccountType};
+use anyhow::{bail, ensure, Result};
+use starknet::core::types::FieldElement;
+use starknet::core::utils::{get_contract_address, get_udc_deployed_address};
+
+/// Address of a contract deployed with the Universal Deployer Contract.
+/// Only a `unique` deployment depends on the address of the deployer
+#[must_use]
+pub fn udc_deployed_address(
+    salt: FieldElement,
+    class_hash: FieldElement,
+    constructor_calldata: &[FieldElement],
+    unique: bool,
+    deployer_address: FieldElement,
+) -> FieldElement {
+    get_udc_deployed_address(
+        salt,
+        class_hash,
+        &udc_uniqueness(unique, deployer_address),
+        constructor_calldata,
+    )
+}
+
+/// Address of an account deployed with a `DEPLOY_ACCOUNT` transaction, which has no deployer.
+/// Braavos accounts are deployed with the base account class and upgraded to `class_hash` afterwards,
+/// so their address does not depend on `class_hash`
+#[must_use]
+pub fn account_address(
+    account_type: &AccountType,
+    class_hash: FieldElement,
+    salt: FieldElement,
+    public_key: FieldElement,
+) -> FieldElement {
+    let (class_hash, constructor_calldata) = match account_type {
+        AccountType::Oz => (class_hash, vec![public_key]),
+        // Owner and guardian, accounts are created without a guardian
+        AccountType::Argent => (class_hash, vec![public_key, FieldElement::ZERO]),
+        AccountType::Braavos => (BRAAVOS_BASE_ACCOUNT_CLASS_HASH, vec![public_key]),
+    };
+
+    get_contract_address(salt, class_hash, &constructor_calldata, FieldElement::ZERO)
+}
+
+/// Address of an account deployed with a `DEPLOY_ACCOUNT` transaction with custom constructor calldata
+#[must_use]
+pub fn account_address_with_calldata(
+    class_hash: FieldElement,
+    salt: FieldElement,
+    constructor_calldata: &[FieldElement],
+) -> FieldElement {
+    get_contract_address(salt, class_hash, constructor_calldata, FieldElement::ZERO)
+}
+
+/// Checks whether custom constructor calldata can be used to deploy an account of the type.
+/// Length of the calldata is checked only for the default account classes, whose constructors are known
+pub fn validate_account_constructor_calldata(
+    account_type: &AccountType,
+    class_hash: FieldElement,
+    constructor_calldata: &[FieldElement],
+) -> Result<()> {
+    let (expected_length, expected_arguments) = match account_type {
+        AccountType::Braavos => bail!(
+            "Constructor calldata can't be passed for Braavos accounts, which are deployed with the base account class"
+        ),
+        AccountType::Oz if class_hash == OZ_CLASS_HASH => (1, "public key"),
+        AccountType::Argent if class_hash == ARGENT_CLASS_HASH => (2, "owner and guardian"),
+        AccountType::Oz | AccountType::Argent => return Ok(()),
+    };
+
+    ensure!(
+        constructor_calldata.len() == expected_length,
+        "Constructor of the account class = {class_hash:#x} expects {expected_length} argument(s) ({expected_arguments}), got {}",
+        constructor_calldata.len()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SALT: FieldElement = FieldElement::ONE;
+    const CLASS_HASH: FieldElement = FieldElement::TWO;
+    const PUBLIC_KEY: FieldElement = FieldElement::THREE;
+
+    #[test]
+    fn account_address_of_types() {
+        assert_eq!(
+            account_address(&AccountType::Oz, CLASS_HASH, SALT, PUBLIC_KEY),
+            get_contract_address(SALT, CLASS_HASH, &[PUBLIC_KEY], FieldElement::ZERO)
+        );
+        assert_eq!(
+            account_address(&AccountType::Argent, CLASS_HASH, SALT, PUBLIC_KEY),
+            get_contract_address(
+                SALT,
+                CLASS_HASH,
+                &[PUBLIC_KEY, FieldElement::ZERO],
+                FieldElement::ZERO
+            )
+        );
+        assert_eq!(
+            account_address(&AccountType::Braavos, CLASS_HASH, SALT, PUBLIC_KEY),
+            account_address(&AccountType::Braavos, FieldElement::MAX, SALT, PUBLIC_KEY)
+        );
+    }
+
+    #[test]
+    fn account_address_with_default_calldata() {
+        assert_eq!(
+            account_address_with_calldata(CLASS_HASH, SALT, &[PUBLIC_KEY, FieldElement::ZERO]),
+            account_address(&AccountType::Argent, CLASS_HASH, SALT, PUBLIC_KEY)
+        );
+    }
+
+    #[test]
+    fn validate_constructor_calldata() {
+        let calldata = [PUBLIC_KEY, FieldElement::ONE];
+
+        assert!(validate_account_constructor_calldata(
+            &AccountType::Argent,
+            ARGENT_CLASS_HASH,
+            &calldata
+        )
+        .is_ok());
+        assert!(
+            validate_account_constructor_calldata(&AccountType::Oz, CLASS_HASH, &calldata).is_ok()
+        );
+        assert_eq!(
+            validate_account_constructor_calldata(&AccountType::Oz, OZ_CLASS_HASH, &calldata)
+                .unwrap_err()
+                .to_string(),
+            format!("Constructor of the account class = {OZ_CLASS_HASH:#x} expects 1 argument(s) (public key), got 2")
+        );
+        assert!(validate_account_constructor_calldata(
+            &AccountType::Braavos,
+            CLASS_HASH,
+            &calldata
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn udc_address_depends_on_deployer_only_if_unique() {
+        let calldata = [FieldElement::ONE];
+
+        assert_eq!(
+            udc_deployed_address(SALT, CLASS_HASH, &calldata, false, FieldElement::ONE),
+            udc_deployed_address(SALT, CLASS_HASH, &calldata, false, FieldElement::TWO)
+        );
+        assert_ne!(
+            udc_deployed_address(SALT, CLASS_HASH, &calldata, true, FieldElement::ONE),
+            udc_deployed_address(SALT, CLASS_HASH, &calldata, true, FieldElement::TWO)
+        );
+    }
+}
@@ -1,8 +1,10 @@
 use crate::ValidatedWaitParams;
 use anyhow::Result;
 use camino::Utf8PathBuf;
-use configuration::GlobalConfig;
+use configuration::{GlobalConfig, CONFIG_FILENAME};
 use serde::{Deserialize, Serialize};
+use starknet::core::types::FieldElement;
+use std::collections::BTreeMap;
 
 #[derive(Default, Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct CastConfig {
@@ -19,6 +21,16 @@ pub struct CastConfig {
     )]
     pub accounts_file: Utf8PathBuf,
 
+    /// Accounts files searched for accounts after `accounts-file`, in the given order
+    #[serde(
+        default,
+        rename(
+            serialize = "additional-accounts-files",
+            deserialize = "additional-accounts-files"
+        )
+    )]
+    pub additional_accounts_files: Vec<Utf8PathBuf>,
+
     pub keystore: Option<Utf8PathBuf>,
 
     #[serde(
@@ -26,6 +38,89 @@ pub struct CastConfig {
         rename(serialize = "wait-params", deserialize = "wait-params")
     )]
     pub wait_params: ValidatedWaitParams,
+
+    /// Seconds to wait for the connection to the RPC node
+    #[serde(
+        default,
+        rename(serialize = "connect-timeout", deserialize = "connect-timeout")
+    )]
+    pub connect_timeout: Option<u64>,
+
+    /// Seconds to wait for the response of the RPC node to a request
+    #[serde(
+        default,
+        rename(serialize = "request-timeout", deserialize = "request-timeout")
+    )]
+    pub request_timeout: Option<u64>,
+
+    /// Headers sent with every request to the RPC node, e.g. with an API key
+    #[serde(
+        default,
+        rename(serialize = "rpc-headers", deserialize = "rpc-headers")
+    )]
+    pub rpc_headers: BTreeMap<String, String>,
+
+    /// Address of the ETH fee token contract, the address used on mainnet and Sepolia if not set
+    #[serde(
+        default,
+        rename(serialize = "eth-address", deserialize = "eth-address")
+    )]
+    pub eth_address: Option<FieldElement>,
+
+    /// Address of the STRK fee token contract, the address used on mainnet and Sepolia if not set
+    #[serde(
+        default,
+        rename(serialize = "strk-address", deserialize = "strk-address")
+    )]
+    pub strk_address: Option<FieldElement>,
+}
+
+impl CastConfig {
+    /// Accounts files in the order in which accounts are looked up.
+    /// New accounts are written to the first one, `accounts-file`
+    #[must_use]
+    pub fn accounts_files(&self) -> Vec<Utf8PathBuf> {
+        std::iter::once(&self.accounts_file)
+            .chain(&self.additional_accounts_files)
+            .cloned()
+            .collect()
+    }
+}
+
+/// Describes where snfoundry.toml is looked for, which file and profile are used
+/// and the order in which values are resolved, printed with `--verbose`
+#[must_use]
+pub fn describe_config_resolution(
+    search_roots: &[Utf8PathBuf],
+    config_file: Option<&Utf8PathBuf>,
+    profile: &Option<String>,
+) -> Vec<String> {
+    let search_roots: Vec<String> = search_roots
+        .iter()
+        .map(|root| format!("{root} and its parents"))
+        .collect();
+    let mut lines = vec![format!(
+        "Looking for {CONFIG_FILENAME} in {}",
+        search_roots.join(", then in ")
+    )];
+
+    match config_file {
+        Some(config_file) => {
+            lines.push(format!("Using config file = {config_file}"));
+            lines.push(format!(
+                "Using profile = {}",
+                profile.as_deref().unwrap_or("default")
+            ));
+            lines.push(
+                "Values passed as flags override values from the profile, which override default values"
+                    .to_string(),
+            );
+        }
+        None => lines.push(format!(
+            "No {CONFIG_FILENAME} found, using values passed as flags and default values"
+        )),
+    }
+    lines
 }
 
 impl GlobalConfig for CastConfig {
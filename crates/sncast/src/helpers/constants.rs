@@ -22,6 +22,12 @@ pub const OZ_CLASS_HASH: FieldElement =
 pub const ARGENT_CLASS_HASH: FieldElement =
     felt!("0x029927c8af6bccf3f6fda035981e765a7bdbf18a2dc0d630494f8758aa908e2b");
 
+// fee token contracts, deployed at the same addresses on mainnet and Sepolia
+pub const ETH_ADDRESS: FieldElement =
+    felt!("0x049d36570d4e46f48e99674bd3fcc84644ddd6b96f7c741b1562b82f9e004dc7");
+pub const STRK_ADDRESS: FieldElement =
+    felt!("0x04718f5a0fc34cc1af16a1cdee98ffb20c31f5cd61d6ab07201858f4287c938d");
+
 pub const BRAAVOS_CLASS_HASH: FieldElement =
     felt!("0x00816dd0297efc55dc1e7559020a3a825e81ef734b558f03c83325d4da7e6253");
 
@@ -34,6 +40,13 @@ pub const WAIT_TIMEOUT: u16 = 300;
 #[allow(dead_code)]
 pub const WAIT_RETRY_INTERVAL: u8 = 5;
 
+// timeouts of requests to the RPC node, so that commands don't hang on unresponsive nodes
+pub const DEFAULT_CONNECT_TIMEOUT_SECS: u64 = 10;
+pub const DEFAULT_REQUEST_TIMEOUT_SECS: u64 = 60;
+
+// used when retrying requests failed with transient errors, the delay is doubled after each attempt
+pub const RETRY_INITIAL_DELAY_MS: u64 = 500;
+
 #[allow(dead_code)]
 pub const DEFAULT_ACCOUNTS_FILE: &str = "~/.starknet_accounts/starknet_open_zeppelin_accounts.json";
 
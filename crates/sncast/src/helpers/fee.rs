@@ -0,0 +1,205 @@
+use crate::helpers::constants::ETH_ADDRESS;
+use crate::helpers::token::token_balance;
+use crate::response::errors::StarknetCommandError;
+use anyhow::{anyhow, bail, ensure, Error, Result};
+use primitive_types::U256;
+use starknet::accounts::AccountError;
+use starknet::core::types::{BlockId, BlockTag, FeeEstimate, FieldElement};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::JsonRpcClient;
+use std::future::Future;
+use std::str::FromStr;
+
+/// Max fee passed as an amount in WEI, or as a percentage of the ETH balance of the account, e.g. `80%`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MaxFee {
+    Amount(FieldElement),
+    /// Percentage of the balance in the ETH fee token deployed at `eth_address`
+    BalancePercentage {
+        percentage: u8,
+        eth_address: FieldElement,
+    },
+}
+
+impl MaxFee {
+    /// Sets the address of the ETH fee token whose balance a percentage refers to,
+    /// the address used on mainnet and Sepolia is kept if `None` is passed
+    #[must_use]
+    pub fn with_eth_address(self, eth_address: Option<FieldElement>) -> Self {
+        match (self, eth_address) {
+            (Self::BalancePercentage { percentage, .. }, Some(eth_address)) => {
+                Self::BalancePercentage {
+                    percentage,
+                    eth_address,
+                }
+            }
+            _ => self,
+        }
+    }
+}
+
+impl FromStr for MaxFee {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        if let Some(percentage) = value.strip_suffix('%') {
+            let percentage: u8 = percentage
+                .parse()
+                .map_err(|_| anyhow!("Invalid percentage of balance = {value}"))?;
+            ensure!(
+                (1..=100).contains(&percentage),
+                "Percentage of balance must be between 1% and 100%, got {value}"
+            );
+            return Ok(Self::BalancePercentage {
+                percentage,
+                eth_address: ETH_ADDRESS,
+            });
+        }
+
+        match FieldElement::from_str(value) {
+            Ok(amount) => Ok(Self::Amount(amount)),
+            Err(_) => {
+                bail!("Invalid max fee = {value}, expected an amount or a percentage of balance")
+            }
+        }
+    }
+}
+
+/// Resolves the max fee to an amount. A percentage of balance is resolved with the balance
+/// of the account in the ETH fee token and checked against the fee estimated by `estimate_fee`, which is awaited only then
+pub async fn resolve_max_fee(
+    provider: &JsonRpcClient<HttpTransport>,
+    account_address: FieldElement,
+    max_fee: Option<MaxFee>,
+    estimate_fee: impl Future<Output = Result<FieldElement, StarknetCommandError>>,
+) -> Result<Option<FieldElement>, StarknetCommandError> {
+    let (percentage, eth_address) = match max_fee {
+        None => return Ok(None),
+        Some(MaxFee::Amount(amount)) => return Ok(Some(amount)),
+        Some(MaxFee::BalancePercentage {
+            percentage,
+            eth_address,
+        }) => (percentage, eth_address),
+    };
+
+    let balance = token_balance(
+        provider,
+        eth_address,
+        account_address,
+        &BlockId::Tag(BlockTag::Pending),
+    )
+    .await?;
+    let max_fee = percentage_of_balance(balance, percentage)?;
+    let estimated_fee = estimate_fee.await?;
+
+    if max_fee < estimated_fee {
+        return Err(anyhow!(
+            "Insufficient balance of account = {account_address:#x}: {percentage}% of its balance = {balance} WEI is {max_fee} WEI, less than the estimated fee = {estimated_fee} WEI"
+        )
+        .into());
+    }
+    Ok(Some(max_fee))
+}
+
+/// Overall fee of the estimate returned by starknet-rs, with errors handled as when sending the transaction
+pub fn estimated_overall_fee<S>(
+    estimate: Result<FeeEstimate, AccountError<S>>,
+) -> Result<FieldElement, StarknetCommandError> {
+    match estimate {
+        Ok(estimate) => Ok(estimate.overall_fee),
+        Err(AccountError::Provider(error)) => {
+            Err(StarknetCommandError::ProviderError(error.into()))
+        }
+        Err(_) => Err(anyhow!("Unknown RPC error").into()),
+    }
+}
+
+fn percentage_of_balance(balance: U256, percentage: u8) -> Result<FieldElement> {
+    let percentage = U256::from(percentage);
+    let amount = balance / 100 * percentage + balance % 100 * percentage / 100;
+
+    let mut bytes = [0_u8; 32];
+    amount.to_big_endian(&mut bytes);
+    FieldElement::from_bytes_be(&bytes).map_err(|_| anyhow!("Max fee = {amount} exceeds felt"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_amounts_and_percentages() {
+        assert_eq!(
+            MaxFee::from_str("1000").unwrap(),
+            MaxFee::Amount(FieldElement::from(1000_u16))
+        );
+        assert_eq!(
+            MaxFee::from_str("0x10").unwrap(),
+            MaxFee::Amount(FieldElement::from(16_u8))
+        );
+        assert_eq!(
+            MaxFee::from_str("80%").unwrap(),
+            MaxFee::BalancePercentage {
+                percentage: 80,
+                eth_address: ETH_ADDRESS
+            }
+        );
+    }
+
+    #[test]
+    fn overrides_eth_address_of_percentages() {
+        let eth_address = FieldElement::from(0x49_u8);
+        assert_eq!(
+            MaxFee::from_str("80%")
+                .unwrap()
+                .with_eth_address(Some(eth_address)),
+            MaxFee::BalancePercentage {
+                percentage: 80,
+                eth_address
+            }
+        );
+        assert_eq!(
+            MaxFee::from_str("80%").unwrap().with_eth_address(None),
+            MaxFee::from_str("80%").unwrap()
+        );
+        assert_eq!(
+            MaxFee::from_str("1000")
+                .unwrap()
+                .with_eth_address(Some(eth_address)),
+            MaxFee::Amount(FieldElement::from(1000_u16))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_percentages() {
+        assert_eq!(
+            MaxFee::from_str("0%").unwrap_err().to_string(),
+            "Percentage of balance must be between 1% and 100%, got 0%"
+        );
+        assert_eq!(
+            MaxFee::from_str("101%").unwrap_err().to_string(),
+            "Percentage of balance must be between 1% and 100%, got 101%"
+        );
+        assert_eq!(
+            MaxFee::from_str("2.5%").unwrap_err().to_string(),
+            "Invalid percentage of balance = 2.5%"
+        );
+        assert!(MaxFee::from_str("abc").is_err());
+    }
+
+    #[test]
+    fn computes_percentage_of_balance() {
+        assert_eq!(
+            percentage_of_balance(U256::from(1999), 80).unwrap(),
+            FieldElement::from(1599_u16)
+        );
+        assert_eq!(
+            percentage_of_balance(U256::from(1999), 100).unwrap(),
+            FieldElement::from(1999_u16)
+        );
+        assert_eq!(
+            percentage_of_balance(U256::MAX, 1).unwrap_err().to_string(),
+            format!("Max fee = {} exceeds felt", U256::MAX / 100)
+        );
+    }
+}
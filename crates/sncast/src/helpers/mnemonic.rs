@@ -0,0 +1,121 @@
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use bip32::{DerivationPath, XPrv};
+use bip39::{Language, Mnemonic};
+use primitive_types::U256;
+use sha2::{Digest, Sha256};
+use starknet::core::types::FieldElement;
+
+const HARDENED: u32 = 1 << 31;
+/// Path under which keys of consecutive derivation indexes are derived
+const DERIVATION_PATH: &str = "m/44'/9004'/0'/0";
+const STARK_CURVE_ORDER: &str = "800000000000010ffffffffffffffffb781126dcae7b2321e66a241adc64d2f";
+const MAX_GRIND_ITERATIONS: u32 = 100_000;
+
+/// Derives a Stark private key from an English BIP-39 mnemonic (without a passphrase).
+/// A secp256k1 key is derived from the mnemonic seed with BIP-32 at `m/44'/9004'/0'/0/<index>`
+/// and ground into a key of the Stark curve, the same way as `grindKey` of starknet.js does.
+pub fn derive_private_key(mnemonic: &str, index: u32) -> Result<FieldElement> {
+    ensure!(
+        index < HARDENED,
+        "Derivation index must be smaller than {HARDENED}"
+    );
+
+    // Checks the number of words, that all of them are in the wordlist and the checksum
+    let mnemonic = Mnemonic::parse_in_normalized(Language::English, mnemonic)
+        .map_err(|error| anyhow!("Invalid mnemonic: {error}"))?;
+    let path: DerivationPath = format!("{DERIVATION_PATH}/{index}")
+        .parse()
+        .expect("Derivation path is valid");
+    let key = XPrv::derive_from_path(mnemonic.to_seed(""), &path)
+        .context("Derived an invalid key, use another derivation index")?;
+
+    grind_key(&key.private_key().to_bytes())
+}
+
+/// Hashes the key with consecutive numbers until the hash is below the largest multiple
+/// of the Stark curve order fitting in 256 bits, so that the key reduced modulo the order is unbiased
+fn grind_key(private_key: &[u8]) -> Result<FieldElement> {
+    let order = U256::from_str_radix(STARK_CURVE_ORDER, 16).expect("Curve order is valid");
+    let remainder_of_2_pow_256 = (U256::MAX % order + 1) % order;
+    let limit = U256::MAX - remainder_of_2_pow_256 + 1;
+
+    for i in 0..MAX_GRIND_ITERATIONS {
+        // Number is appended as the shortest big endian bytes, but at least one byte
+        let number = i.to_be_bytes();
+        let leading_zeros = (i.leading_zeros() / 8).min(3) as usize;
+
+        let hash = Sha256::new()
+            .chain_update(private_key)
+            .chain_update(&number[leading_zeros..])
+            .finalize();
+        let key = U256::from_big_endian(&hash);
+        if key < limit {
+            let mut bytes = [0; 32];
+            (key % order).to_big_endian(&mut bytes);
+            return Ok(FieldElement::from_bytes_be(&bytes)
+                .expect("Key smaller than the curve order is a valid felt"));
+        }
+    }
+
+    bail!("Failed to grind the key into a Stark key")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn derive_keys_of_indexes() {
+        assert_eq!(
+            derive_private_key(MNEMONIC, 0).unwrap(),
+            FieldElement::from_hex_be(
+                "0x1b8e16cdf31892c56c0370f0e4ca0da096ef4e0c81007b3ba10b11452f8971"
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            derive_private_key(MNEMONIC, 1).unwrap(),
+            FieldElement::from_hex_be(
+                "0x6d582b352685f7c37a2faa748536c741c3a8c660cb011bce57457a32cd04d1a"
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn whitespace_in_mnemonic_is_ignored() {
+        let mnemonic = format!("  {}\n", MNEMONIC.replace(' ', "\t "));
+
+        assert_eq!(
+            derive_private_key(&mnemonic, 0).unwrap(),
+            derive_private_key(MNEMONIC, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn invalid_word_count() {
+        let error = derive_private_key("abandon about", 0).unwrap_err();
+
+        assert!(error.to_string().starts_with("Invalid mnemonic: "));
+    }
+
+    #[test]
+    fn unknown_word() {
+        let mnemonic = MNEMONIC.replace("about", "starknet");
+
+        let error = derive_private_key(&mnemonic, 0).unwrap_err();
+
+        assert!(error.to_string().starts_with("Invalid mnemonic: "));
+    }
+
+    #[test]
+    fn invalid_checksum() {
+        let mnemonic = MNEMONIC.replace("about", "abandon");
+
+        let error = derive_private_key(&mnemonic, 0).unwrap_err();
+
+        assert!(error.to_string().starts_with("Invalid mnemonic: "));
+    }
+}
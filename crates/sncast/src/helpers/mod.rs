@@ -1,4 +1,9 @@
+pub mod account_factory;
+pub mod address;
 pub mod braavos;
 pub mod configuration;
 pub mod constants;
+pub mod fee;
+pub mod mnemonic;
 pub mod scarb_utils;
+pub mod token;
@@ -0,0 +1,68 @@
+use crate::response::errors::StarknetCommandError;
+use anyhow::{anyhow, bail, Context, Result};
+use primitive_types::U256;
+use starknet::core::types::{BlockId, FieldElement, FunctionCall};
+use starknet::core::utils::get_selector_from_name;
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
+
+/// Balance of `address` in the ERC20 token deployed at `token_address`
+pub async fn token_balance(
+    provider: &JsonRpcClient<HttpTransport>,
+    token_address: FieldElement,
+    address: FieldElement,
+    block_id: &BlockId,
+) -> Result<U256, StarknetCommandError> {
+    let function_call = FunctionCall {
+        contract_address: token_address,
+        entry_point_selector: get_selector_from_name("balanceOf")
+            .expect("Failed to compute selector of balanceOf"),
+        calldata: vec![address],
+    };
+    let response = provider
+        .call(function_call, block_id)
+        .await
+        .map_err(|error| StarknetCommandError::ProviderError(error.into()))?;
+
+    let balance = u256_from_felts(&response).with_context(|| {
+        format!("Failed to decode balance returned by token {token_address:#x}")
+    })?;
+    Ok(balance)
+}
+
+/// Decodes a `u256` serialized as its low and high halves
+fn u256_from_felts(felts: &[FieldElement]) -> Result<U256> {
+    let [low, high] = felts else {
+        bail!("Expected 2 felts of u256, got {}", felts.len());
+    };
+    let to_u128 =
+        |felt: &FieldElement| u128::try_from(*felt).map_err(|_| anyhow!("{felt:#x} exceeds u128"));
+
+    Ok((U256::from(to_u128(high)?) << 128) + U256::from(to_u128(low)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_u256() {
+        assert_eq!(
+            u256_from_felts(&[FieldElement::from(1000_u32), FieldElement::ZERO]).unwrap(),
+            U256::from(1000)
+        );
+        assert_eq!(
+            u256_from_felts(&[FieldElement::ZERO, FieldElement::ONE])
+                .unwrap()
+                .to_string(),
+            "340282366920938463463374607431768211456"
+        );
+    }
+
+    #[test]
+    fn invalid_u256() {
+        let error = u256_from_felts(&[FieldElement::ONE]).unwrap_err();
+
+        assert_eq!(error.to_string(), "Expected 2 felts of u256, got 1");
+    }
+}
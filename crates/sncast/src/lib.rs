@@ -1,5 +1,6 @@
 use anyhow::{anyhow, bail, Context, Error, Result};
 use camino::Utf8PathBuf;
+use clap::ValueEnum;
 use helpers::constants::{KEYSTORE_PASSWORD_ENV_VAR, UDC_ADDRESS};
 use rand::rngs::OsRng;
 use rand::RngCore;
@@ -16,26 +17,34 @@ use starknet::core::utils::{UdcUniqueSettings, UdcUniqueness};
 use starknet::{
     accounts::{ExecutionEncoding, SingleOwnerAccount},
     providers::{
-        jsonrpc::{HttpTransport, JsonRpcClient},
+        jsonrpc::{HttpTransport, HttpTransportError, JsonRpcClient, JsonRpcClientError},
         Provider, ProviderError,
         ProviderError::StarknetError,
     },
     signers::{LocalWallet, SigningKey},
 };
 
-use crate::helpers::constants::{DEFAULT_STATE_FILE_SUFFIX, WAIT_RETRY_INTERVAL, WAIT_TIMEOUT};
-use crate::response::errors::SNCastProviderError;
+use crate::helpers::configuration::CastConfig;
+use crate::helpers::constants::{
+    DEFAULT_CONNECT_TIMEOUT_SECS, DEFAULT_REQUEST_TIMEOUT_SECS, DEFAULT_STATE_FILE_SUFFIX,
+    RETRY_INITIAL_DELAY_MS, WAIT_RETRY_INTERVAL, WAIT_TIMEOUT,
+};
+use crate::response::errors::{SNCastProviderError, StarknetCommandError};
 use conversions::serde::serialize::CairoSerialize;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use serde::de::DeserializeOwned;
-use shared::rpc::create_rpc_client;
+use shared::utils::decode_revert_reason;
 use starknet::accounts::{AccountFactory, AccountFactoryError};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
 use std::str::FromStr;
 use std::thread::sleep;
 use std::time::Duration;
 use std::{env, fs};
 use thiserror::Error;
+use url::Url;
 
+pub mod commands;
 pub mod helpers;
 pub mod response;
 pub mod state;
@@ -80,25 +89,67 @@ pub enum NumbersFormat {
     Default,
     Decimal,
     Hex,
+    /// Printable short strings are decoded, other felts are displayed as hex
+    Auto,
+}
+
+/// Format of felts in the output, selected with `--felt-format`
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum FeltFormat {
+    Hex,
+    Dec,
+    Auto,
 }
 
 impl NumbersFormat {
     #[must_use]
-    pub fn from_flags(hex_format: bool, dec_format: bool) -> Self {
+    pub fn from_flags(hex_format: bool, dec_format: bool, felt_format: Option<FeltFormat>) -> Self {
         assert!(
-            !(hex_format && dec_format),
+            [hex_format, dec_format, felt_format.is_some()]
+                .iter()
+                .filter(|flag| **flag)
+                .count()
+                <= 1,
             "Exclusivity should be validated by clap"
         );
-        if hex_format {
-            NumbersFormat::Hex
-        } else if dec_format {
-            NumbersFormat::Decimal
-        } else {
-            NumbersFormat::Default
+        match felt_format {
+            Some(FeltFormat::Hex) => NumbersFormat::Hex,
+            Some(FeltFormat::Dec) => NumbersFormat::Decimal,
+            Some(FeltFormat::Auto) => NumbersFormat::Auto,
+            None if hex_format => NumbersFormat::Hex,
+            None if dec_format => NumbersFormat::Decimal,
+            None => NumbersFormat::Default,
+        }
+    }
+
+    /// Formats the felt, `None` with the default format which leaves formatting to the response
+    #[must_use]
+    pub fn format_felt(self, felt: FieldElement) -> Option<String> {
+        match self {
+            NumbersFormat::Default => None,
+            NumbersFormat::Decimal => Some(format!("{felt:#}")),
+            NumbersFormat::Hex => Some(format!("{felt:#x}")),
+            NumbersFormat::Auto => Some(match printable_short_string(felt) {
+                Some(short_string) => format!("'{short_string}'"),
+                None => format!("{felt:#x}"),
+            }),
         }
     }
 }
 
+/// Decodes the felt as a short string if it consists of at least two printable ASCII characters
+/// including a letter, so that small numbers like nonces are not mistaken for strings
+fn printable_short_string(felt: FieldElement) -> Option<String> {
+    let bytes = felt.to_bytes_be();
+    let first_byte = bytes.iter().position(|byte| *byte != 0)?;
+    let text = &bytes[first_byte..];
+
+    let is_short_string = (2..=31).contains(&text.len())
+        && text.iter().all(|byte| (0x20..=0x7e).contains(byte))
+        && text.iter().any(u8::is_ascii_alphabetic);
+    is_short_string.then(|| String::from_utf8_lossy(text).into_owned())
+}
+
 #[derive(Clone, Copy)]
 pub struct WaitForTx {
     pub wait: bool,
@@ -159,8 +210,50 @@ impl Default for ValidatedWaitParams {
 }
 
 pub fn get_provider(url: &str) -> Result<JsonRpcClient<HttpTransport>> {
-    raise_if_empty(url, "RPC url")?;
-    create_rpc_client(url)
+    build_provider(&CastConfig {
+        url: url.to_string(),
+        ..Default::default()
+    })
+}
+
+/// Creates the client of the RPC node under `url` of the config, with timeouts and headers of the config.
+/// Proxies set with `HTTP_PROXY`, `HTTPS_PROXY` and `NO_PROXY` environment variables are used
+pub fn build_provider(config: &CastConfig) -> Result<JsonRpcClient<HttpTransport>> {
+    raise_if_empty(&config.url, "RPC url")?;
+    let url =
+        Url::parse(&config.url).with_context(|| format!("Failed to parse URL: {}", config.url))?;
+
+    let client = reqwest::Client::builder()
+        .connect_timeout(Duration::from_secs(
+            config
+                .connect_timeout
+                .unwrap_or(DEFAULT_CONNECT_TIMEOUT_SECS),
+        ))
+        .timeout(Duration::from_secs(
+            config
+                .request_timeout
+                .unwrap_or(DEFAULT_REQUEST_TIMEOUT_SECS),
+        ))
+        .default_headers(parse_rpc_headers(&config.rpc_headers)?)
+        .build()
+        .context("Failed to create the HTTP client")?;
+
+    Ok(JsonRpcClient::new(HttpTransport::new_with_client(
+        url, client,
+    )))
+}
+
+fn parse_rpc_headers(headers: &BTreeMap<String, String>) -> Result<HeaderMap> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let name = HeaderName::from_bytes(name.as_bytes())
+                .with_context(|| format!("Invalid RPC header name = {name}"))?;
+            let value = HeaderValue::from_str(value)
+                .with_context(|| format!("Invalid value of RPC header = {name}"))?;
+            Ok((name, value))
+        })
+        .collect()
 }
 
 pub async fn get_chain_id(provider: &JsonRpcClient<HttpTransport>) -> Result<FieldElement> {
@@ -177,6 +270,51 @@ pub fn get_keystore_password(env_var: &str) -> std::io::Result<String> {
     }
 }
 
+/// Well-known networks with public RPC nodes, selected with `--network`
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum Network {
+    Mainnet,
+    Sepolia,
+}
+
+impl Network {
+    /// Public RPC node of the network
+    #[must_use]
+    pub fn url(self) -> &'static str {
+        match self {
+            Network::Mainnet => "https://free-rpc.nethermind.io/mainnet-juno/v0_7",
+            Network::Sepolia => "https://free-rpc.nethermind.io/sepolia-juno/v0_7",
+        }
+    }
+
+    #[must_use]
+    pub fn chain_id(self) -> FieldElement {
+        match self {
+            Network::Mainnet => FieldElement::from_byte_slice_be(b"SN_MAIN").unwrap(),
+            Network::Sepolia => FieldElement::from_byte_slice_be(b"SN_SEPOLIA").unwrap(),
+        }
+    }
+}
+
+/// Checks that the RPC node is connected to the network selected with `--network`
+pub async fn verify_network(
+    provider: &JsonRpcClient<HttpTransport>,
+    network: Network,
+) -> Result<()> {
+    let chain_id = get_chain_id(provider).await?;
+    if chain_id != network.chain_id() {
+        bail!(
+            "RPC node is connected to network = {}, but --network {} was passed",
+            chain_id_to_network_name(chain_id),
+            network
+                .to_possible_value()
+                .expect("Network has no skipped variants")
+                .get_name()
+        );
+    }
+    Ok(())
+}
+
 #[must_use]
 pub fn chain_id_to_network_name(chain_id: FieldElement) -> String {
     let decoded = decode_chain_id(chain_id);
@@ -217,7 +355,7 @@ pub async fn get_nonce(
 
 pub async fn get_account<'a>(
     account: &str,
-    accounts_file: &Utf8PathBuf,
+    accounts_files: &[Utf8PathBuf],
     provider: &'a JsonRpcClient<HttpTransport>,
     keystore: Option<Utf8PathBuf>,
 ) -> Result<SingleOwnerAccount<&'a JsonRpcClient<HttpTransport>, LocalWallet>> {
@@ -225,7 +363,7 @@ pub async fn get_account<'a>(
     let account_data = if let Some(keystore) = keystore {
         get_account_data_from_keystore(account, &keystore)?
     } else {
-        get_account_data_from_accounts_file(account, chain_id, accounts_file)?
+        get_account_data_from_accounts_files(account, chain_id, accounts_files)?
     };
 
     let account = build_account(account_data, chain_id, provider).await?;
@@ -364,18 +502,48 @@ pub fn get_account_data_from_accounts_file(
     name: &str,
     chain_id: FieldElement,
     path: &Utf8PathBuf,
+) -> Result<AccountData> {
+    get_account_data_from_accounts_files(name, chain_id, std::slice::from_ref(path))
+}
+
+/// Looks up the account in all of the accounts files which exist, in the given order.
+/// The account can be defined for a network in only one of the files.
+pub fn get_account_data_from_accounts_files(
+    name: &str,
+    chain_id: FieldElement,
+    paths: &[Utf8PathBuf],
 ) -> Result<AccountData> {
     raise_if_empty(name, "Account name")?;
-    check_account_file_exists(path)?;
+    let existing_paths: Vec<&Utf8PathBuf> = paths.iter().filter(|path| path.exists()).collect();
+    if existing_paths.is_empty() {
+        check_account_file_exists(paths.first().context("No accounts file passed")?)?;
+    }
 
-    let accounts: HashMap<String, HashMap<String, AccountData>> = read_and_parse_json_file(path)?;
     let network_name = chain_id_to_network_name(chain_id);
+    let mut found = vec![];
+    for path in existing_paths {
+        let mut accounts: HashMap<String, HashMap<String, AccountData>> =
+            read_and_parse_json_file(path)?;
+        if let Some(account) = accounts
+            .get_mut(&network_name)
+            .and_then(|accounts_map| accounts_map.remove(name))
+        {
+            found.push((path, account));
+        }
+    }
 
-    accounts
-        .get(&network_name)
-        .and_then(|accounts_map| accounts_map.get(name))
-        .cloned()
-        .ok_or_else(|| anyhow!("Account = {name} not found under network = {network_name}"))
+    match found.len() {
+        0 => bail!("Account = {name} not found under network = {network_name}"),
+        1 => Ok(found.remove(0).1),
+        _ => bail!(
+            "Account = {name} is defined under network = {network_name} in multiple accounts files = {}",
+            found
+                .iter()
+                .map(|(path, _)| path.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
 }
 
 fn read_and_parse_json_file<T: DeserializeOwned>(path: &Utf8PathBuf) -> Result<T> {
@@ -478,7 +646,7 @@ impl ErrorData {
 impl From<ContractErrorData> for ErrorData {
     fn from(value: ContractErrorData) -> Self {
         ErrorData {
-            data: value.revert_error,
+            data: decode_revert_reason(&value.revert_error),
         }
     }
 }
@@ -558,7 +726,7 @@ async fn get_revert_reason(
     {
         Err(WaitForTransactionError::TransactionError(
             TransactionError::Reverted(ErrorData {
-                data: reason.clone(),
+                data: decode_revert_reason(reason),
             }),
         ))
     } else {
@@ -655,6 +823,58 @@ pub fn apply_optional<T, R, F: FnOnce(T, R) -> T>(initial: T, option: Option<R>,
     }
 }
 
+/// Max fee which starknet-rs sets for a transaction sent without one, the estimated fee increased by 10%
+#[must_use]
+pub fn max_fee_from_estimate(estimated_fee: FieldElement) -> FieldElement {
+    estimated_fee + estimated_fee.floor_div(FieldElement::from(10_u8))
+}
+
+/// Checks whether the request failed because of the transport or rate limiting,
+/// in which case sending it again may succeed
+#[must_use]
+pub fn is_transient_error(error: &ProviderError) -> bool {
+    match error {
+        ProviderError::RateLimited => true,
+        ProviderError::Other(error) => matches!(
+            error
+                .as_any()
+                .downcast_ref::<JsonRpcClientError<HttpTransportError>>(),
+            Some(JsonRpcClientError::TransportError(_))
+        ),
+        _ => false,
+    }
+}
+
+/// Sends the request again up to `retries` times if it fails with a transient error,
+/// doubling the delay after each attempt
+pub async fn retry_on_transient_error<T, F, Fut>(
+    retries: u32,
+    mut request: F,
+) -> Result<T, StarknetCommandError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, ProviderError>>,
+{
+    let mut delay = Duration::from_millis(RETRY_INITIAL_DELAY_MS);
+    let mut attempts = 0;
+
+    loop {
+        attempts += 1;
+        match request().await {
+            Ok(value) => return Ok(value),
+            Err(error) if is_transient_error(&error) && attempts <= retries => {
+                tokio::time::sleep(delay).await;
+                delay *= 2;
+            }
+            Err(error) if attempts > 1 => {
+                let error = SNCastProviderError::from(error);
+                return Err(anyhow!("{error}, failed after {attempts} attempts").into());
+            }
+            Err(error) => return Err(StarknetCommandError::ProviderError(error.into())),
+        }
+    }
+}
+
 #[must_use]
 pub fn get_default_state_file_name(script_name: &str, chain_id: &str) -> String {
     format!("{script_name}_{chain_id}_{DEFAULT_STATE_FILE_SUFFIX}")
@@ -663,9 +883,11 @@ pub fn get_default_state_file_name(script_name: &str, chain_id: &str) -> String
 #[cfg(test)]
 mod tests {
     use crate::helpers::constants::KEYSTORE_PASSWORD_ENV_VAR;
+    use crate::response::errors::{SNCastProviderError, SNCastStarknetError, StarknetCommandError};
     use crate::{
         chain_id_to_network_name, extract_or_generate_salt, get_account_data_from_accounts_file,
-        get_account_data_from_keystore, get_block_id, udc_uniqueness, AccountType,
+        get_account_data_from_accounts_files, get_account_data_from_keystore, get_block_id,
+        retry_on_transient_error, udc_uniqueness, AccountType, Network,
     };
     use camino::Utf8PathBuf;
     use conversions::string::IntoHexStr;
@@ -676,6 +898,7 @@ mod tests {
     };
     use starknet::core::utils::UdcUniqueSettings;
     use starknet::core::utils::UdcUniqueness::{NotUnique, Unique};
+    use starknet::providers::ProviderError;
     use std::env;
 
     #[test]
@@ -757,6 +980,18 @@ mod tests {
         assert_eq!(network_name_sepolia, "alpha-sepolia");
     }
 
+    #[test]
+    fn test_network_chain_id() {
+        assert_eq!(
+            chain_id_to_network_name(Network::Mainnet.chain_id()),
+            "alpha-mainnet"
+        );
+        assert_eq!(
+            chain_id_to_network_name(Network::Sepolia.chain_id()),
+            "alpha-sepolia"
+        );
+    }
+
     #[test]
     fn test_get_account_data_from_accounts_file() {
         let account = get_account_data_from_accounts_file(
@@ -787,6 +1022,56 @@ mod tests {
         assert_eq!(account.account_type, Some(AccountType::Oz));
     }
 
+    #[test]
+    fn test_get_account_data_from_accounts_files() {
+        let chain_id = FieldElement::from_byte_slice_be("SN_SEPOLIA".as_bytes()).unwrap();
+        let accounts_files = [
+            Utf8PathBuf::from("tests/data/accounts/nonexistentfile.json"),
+            Utf8PathBuf::from("tests/data/accounts/accounts.json"),
+            Utf8PathBuf::from("tests/data/accounts/faulty_accounts.json"),
+        ];
+
+        let account =
+            get_account_data_from_accounts_files("user1", chain_id, &accounts_files).unwrap();
+        assert_eq!(
+            account.private_key.into_hex_string(),
+            "0xffd33878eed7767e7c546ce3fc026295"
+        );
+
+        let account =
+            get_account_data_from_accounts_files("with_wrong_address", chain_id, &accounts_files)
+                .unwrap();
+        assert_eq!(
+            account.address.map(IntoHexStr::into_hex_string),
+            Some("0x2".to_string())
+        );
+
+        let err =
+            get_account_data_from_accounts_files("user100", chain_id, &accounts_files).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Account = user100 not found under network = alpha-sepolia"
+        );
+    }
+
+    #[test]
+    fn test_get_account_data_from_accounts_files_defined_twice() {
+        let err = get_account_data_from_accounts_files(
+            "user1",
+            FieldElement::from_byte_slice_be("SN_SEPOLIA".as_bytes()).unwrap(),
+            &[
+                Utf8PathBuf::from("tests/data/accounts/accounts.json"),
+                Utf8PathBuf::from("tests/data/accounts/../accounts/accounts.json"),
+            ],
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Account = user1 is defined under network = alpha-sepolia in multiple accounts files = tests/data/accounts/accounts.json, tests/data/accounts/../accounts/accounts.json"
+        );
+    }
+
     #[test]
     fn test_get_account_data_from_keystore() {
         env::set_var(KEYSTORE_PASSWORD_ENV_VAR, "123");
@@ -854,4 +1139,42 @@ mod tests {
             .to_string()
             .contains("Account = user1 not found under network = CUSTOM_CHAIN_ID"));
     }
+
+    #[tokio::test]
+    async fn test_retry_on_transient_error_reports_attempts() {
+        let mut attempts = 0;
+        let result: Result<(), _> = retry_on_transient_error(2, || {
+            attempts += 1;
+            async { Err(ProviderError::RateLimited) }
+        })
+        .await;
+
+        assert_eq!(attempts, 3);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Request rate limited, failed after 3 attempts"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retry_on_transient_error_does_not_retry_other_errors() {
+        let mut attempts = 0;
+        let result: Result<(), _> = retry_on_transient_error(2, || {
+            attempts += 1;
+            async {
+                Err(ProviderError::StarknetError(
+                    starknet::core::types::StarknetError::ContractNotFound,
+                ))
+            }
+        })
+        .await;
+
+        assert_eq!(attempts, 1);
+        assert!(matches!(
+            result,
+            Err(StarknetCommandError::ProviderError(
+                SNCastProviderError::StarknetError(SNCastStarknetError::ContractNotFound)
+            ))
+        ));
+    }
 }
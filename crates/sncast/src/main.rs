@@ -1,27 +1,32 @@
 use crate::starknet_commands::account::Account;
 use crate::starknet_commands::show_config::ShowConfig;
 use crate::starknet_commands::{
-    account, call::Call, declare::Declare, deploy::Deploy, invoke::Invoke, multicall::Multicall,
-    script::Script, tx_status::TxStatus,
+    abi::Abi, account, balance::Balance, call::Call, compute_address::ComputeAddress,
+    declare::Declare, deploy::Deploy, get_events::GetEvents, hash, hash::Hash, invoke::Invoke,
+    multicall::Multicall, script::Script, tx_events::TxEvents, tx_status::TxStatus,
 };
-use anyhow::{Context, Result};
-use configuration::load_global_config;
-use sncast::response::print::{print_command_result, OutputFormat};
+use anyhow::{bail, ensure, Context, Result};
+use configuration::{find_global_config_file, load_global_config};
+use sncast::response::print::{print_account_command_result, print_command_result, OutputFormat};
 
 use camino::Utf8PathBuf;
 use clap::{Parser, Subcommand};
+use shared::print::print_as_warning;
 use shared::verify_and_warn_if_incompatible_rpc_version;
-use sncast::helpers::configuration::CastConfig;
+use sncast::helpers::configuration::{describe_config_resolution, CastConfig};
 use sncast::helpers::constants::{DEFAULT_ACCOUNTS_FILE, DEFAULT_MULTICALL_CONTENTS};
 use sncast::helpers::scarb_utils::{
     assert_manifest_path_exists, build, build_and_load_artifacts, get_package_metadata,
     get_scarb_metadata_with_deps, BuildConfig,
 };
 use sncast::response::errors::handle_starknet_command_error;
+use sncast::response::structs::{TransactionCommandResponse, WithReceiptResponse};
 use sncast::{
-    chain_id_to_network_name, get_account, get_block_id, get_chain_id, get_default_state_file_name,
-    get_nonce, get_provider, NumbersFormat, ValidatedWaitParams, WaitForTx,
+    build_provider, chain_id_to_network_name, get_account, get_block_id, get_chain_id,
+    get_default_state_file_name, get_nonce, verify_network, FeltFormat, Network, NumbersFormat,
+    ValidatedWaitParams, WaitForTx,
 };
+use starknet::core::types::EventFilter;
 use starknet::core::utils::get_selector_from_name;
 use starknet::providers::jsonrpc::HttpTransport;
 use starknet::providers::JsonRpcClient;
@@ -71,15 +76,22 @@ struct Cli {
     #[clap(short = 'u', long = "url")]
     rpc_url: Option<String>,
 
+    /// Well-known network whose public RPC node is used; overrides url from snfoundry.toml,
+    /// but is overridden by --url
+    #[clap(long, value_enum)]
+    network: Option<Network>,
+
     /// Account to be used for contract declaration;
     /// When using keystore (`--keystore`), this should be a path to account file    
     /// When using accounts file, this should be an account name
     #[clap(short = 'a', long)]
     account: Option<String>,
 
-    /// Path to the file holding accounts info
+    /// Path to the file holding accounts info.
+    /// Can be passed multiple times, accounts are looked up in all of the files in the given order
+    /// and new accounts are written to the first one
     #[clap(short = 'f', long = "accounts-file")]
-    accounts_file_path: Option<Utf8PathBuf>,
+    accounts_file_path: Vec<Utf8PathBuf>,
 
     /// Path to keystore file; if specified, --account should be a path to starkli JSON account file
     #[clap(short, long)]
@@ -93,6 +105,10 @@ struct Cli {
     #[clap(long, conflicts_with = "int_format")]
     hex_format: bool,
 
+    /// Format of felts in the output; `auto` decodes printable short strings and displays other felts as hex
+    #[clap(long, value_enum, conflicts_with_all = ["int_format", "hex_format"])]
+    felt_format: Option<FeltFormat>,
+
     /// If passed, output will be displayed in json format
     #[clap(short, long)]
     json: bool,
@@ -109,6 +125,15 @@ struct Cli {
     #[clap(long)]
     wait_retry_interval: Option<u8>,
 
+    /// If passed, the signed transaction will be printed instead of being sent,
+    /// `account delete` prints the account which would be removed, other commands fail when it is passed
+    #[clap(long, global = true)]
+    dry_run: bool,
+
+    /// If passed, prints which snfoundry.toml file and profile are used
+    #[clap(long)]
+    verbose: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -141,22 +166,90 @@ enum Commands {
 
     /// Get the status of a transaction
     TxStatus(TxStatus),
+
+    /// Get events emitted by a transaction, decoded with ABIs of the emitting contracts
+    TxEvents(TxEvents),
+
+    /// Get events matching the filter, decoded with ABIs of the emitting contracts
+    GetEvents(GetEvents),
+
+    /// Save the ABI of a declared class to a file
+    Abi(Abi),
+
+    /// Get ETH and STRK balances of an account
+    Balance(Balance),
+
+    /// Compute hashes and storage addresses offline
+    Hash(Hash),
+
+    /// Compute address of a contract or an account offline
+    ComputeAddress(ComputeAddress),
+}
+
+impl Commands {
+    /// Name of the command if it doesn't support `--dry-run`.
+    /// Only commands sending transactions and `account delete` support it
+    fn name_if_dry_run_unsupported(&self) -> Option<&'static str> {
+        match self {
+            Commands::Declare(_) | Commands::Deploy(_) | Commands::Invoke(_) => None,
+            Commands::Script(script) => match script.command {
+                starknet_commands::script::Commands::Init(_) => Some("script init"),
+                // `script run` rejects `--dry-run` with its own, more specific error
+                starknet_commands::script::Commands::Run(_) => None,
+            },
+            Commands::Multicall(multicall) => match multicall.command {
+                starknet_commands::multicall::Commands::New(_) => Some("multicall new"),
+                starknet_commands::multicall::Commands::Run(_) => None,
+            },
+            Commands::Account(account) => match account.command {
+                account::Commands::Add(_) => Some("account add"),
+                account::Commands::Create(_) => Some("account create"),
+                account::Commands::Rename(_) => Some("account rename"),
+                account::Commands::Deploy(_) | account::Commands::Delete(_) => None,
+            },
+            Commands::Call(_) => Some("call"),
+            Commands::ShowConfig(_) => Some("show-config"),
+            Commands::TxStatus(_) => Some("tx-status"),
+            Commands::TxEvents(_) => Some("tx-events"),
+            Commands::GetEvents(_) => Some("get-events"),
+            Commands::Abi(_) => Some("abi"),
+            Commands::Balance(_) => Some("balance"),
+            Commands::Hash(_) => Some("hash"),
+            Commands::ComputeAddress(_) => Some("compute-address"),
+        }
+    }
 }
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
 
-    let numbers_format = NumbersFormat::from_flags(cli.hex_format, cli.int_format);
+    if cli.dry_run {
+        if let Some(command) = cli.command.name_if_dry_run_unsupported() {
+            bail!("--dry-run can't be used with {command}, it is supported only by commands sending transactions and account delete");
+        }
+    }
+
+    let numbers_format = NumbersFormat::from_flags(cli.hex_format, cli.int_format, cli.felt_format);
     let output_format = OutputFormat::from_flag(cli.json);
 
     let runtime = Runtime::new().expect("Failed to instantiate Runtime");
 
     if let Commands::Script(script) = &cli.command {
         run_script_command(&cli, runtime, script, numbers_format, &output_format)
+    } else if let Commands::Hash(hash) = &cli.command {
+        run_hash_command(hash, numbers_format, &output_format)
+    } else if let Commands::ComputeAddress(compute_address) = &cli.command {
+        let mut result = starknet_commands::compute_address::compute_address(compute_address);
+        print_command_result(
+            "compute-address",
+            &mut result,
+            numbers_format,
+            &output_format,
+        )
     } else {
-        let mut config = load_global_config::<CastConfig>(&None, &cli.profile)?;
+        let mut config = load_cast_config(&None, &cli)?;
         update_cast_config(&mut config, &cli);
-        let provider = get_provider(&config.url)?;
+        let provider = build_provider(&config)?;
         runtime.block_on(run_async_command(
             cli,
             config,
@@ -176,17 +269,22 @@ async fn run_async_command(
     output_format: OutputFormat,
 ) -> Result<()> {
     verify_and_warn_if_incompatible_rpc_version(&provider, &config.url).await?;
+    if let Some(network) = cli.network {
+        verify_network(&provider, network).await?;
+    }
 
     let wait_config = WaitForTx {
         wait: cli.wait,
         wait_params: config.wait_params,
     };
+    // A max fee passed as a percentage refers to the balance in the ETH fee token set in the profile
+    let eth_address = config.eth_address;
 
     match cli.command {
-        Commands::Declare(declare) => {
+        Commands::Declare(declare) if declare.all => {
             let account = get_account(
                 &config.account,
-                &config.accounts_file,
+                &config.accounts_files(),
                 &provider,
                 config.keystore,
             )
@@ -202,41 +300,109 @@ async fn run_async_command(
                 },
             )
             .expect("Failed to build contract");
-            let mut result = starknet_commands::declare::declare(
-                &declare.contract,
-                declare.max_fee,
+            let mut result = starknet_commands::declare::declare_all(
+                declare
+                    .max_fee
+                    .map(|max_fee| max_fee.with_eth_address(eth_address)),
                 &account,
                 declare.nonce,
                 &artifacts,
                 wait_config,
+                cli.dry_run,
             )
-            .await
-            .map_err(handle_starknet_command_error);
+            .await;
 
+            print_command_result("declare", &mut result, numbers_format, &output_format)?;
+            let failed_count = result.map_or(0, |response| response.failed_contracts.len());
+            ensure!(
+                failed_count == 0,
+                "Failed to declare {failed_count} contract(s)"
+            );
+            Ok(())
+        }
+        Commands::Declare(declare) => {
+            let account = get_account(
+                &config.account,
+                &config.accounts_files(),
+                &provider,
+                config.keystore,
+            )
+            .await?;
+            let result = if let (Some(sierra_file), Some(casm_file)) =
+                (&declare.sierra_file, &declare.casm_file)
+            {
+                starknet_commands::declare::declare_from_files(
+                    sierra_file,
+                    casm_file,
+                    declare
+                        .max_fee
+                        .map(|max_fee| max_fee.with_eth_address(eth_address)),
+                    &account,
+                    declare.nonce,
+                    wait_config,
+                    cli.dry_run,
+                )
+                .await
+            } else {
+                let manifest_path = assert_manifest_path_exists()?;
+                let package_metadata = get_package_metadata(&manifest_path, &declare.package)?;
+                let artifacts = build_and_load_artifacts(
+                    &package_metadata,
+                    &BuildConfig {
+                        scarb_toml_path: manifest_path,
+                        json: cli.json,
+                        profile: cli.profile.unwrap_or("dev".to_string()),
+                    },
+                )
+                .expect("Failed to build contract");
+                starknet_commands::declare::declare(
+                    &declare
+                        .contract
+                        .expect("Contract name is required without `--sierra-file`"),
+                    declare
+                        .max_fee
+                        .map(|max_fee| max_fee.with_eth_address(eth_address)),
+                    &account,
+                    declare.nonce,
+                    &artifacts,
+                    wait_config,
+                    cli.dry_run,
+                )
+                .await
+            };
+            let result = result.map_err(handle_starknet_command_error);
+
+            let mut result =
+                with_receipt_if_waited(&provider, cli.wait, result, numbers_format).await;
             print_command_result("declare", &mut result, numbers_format, &output_format)?;
             Ok(())
         }
         Commands::Deploy(deploy) => {
             let account = get_account(
                 &config.account,
-                &config.accounts_file,
+                &config.accounts_files(),
                 &provider,
                 config.keystore,
             )
             .await?;
-            let mut result = starknet_commands::deploy::deploy(
+            let result = starknet_commands::deploy::deploy(
                 deploy.class_hash,
                 deploy.constructor_calldata,
                 deploy.salt,
                 deploy.unique,
-                deploy.max_fee,
+                deploy
+                    .max_fee
+                    .map(|max_fee| max_fee.with_eth_address(eth_address)),
                 &account,
                 deploy.nonce,
                 wait_config,
+                cli.dry_run,
             )
             .await
             .map_err(handle_starknet_command_error);
 
+            let mut result =
+                with_receipt_if_waited(&provider, cli.wait, result, numbers_format).await;
             print_command_result("deploy", &mut result, numbers_format, &output_format)?;
             Ok(())
         }
@@ -250,6 +416,7 @@ async fn run_async_command(
                 call.calldata,
                 &provider,
                 block_id.as_ref(),
+                call.retry,
             )
             .await
             .map_err(handle_starknet_command_error);
@@ -258,26 +425,98 @@ async fn run_async_command(
             Ok(())
         }
         Commands::Invoke(invoke) => {
+            if let Some(path) = &invoke.from_file {
+                ensure!(
+                    !cli.dry_run,
+                    "--dry-run can't be used with --from-file, the transaction in the file is already signed"
+                );
+                let result =
+                    starknet_commands::invoke::invoke_from_file(&provider, path, wait_config)
+                        .await
+                        .map_err(handle_starknet_command_error);
+
+                let mut result =
+                    with_receipt_if_waited(&provider, cli.wait, result, numbers_format).await;
+                print_command_result("invoke", &mut result, numbers_format, &output_format)?;
+                return Ok(());
+            }
+
             let account = get_account(
                 &config.account,
-                &config.accounts_file,
+                &config.accounts_files(),
                 &provider,
                 config.keystore,
             )
             .await?;
-            let mut result = starknet_commands::invoke::invoke(
-                invoke.contract_address,
-                get_selector_from_name(&invoke.function)
-                    .context("Failed to convert entry point selector to FieldElement")?,
+            let contract_address = invoke
+                .contract_address
+                .expect("Contract address is required by clap");
+            let function = invoke.function.expect("Function is required by clap");
+            let function_selector = get_selector_from_name(&function)
+                .context("Failed to convert entry point selector to FieldElement")?;
+
+            if let Some(path) = &invoke.export {
+                let mut result = starknet_commands::invoke::export_invoke(
+                    contract_address,
+                    function_selector,
+                    invoke.calldata,
+                    invoke
+                        .max_fee
+                        .expect("Max fee is required by clap")
+                        .with_eth_address(eth_address),
+                    &account,
+                    invoke.nonce,
+                    path,
+                )
+                .await
+                .map_err(handle_starknet_command_error);
+
+                print_command_result("invoke", &mut result, numbers_format, &output_format)?;
+                return Ok(());
+            }
+
+            if invoke.simulate {
+                let mut result = starknet_commands::invoke::simulate_invoke(
+                    contract_address,
+                    function_selector,
+                    invoke.calldata,
+                    invoke
+                        .max_fee
+                        .map(|max_fee| max_fee.with_eth_address(eth_address)),
+                    &account,
+                    invoke.nonce,
+                )
+                .await
+                .map_err(handle_starknet_command_error);
+
+                print_command_result("invoke", &mut result, numbers_format, &output_format)?;
+                return Ok(());
+            }
+
+            let result = starknet_commands::invoke::invoke(
+                contract_address,
+                function_selector,
                 invoke.calldata,
-                invoke.max_fee,
+                invoke
+                    .max_fee
+                    .map(|max_fee| max_fee.with_eth_address(eth_address)),
                 &account,
                 invoke.nonce,
                 wait_config,
+                cli.dry_run,
             )
-            .await
-            .map_err(handle_starknet_command_error);
+            .await;
+
+            let result = match (result, invoke.nonce) {
+                (Err(error), Some(nonce)) => Err(
+                    starknet_commands::invoke::handle_invalid_nonce_error(error, &account, nonce)
+                        .await,
+                ),
+                (result, _) => result.map_err(handle_starknet_command_error),
+            };
 
+            let mut result =
+                with_receipt_if_waited(&provider, cli.wait, result, numbers_format).await;
             print_command_result("invoke", &mut result, numbers_format, &output_format)?;
             Ok(())
         }
@@ -300,7 +539,7 @@ async fn run_async_command(
                 starknet_commands::multicall::Commands::Run(run) => {
                     let account = get_account(
                         &config.account,
-                        &config.accounts_file,
+                        &config.accounts_files(),
                         &provider,
                         config.keystore,
                     )
@@ -308,8 +547,10 @@ async fn run_async_command(
                     let mut result = starknet_commands::multicall::run::run(
                         &run.path,
                         &account,
-                        run.max_fee,
+                        run.max_fee
+                            .map(|max_fee| max_fee.with_eth_address(eth_address)),
                         wait_config,
+                        cli.dry_run,
                     )
                     .await;
 
@@ -334,11 +575,17 @@ async fn run_async_command(
                 )
                 .await;
 
-                print_command_result("account add", &mut result, numbers_format, &output_format)?;
+                print_account_command_result(
+                    "account add",
+                    &mut result,
+                    numbers_format,
+                    &output_format,
+                )?;
                 Ok(())
             }
             account::Commands::Create(create) => {
                 let chain_id = get_chain_id(&provider).await?;
+                let private_key = create.private_key_from_mnemonic()?;
                 let account = if config.keystore.is_none() {
                     create
                         .name
@@ -357,10 +604,12 @@ async fn run_async_command(
                     create.salt,
                     create.add_profile,
                     create.class_hash,
+                    create.skip_fee_estimate,
+                    private_key,
                 )
                 .await;
 
-                print_command_result(
+                print_account_command_result(
                     "account create",
                     &mut result,
                     numbers_format,
@@ -368,7 +617,38 @@ async fn run_async_command(
                 )?;
                 Ok(())
             }
-            account::Commands::Deploy(deploy) => {
+            account::Commands::Deploy(deploy) if deploy.all => {
+                let chain_id = get_chain_id(&provider).await?;
+                let mut result = starknet_commands::account::deploy::deploy_all(
+                    &provider,
+                    config.accounts_file,
+                    deploy
+                        .max_fee
+                        .map(|max_fee| max_fee.with_eth_address(eth_address)),
+                    chain_id,
+                    wait_config,
+                    config.keystore.clone(),
+                    cli.dry_run,
+                )
+                .await;
+
+                print_account_command_result(
+                    "account deploy",
+                    &mut result,
+                    numbers_format,
+                    &output_format,
+                )?;
+                let failed_count = result.map_or(0, |response| response.failed_accounts.len());
+                ensure!(
+                    failed_count == 0,
+                    "Failed to deploy {failed_count} account(s)"
+                );
+                Ok(())
+            }
+            account::Commands::Deploy(mut deploy) => {
+                deploy.max_fee = deploy
+                    .max_fee
+                    .map(|max_fee| max_fee.with_eth_address(eth_address));
                 let chain_id = get_chain_id(&provider).await?;
                 let keystore_path = config.keystore.clone();
                 let mut result = starknet_commands::account::deploy::deploy(
@@ -379,10 +659,11 @@ async fn run_async_command(
                     wait_config,
                     &config.account,
                     keystore_path,
+                    cli.dry_run,
                 )
                 .await;
 
-                print_command_result(
+                print_account_command_result(
                     "account deploy",
                     &mut result,
                     numbers_format,
@@ -401,9 +682,11 @@ async fn run_async_command(
                     &config.accounts_file,
                     &network_name,
                     delete.yes,
+                    cli.dry_run,
+                    &delete.backup,
                 );
 
-                print_command_result(
+                print_account_command_result(
                     "account delete",
                     &mut result,
                     numbers_format,
@@ -411,6 +694,29 @@ async fn run_async_command(
                 )?;
                 Ok(())
             }
+            account::Commands::Rename(rename) => {
+                let network_name = match rename.network {
+                    Some(network) => network,
+                    None => chain_id_to_network_name(get_chain_id(&provider).await?),
+                };
+
+                let mut result = starknet_commands::account::rename::rename(
+                    &rename.from,
+                    &rename.to,
+                    &config.accounts_file,
+                    &network_name,
+                    &rename.backup,
+                )
+                .await;
+
+                print_command_result(
+                    "account rename",
+                    &mut result,
+                    numbers_format,
+                    &output_format,
+                )?;
+                Ok(())
+            }
         },
         Commands::ShowConfig(_) => {
             let mut result =
@@ -426,10 +732,118 @@ async fn run_async_command(
             print_command_result("tx-status", &mut result, numbers_format, &output_format)?;
             Ok(())
         }
-        Commands::Script(_) => unreachable!(),
+        Commands::TxEvents(tx_events) => {
+            let mut result = starknet_commands::tx_events::tx_events(
+                &provider,
+                tx_events.transaction_hash,
+                numbers_format,
+            )
+            .await
+            .context("Failed to get transaction events");
+            print_command_result("tx-events", &mut result, numbers_format, &output_format)?;
+            Ok(())
+        }
+        Commands::GetEvents(get_events) => {
+            let filter = EventFilter {
+                from_block: get_events
+                    .from_block
+                    .as_deref()
+                    .map(get_block_id)
+                    .transpose()?,
+                to_block: get_events
+                    .to_block
+                    .as_deref()
+                    .map(get_block_id)
+                    .transpose()?,
+                address: get_events.address,
+                keys: starknet_commands::get_events::parse_keys(&get_events.keys)?,
+            };
+            let mut result = starknet_commands::get_events::get_events(
+                &provider,
+                filter,
+                get_events.chunk_size,
+                get_events.limit,
+                numbers_format,
+            )
+            .await
+            .context("Failed to get events");
+            print_command_result("get-events", &mut result, numbers_format, &output_format)?;
+            Ok(())
+        }
+        Commands::Abi(abi) => {
+            let mut result = starknet_commands::abi::abi(
+                &provider,
+                abi.class_hash,
+                abi.contract_address,
+                abi.output,
+            )
+            .await
+            .context("Failed to get ABI");
+            print_command_result("abi", &mut result, numbers_format, &output_format)?;
+            Ok(())
+        }
+        Commands::Balance(balance) => {
+            let address = match balance.address {
+                Some(address) => address,
+                None => {
+                    let account = get_account(
+                        &config.account,
+                        &config.accounts_files(),
+                        &provider,
+                        config.keystore,
+                    )
+                    .await?;
+                    starknet::accounts::Account::address(&account)
+                }
+            };
+            let block_id = get_block_id(&balance.block_id)?;
+
+            let mut result = starknet_commands::balance::balance(
+                &provider,
+                address,
+                balance.token,
+                balance.eth_address.or(config.eth_address),
+                balance.strk_address.or(config.strk_address),
+                block_id.as_ref(),
+            )
+            .await
+            .map_err(handle_starknet_command_error);
+            print_command_result("balance", &mut result, numbers_format, &output_format)?;
+            Ok(())
+        }
+        Commands::Script(_) | Commands::Hash(_) | Commands::ComputeAddress(_) => unreachable!(),
     }
 }
 
+/// After a transaction was awaited with `--wait`, adds its receipt with the actual fee paid to the response.
+/// The transaction was already accepted at this point, so failing to get the receipt is only a warning
+async fn with_receipt_if_waited<T: TransactionCommandResponse>(
+    provider: &JsonRpcClient<HttpTransport>,
+    wait: bool,
+    result: Result<T>,
+    numbers_format: NumbersFormat,
+) -> Result<WithReceiptResponse<T>> {
+    let response = result?;
+    let receipt = match response.sent_transaction_hash() {
+        Some(transaction_hash) if wait => {
+            match starknet_commands::receipt::receipt(provider, transaction_hash, numbers_format)
+                .await
+            {
+                Ok(receipt) => Some(receipt),
+                Err(error) => {
+                    print_as_warning(
+                        &handle_starknet_command_error(error)
+                            .context("Failed to get transaction receipt"),
+                    );
+                    None
+                }
+            }
+        }
+        _ => None,
+    };
+    Ok(WithReceiptResponse { response, receipt })
+}
+
 fn run_script_command(
     cli: &Cli,
     runtime: Runtime,
@@ -443,19 +857,23 @@ fn run_script_command(
             print_command_result("script init", &mut result, numbers_format, output_format)?;
         }
         starknet_commands::script::Commands::Run(run) => {
+            ensure!(
+                !cli.dry_run,
+                "--dry-run can't be used with script run, transactions of scripts depend on the results of the sent ones"
+            );
             let manifest_path = assert_manifest_path_exists()?;
             let package_metadata = get_package_metadata(&manifest_path, &run.package)?;
 
-            let mut config = load_global_config::<CastConfig>(
-                &Some(package_metadata.root.clone()),
-                &cli.profile,
-            )?;
+            let mut config = load_cast_config(&Some(package_metadata.root.clone()), cli)?;
             update_cast_config(&mut config, cli);
-            let provider = get_provider(&config.url)?;
+            let provider = build_provider(&config)?;
             runtime.block_on(verify_and_warn_if_incompatible_rpc_version(
                 &provider,
                 &config.url,
             ))?;
+            if let Some(network) = cli.network {
+                runtime.block_on(verify_network(&provider, network))?;
+            }
 
             let mut artifacts = build_and_load_artifacts(
                 &package_metadata,
@@ -497,6 +915,7 @@ fn run_script_command(
                 runtime,
                 &config,
                 state_file_path,
+                run.track_nonce,
             );
 
             print_command_result("script run", &mut result, numbers_format, output_format)?;
@@ -506,6 +925,47 @@ fn run_script_command(
     Ok(())
 }
 
+fn run_hash_command(
+    hash: &Hash,
+    numbers_format: NumbersFormat,
+    output_format: &OutputFormat,
+) -> Result<()> {
+    match &hash.command {
+        hash::Commands::Pedersen(pedersen) => {
+            let mut result = Ok(hash::pedersen(pedersen));
+            print_command_result("hash pedersen", &mut result, numbers_format, output_format)
+        }
+        hash::Commands::Poseidon(poseidon) => {
+            let mut result = Ok(hash::poseidon(poseidon));
+            print_command_result("hash poseidon", &mut result, numbers_format, output_format)
+        }
+        hash::Commands::StorageAddress(storage_address) => {
+            let mut result = hash::storage_address(storage_address);
+            print_command_result(
+                "hash storage-address",
+                &mut result,
+                numbers_format,
+                output_format,
+            )
+        }
+    }
+}
+
+/// Loads the profile from snfoundry.toml found in `path` or the current directory, or their parents
+fn load_cast_config(path: &Option<Utf8PathBuf>, cli: &Cli) -> Result<CastConfig> {
+    if cli.verbose {
+        let current_dir = Utf8PathBuf::try_from(std::env::current_dir()?)?;
+        let search_roots: Vec<Utf8PathBuf> = path.iter().cloned().chain([current_dir]).collect();
+        let config_file = find_global_config_file(path);
+
+        for line in describe_config_resolution(&search_roots, config_file.as_ref(), &cli.profile) {
+            eprintln!("{line}");
+        }
+    }
+
+    load_global_config::<CastConfig>(path, &cli.profile)
+}
+
 fn update_cast_config(config: &mut CastConfig, cli: &Cli) {
     macro_rules! clone_or_else {
         ($field:expr, $config_field:expr) => {
@@ -513,16 +973,30 @@ fn update_cast_config(config: &mut CastConfig, cli: &Cli) {
         };
     }
 
-    config.url = clone_or_else!(cli.rpc_url, config.url);
+    config.url = cli
+        .rpc_url
+        .clone()
+        .or_else(|| cli.network.map(|network| network.url().to_string()))
+        .unwrap_or_else(|| config.url.clone());
     config.account = clone_or_else!(cli.account, config.account);
     config.keystore = cli.keystore.clone().or(config.keystore.clone());
 
     if config.accounts_file == Utf8PathBuf::default() {
         config.accounts_file = Utf8PathBuf::from(DEFAULT_ACCOUNTS_FILE);
     }
-    let new_accounts_file = clone_or_else!(cli.accounts_file_path, config.accounts_file);
+    // Accounts files passed as flags replace all of the accounts files from the profile
+    if let Some((accounts_file, additional_accounts_files)) = cli.accounts_file_path.split_first() {
+        config.accounts_file = accounts_file.clone();
+        config.additional_accounts_files = additional_accounts_files.to_vec();
+    }
 
-    config.accounts_file = Utf8PathBuf::from(shellexpand::tilde(&new_accounts_file).to_string());
+    let expand_tilde = |path: &Utf8PathBuf| Utf8PathBuf::from(shellexpand::tilde(path).to_string());
+    config.accounts_file = expand_tilde(&config.accounts_file);
+    config.additional_accounts_files = config
+        .additional_accounts_files
+        .iter()
+        .map(expand_tilde)
+        .collect();
 
     config.wait_params = ValidatedWaitParams::new(
         clone_or_else!(
@@ -1,6 +1,7 @@
 use crate::{handle_rpc_error, ErrorData, WaitForTransactionError};
 use anyhow::anyhow;
 use conversions::serde::serialize::CairoSerialize;
+use shared::utils::decode_revert_reason;
 use starknet::core::types::StarknetError::{
     ContractError, TransactionExecutionError, ValidationFailure,
 };
@@ -108,8 +109,15 @@ impl From<StarknetError> for SNCastStarknetError {
             StarknetError::InvalidTransactionIndex => SNCastStarknetError::InvalidTransactionIndex,
             StarknetError::ClassHashNotFound => SNCastStarknetError::ClassHashNotFound,
             StarknetError::TransactionHashNotFound => SNCastStarknetError::TransactionHashNotFound,
-            ContractError(err) => SNCastStarknetError::ContractError(err),
-            TransactionExecutionError(err) => SNCastStarknetError::TransactionExecutionError(err),
+            ContractError(err) => SNCastStarknetError::ContractError(ContractErrorData {
+                revert_error: decode_revert_reason(&err.revert_error),
+            }),
+            TransactionExecutionError(err) => {
+                SNCastStarknetError::TransactionExecutionError(TransactionExecutionErrorData {
+                    transaction_index: err.transaction_index,
+                    execution_error: decode_revert_reason(&err.execution_error),
+                })
+            }
             StarknetError::ClassAlreadyDeclared => SNCastStarknetError::ClassAlreadyDeclared,
             StarknetError::InvalidTransactionNonce => SNCastStarknetError::InvalidTransactionNonce,
             StarknetError::InsufficientMaxFee => SNCastStarknetError::InsufficientMaxFee,
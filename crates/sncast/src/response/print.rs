@@ -7,7 +7,7 @@ use serde::{Serialize, Serializer};
 
 use crate::NumbersFormat;
 
-use super::structs::CommandResponse;
+use super::structs::{AccountCommandResponse, CommandResponse};
 
 pub enum OutputFormat {
     Json,
@@ -88,6 +88,45 @@ pub fn print_command_result<T: CommandResponse>(
     Ok(())
 }
 
+/// Prints the result of an `account` subcommand.
+/// With `--json`, only the account details are printed so the output has a stable schema.
+pub fn print_account_command_result<T: AccountCommandResponse>(
+    command: &str,
+    result: &mut Result<T>,
+    numbers_format: NumbersFormat,
+    output_format: &OutputFormat,
+) -> Result<()> {
+    if let (OutputFormat::Json, Ok(response)) = (output_format, result.as_ref()) {
+        if let Some(account) = response.account_details() {
+            let account_value = serde_json::to_value(account)?;
+            println!(
+                "{}",
+                serde_json::to_string(&format_json_numbers(account_value, numbers_format))?
+            );
+            return Ok(());
+        }
+    }
+
+    print_command_result(command, result, numbers_format, output_format)
+}
+
+fn format_json_numbers(value: Value, numbers_format: NumbersFormat) -> Value {
+    match value {
+        Value::Object(obj) => Value::Object(
+            obj.into_iter()
+                .map(|(k, v)| (k, format_json_numbers(v, numbers_format)))
+                .collect(),
+        ),
+        Value::String(s) => {
+            match apply_numbers_formatting(OutputValue::String(s), numbers_format) {
+                OutputValue::String(formatted) => Value::String(formatted),
+                OutputValue::Array(_) => unreachable!("Formatting a string yields a string"),
+            }
+        }
+        other => other,
+    }
+}
+
 fn pretty_output(output: OutputData, output_format: &OutputFormat) -> Result<Vec<String>> {
     match output_format {
         OutputFormat::Json => {
@@ -145,11 +184,7 @@ fn apply_numbers_formatting(value: OutputValue, formatting: NumbersFormat) -> Ou
     match value {
         OutputValue::String(input) => {
             if let Ok(field) = FieldElement::from_str(&input) {
-                return match formatting {
-                    NumbersFormat::Decimal => OutputValue::String(format!("{field:#}")),
-                    NumbersFormat::Hex => OutputValue::String(format!("{field:#x}")),
-                    NumbersFormat::Default => OutputValue::String(input),
-                };
+                return OutputValue::String(formatting.format_felt(field).unwrap_or(input));
             }
             OutputValue::String(input)
         }
@@ -223,6 +258,29 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_format_json_value_auto() {
+        let json_value = OutputValue::Array(vec![
+            OutputValue::String(String::from("0x68656c6c6f")),
+            OutputValue::String(String::from("0x3039")),
+            OutputValue::String(String::from("12345")),
+            OutputValue::String(String::from(
+                "0x49d36570d4e46f48e99674bd3fcc84644ddd6b96f7c741b1562b82f9e004dc7",
+            )),
+        ]);
+
+        let actual = apply_numbers_formatting(json_value, NumbersFormat::Auto);
+        let expected = OutputValue::Array(vec![
+            OutputValue::String(String::from("'hello'")),
+            OutputValue::String(String::from("0x3039")),
+            OutputValue::String(String::from("0x3039")),
+            OutputValue::String(String::from(
+                "0x49d36570d4e46f48e99674bd3fcc84644ddd6b96f7c741b1562b82f9e004dc7",
+            )),
+        ]);
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn test_struct_value_to_output_data() {
         let mut json_value = Map::new();
@@ -8,6 +8,13 @@ pub struct Decimal(pub u64);
 #[derive(Clone, Debug, Deserialize, CairoSerialize, PartialEq)]
 pub struct Felt(pub FieldElement);
 
+impl Felt {
+    #[must_use]
+    pub fn zero() -> Self {
+        Felt(FieldElement::ZERO)
+    }
+}
+
 impl Serialize for Decimal {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -35,8 +42,28 @@ where
     serializer.serialize_str(&format!("{val:#}"))
 }
 
+fn serialize_optional_as_decimal<S>(value: &Option<Felt>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match value {
+        Some(value) => serialize_as_decimal(value, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
 pub trait CommandResponse: Serialize {}
 
+pub trait TransactionCommandResponse: CommandResponse {
+    /// Hash of the transaction the command sent, if it sent one
+    fn sent_transaction_hash(&self) -> Option<FieldElement>;
+}
+
+pub trait AccountCommandResponse: CommandResponse {
+    /// Details of the account the command operated on, if the command produced one
+    fn account_details(&self) -> Option<&AccountDetailsResponse>;
+}
+
 #[derive(Serialize, CairoSerialize, Clone)]
 pub struct CallResponse {
     pub response: Vec<Felt>,
@@ -46,9 +73,26 @@ impl CommandResponse for CallResponse {}
 #[derive(Serialize, Deserialize, CairoSerialize, Clone, Debug, PartialEq)]
 pub struct InvokeResponse {
     pub transaction_hash: Felt,
+    // State files written by older versions do not contain the nonce
+    #[serde(default = "Felt::zero")]
+    pub nonce: Felt,
 }
 impl CommandResponse for InvokeResponse {}
 
+impl TransactionCommandResponse for InvokeResponse {
+    fn sent_transaction_hash(&self) -> Option<FieldElement> {
+        Some(self.transaction_hash.0)
+    }
+}
+
+#[derive(Serialize)]
+pub struct InvokeExportResponse {
+    pub transaction_hash: Felt,
+    pub nonce: Felt,
+    pub path: Utf8PathBuf,
+}
+impl CommandResponse for InvokeExportResponse {}
+
 #[derive(Clone, Serialize, Deserialize, CairoSerialize, Debug, PartialEq)]
 pub struct DeployResponse {
     pub contract_address: Felt,
@@ -56,6 +100,12 @@ pub struct DeployResponse {
 }
 impl CommandResponse for DeployResponse {}
 
+impl TransactionCommandResponse for DeployResponse {
+    fn sent_transaction_hash(&self) -> Option<FieldElement> {
+        Some(self.transaction_hash.0)
+    }
+}
+
 #[derive(Clone, Serialize, Deserialize, CairoSerialize, Debug, PartialEq)]
 pub struct DeclareResponse {
     pub class_hash: Felt,
@@ -63,31 +113,250 @@ pub struct DeclareResponse {
 }
 impl CommandResponse for DeclareResponse {}
 
+impl TransactionCommandResponse for DeclareResponse {
+    fn sent_transaction_hash(&self) -> Option<FieldElement> {
+        Some(self.transaction_hash.0)
+    }
+}
+
+/// Result of declaring all contracts of a package. Class hashes are in the order of `contracts`,
+/// which include the already declared ones, failed ones are `<name>: <error>`
+#[derive(Serialize)]
+pub struct DeclareAllResponse {
+    pub contracts: Vec<String>,
+    pub class_hashes: Vec<Felt>,
+    pub transaction_hashes: Vec<Felt>,
+    pub already_declared_contracts: Vec<String>,
+    pub failed_contracts: Vec<String>,
+}
+
+impl CommandResponse for DeclareAllResponse {}
+
 #[derive(Serialize)]
 pub struct AccountCreateResponse {
     pub address: Felt,
-    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
-    pub max_fee: Felt,
+    #[serde(
+        serialize_with = "crate::response::structs::serialize_optional_as_decimal",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub max_fee: Option<Felt>,
     pub add_profile: String,
     pub message: String,
+    #[serde(skip)]
+    pub account: AccountDetailsResponse,
 }
 
 impl CommandResponse for AccountCreateResponse {}
 
+impl AccountCommandResponse for AccountCreateResponse {
+    fn account_details(&self) -> Option<&AccountDetailsResponse> {
+        Some(&self.account)
+    }
+}
+
+#[derive(Serialize)]
+pub struct FeeEstimateResponse {
+    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
+    pub overall_fee: Felt,
+    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
+    pub gas_consumed: Felt,
+    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
+    pub gas_price: Felt,
+}
+
+impl CommandResponse for FeeEstimateResponse {}
+
+#[derive(Serialize)]
+pub struct InvokeSimulateResponse {
+    #[serde(flatten)]
+    pub fee_estimate: FeeEstimateResponse,
+    pub revert_reason: Option<String>,
+    pub call_trace: Vec<String>,
+}
+
+impl CommandResponse for InvokeSimulateResponse {}
+
+/// Signed transaction which a command would send, printed instead of sending it with `--dry-run`
+#[derive(Serialize)]
+pub struct DryRunResponse {
+    #[serde(rename = "type")]
+    pub transaction_type: String,
+    pub transaction_hash: Felt,
+    /// Class hash of a declared class, which is not a field of the declare transaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class_hash: Option<Felt>,
+    /// Fields of the transaction in the JSON-RPC format, e.g. `nonce`, `max_fee` and `signature`
+    #[serde(flatten)]
+    pub transaction: serde_json::Map<String, serde_json::Value>,
+}
+
+impl DryRunResponse {
+    /// Fields of the transaction other than felts and their arrays, like the contract class
+    /// of a declare transaction, are left out
+    pub fn new(
+        transaction_type: &str,
+        transaction_hash: FieldElement,
+        transaction: &impl Serialize,
+    ) -> anyhow::Result<Self> {
+        let serde_json::Value::Object(mut fields) = serde_json::to_value(transaction)? else {
+            anyhow::bail!("Transaction is not serialized as an object");
+        };
+        fields.retain(|key, value| key != "type" && (value.is_string() || value.is_array()));
+
+        Ok(DryRunResponse {
+            transaction_type: transaction_type.to_string(),
+            transaction_hash: Felt(transaction_hash),
+            class_hash: None,
+            transaction: fields,
+        })
+    }
+}
+
+impl CommandResponse for DryRunResponse {}
+
+/// Response of a command sending a transaction, or the transaction it would send with `--dry-run`
+#[derive(Serialize)]
+#[serde(untagged)]
+pub enum TransactionResponse<T> {
+    Sent(T),
+    DryRun(DryRunResponse),
+}
+
+impl<T> TransactionResponse<T> {
+    /// Response of the sent transaction, for callers which never build transactions with `--dry-run`
+    #[must_use]
+    pub fn expect_sent(self) -> T {
+        match self {
+            TransactionResponse::Sent(response) => response,
+            TransactionResponse::DryRun(_) => panic!("Transaction was not sent with --dry-run"),
+        }
+    }
+}
+
+impl<T: CommandResponse> CommandResponse for TransactionResponse<T> {}
+
+impl<T: TransactionCommandResponse> TransactionCommandResponse for TransactionResponse<T> {
+    fn sent_transaction_hash(&self) -> Option<FieldElement> {
+        match self {
+            TransactionResponse::Sent(response) => response.sent_transaction_hash(),
+            TransactionResponse::DryRun(_) => None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+pub enum AccountDeployResponse {
+    Deployed(AccountDeployedResponse),
+    FeeEstimate(FeeEstimateResponse),
+    DryRun(DryRunResponse),
+}
+
+impl CommandResponse for AccountDeployResponse {}
+
+impl AccountCommandResponse for AccountDeployResponse {
+    fn account_details(&self) -> Option<&AccountDetailsResponse> {
+        match self {
+            AccountDeployResponse::Deployed(deployed) => deployed.account.as_ref(),
+            AccountDeployResponse::FeeEstimate(_) | AccountDeployResponse::DryRun(_) => None,
+        }
+    }
+}
+
+/// Result of deploying all undeployed accounts of the network, failed ones are `<name>: <error>`
+#[derive(Serialize)]
+pub struct AccountsDeployResponse {
+    pub deployed_accounts: Vec<String>,
+    pub transaction_hashes: Vec<Felt>,
+    pub failed_accounts: Vec<String>,
+}
+
+impl CommandResponse for AccountsDeployResponse {}
+
+impl AccountCommandResponse for AccountsDeployResponse {
+    fn account_details(&self) -> Option<&AccountDetailsResponse> {
+        None
+    }
+}
+
+#[derive(Serialize, Clone)]
+pub struct AccountDeployedResponse {
+    pub transaction_hash: Felt,
+    #[serde(skip)]
+    pub account: Option<AccountDetailsResponse>,
+}
+
 #[derive(Serialize)]
 pub struct AccountAddResponse {
     pub add_profile: String,
+    #[serde(skip)]
+    pub account: AccountDetailsResponse,
 }
 
 impl CommandResponse for AccountAddResponse {}
 
+impl AccountCommandResponse for AccountAddResponse {
+    fn account_details(&self) -> Option<&AccountDetailsResponse> {
+        Some(&self.account)
+    }
+}
+
 #[derive(Serialize)]
 pub struct AccountDeleteResponse {
     pub result: String,
+    #[serde(skip)]
+    pub account: AccountDetailsResponse,
 }
 
 impl CommandResponse for AccountDeleteResponse {}
 
+impl AccountCommandResponse for AccountDeleteResponse {
+    fn account_details(&self) -> Option<&AccountDetailsResponse> {
+        Some(&self.account)
+    }
+}
+
+/// Stable description of an account, printed instead of the whole response
+/// by `account` subcommands when `--json` is passed
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct AccountDetailsResponse {
+    pub name: String,
+    pub address: Felt,
+    pub class_hash: Option<Felt>,
+    pub salt: Option<Felt>,
+    pub deployed: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_hash: Option<Felt>,
+}
+
+#[derive(Serialize)]
+pub struct AccountRenameResponse {
+    pub result: String,
+    pub updated_profiles: Option<Vec<String>>,
+}
+
+impl CommandResponse for AccountRenameResponse {}
+
+#[derive(Serialize)]
+pub struct HashResponse {
+    pub hash: Felt,
+}
+
+impl CommandResponse for HashResponse {}
+
+#[derive(Serialize)]
+pub struct StorageAddressResponse {
+    pub storage_address: Felt,
+}
+
+impl CommandResponse for StorageAddressResponse {}
+
+#[derive(Serialize)]
+pub struct ComputeAddressResponse {
+    pub contract_address: Felt,
+}
+impl CommandResponse for ComputeAddressResponse {}
+
 #[derive(Serialize)]
 pub struct MulticallNewResponse {
     pub path: Utf8PathBuf,
@@ -102,6 +371,7 @@ pub struct ShowConfigResponse {
     pub rpc_url: Option<String>,
     pub account: Option<String>,
     pub accounts_file_path: Option<Utf8PathBuf>,
+    pub additional_accounts_files: Option<Vec<Utf8PathBuf>>,
     pub keystore: Option<Utf8PathBuf>,
     pub wait_timeout: Option<Decimal>,
     pub wait_retry_interval: Option<Decimal>,
@@ -144,3 +414,58 @@ pub struct TransactionStatusResponse {
 }
 
 impl CommandResponse for TransactionStatusResponse {}
+
+#[derive(Serialize)]
+pub struct TransactionEventsResponse {
+    pub events: Vec<String>,
+}
+
+impl CommandResponse for TransactionEventsResponse {}
+
+/// Receipt of a transaction awaited with `--wait`, fee is in the smallest unit of `fee_unit`
+#[derive(Serialize)]
+pub struct TransactionReceiptResponse {
+    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
+    pub actual_fee: Felt,
+    pub fee_unit: String,
+    pub finality_status: FinalityStatus,
+    pub execution_status: ExecutionStatus,
+    pub revert_reason: Option<String>,
+    pub execution_resources: Vec<String>,
+    pub events: Vec<String>,
+}
+
+impl CommandResponse for TransactionReceiptResponse {}
+
+/// Response of a command sending a transaction, with the receipt of the transaction if it was awaited
+#[derive(Serialize)]
+pub struct WithReceiptResponse<T> {
+    #[serde(flatten)]
+    pub response: T,
+    #[serde(flatten)]
+    pub receipt: Option<TransactionReceiptResponse>,
+}
+
+impl<T: CommandResponse> CommandResponse for WithReceiptResponse<T> {}
+
+#[derive(Serialize)]
+pub struct EventsResponse {
+    pub events: Vec<String>,
+}
+
+impl CommandResponse for EventsResponse {}
+
+#[derive(Serialize)]
+pub struct BalanceResponse {
+    pub eth_balance: Option<String>,
+    pub strk_balance: Option<String>,
+}
+
+impl CommandResponse for BalanceResponse {}
+
+#[derive(Serialize)]
+pub struct AbiResponse {
+    pub path: Utf8PathBuf,
+}
+
+impl CommandResponse for AbiResponse {}
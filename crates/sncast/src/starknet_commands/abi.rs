@@ -0,0 +1,64 @@
+use anyhow::{Context, Result};
+use camino::Utf8PathBuf;
+use clap::Args;
+use sncast::response::errors::StarknetCommandError;
+use sncast::response::structs::AbiResponse;
+use starknet::core::types::{BlockId, BlockTag, ContractClass, FieldElement};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
+
+#[derive(Args)]
+#[command(about = "Save the ABI of a declared class to a file")]
+pub struct Abi {
+    /// Class hash of the class (hex)
+    #[clap(
+        short = 'g',
+        long,
+        conflicts_with = "contract_address",
+        required_unless_present = "contract_address"
+    )]
+    pub class_hash: Option<FieldElement>,
+
+    /// Address of a contract of the class (hex)
+    #[clap(short = 'a', long)]
+    pub contract_address: Option<FieldElement>,
+
+    /// Path to the file where the ABI is saved
+    #[clap(short, long)]
+    pub output: Utf8PathBuf,
+}
+
+pub async fn abi(
+    provider: &JsonRpcClient<HttpTransport>,
+    class_hash: Option<FieldElement>,
+    contract_address: Option<FieldElement>,
+    output: Utf8PathBuf,
+) -> Result<AbiResponse, StarknetCommandError> {
+    let block_id = BlockId::Tag(BlockTag::Pending);
+    let class = match (class_hash, contract_address) {
+        (Some(class_hash), _) => provider.get_class(block_id, class_hash).await,
+        (None, Some(contract_address)) => provider.get_class_at(block_id, contract_address).await,
+        (None, None) => unreachable!("Class hash or contract address is required by clap"),
+    }
+    .map_err(|error| StarknetCommandError::ProviderError(error.into()))?;
+
+    let abi = abi_json(&class)?;
+    std::fs::write(&output, abi)
+        .with_context(|| format!("Failed to write ABI to file = {output}"))?;
+
+    Ok(AbiResponse { path: output })
+}
+
+fn abi_json(class: &ContractClass) -> Result<String> {
+    match class {
+        // ABI of a Sierra class is stored as a JSON string, it is reformatted to be readable
+        ContractClass::Sierra(class) => {
+            let abi: serde_json::Value =
+                serde_json::from_str(&class.abi).context("Failed to parse ABI of the class")?;
+            Ok(serde_json::to_string_pretty(&abi)?)
+        }
+        ContractClass::Legacy(class) => Ok(serde_json::to_string_pretty(
+            &class.abi.clone().unwrap_or_default(),
+        )?),
+    }
+}
@@ -6,7 +6,7 @@ use anyhow::{ensure, Context, Result};
 use camino::Utf8PathBuf;
 use clap::Args;
 use sncast::helpers::configuration::CastConfig;
-use sncast::response::structs::AccountAddResponse;
+use sncast::response::structs::{AccountAddResponse, AccountDetailsResponse, Felt};
 use sncast::{check_class_hash_exists, get_chain_id};
 use sncast::{check_if_legacy_contract, get_class_hash_by_address};
 use starknet::core::types::FieldElement;
@@ -129,6 +129,14 @@ pub async fn add(
         } else {
             "--add-profile flag was not set. No profile added to snfoundry.toml".to_string()
         },
+        account: AccountDetailsResponse {
+            name: account.to_string(),
+            address: Felt(add.address),
+            class_hash: class_hash.map(Felt),
+            salt: add.salt.map(Felt),
+            deployed,
+            transaction_hash: None,
+        },
     })
 }
 
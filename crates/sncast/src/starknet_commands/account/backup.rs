@@ -0,0 +1,121 @@
+use anyhow::{Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
+use clap::Args;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Args, Debug)]
+pub struct BackupArgs {
+    /// Directory where backups of the accounts file are saved before it is modified;
+    /// defaults to `backups` directory next to the accounts file
+    #[clap(long)]
+    pub backup_dir: Option<Utf8PathBuf>,
+
+    /// Number of the most recent backups of the accounts file which are kept
+    #[clap(long, default_value_t = 10)]
+    pub max_backups: usize,
+}
+
+/// Copies the accounts file to the backup directory as `<file stem>.<unix timestamp in ms>.json`
+/// and removes the oldest backups of this file exceeding `max_backups`.
+/// Returns path of the created backup.
+pub fn backup_accounts_file(accounts_file: &Utf8Path, args: &BackupArgs) -> Result<Utf8PathBuf> {
+    let backup_dir = match &args.backup_dir {
+        Some(backup_dir) => backup_dir.clone(),
+        None => accounts_file
+            .parent()
+            .unwrap_or(Utf8Path::new(""))
+            .join("backups"),
+    };
+    std::fs::create_dir_all(&backup_dir)
+        .with_context(|| format!("Failed to create backup directory = {backup_dir}"))?;
+
+    let stem = accounts_file.file_stem().unwrap_or("accounts");
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("System time is smaller than Unix epoch")
+        .as_millis();
+    let backup_path = backup_dir.join(format!("{stem}.{timestamp}.json"));
+
+    std::fs::copy(accounts_file, &backup_path)
+        .with_context(|| format!("Failed to back up accounts file to {backup_path}"))?;
+
+    prune_backups(&backup_dir, stem, args.max_backups)?;
+
+    Ok(backup_path)
+}
+
+fn prune_backups(backup_dir: &Utf8Path, stem: &str, max_backups: usize) -> Result<()> {
+    let mut backups: Vec<(u128, Utf8PathBuf)> = backup_dir
+        .read_dir_utf8()
+        .with_context(|| format!("Failed to read backup directory = {backup_dir}"))?
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let timestamp = entry
+                .file_name()
+                .strip_prefix(&format!("{stem}."))?
+                .strip_suffix(".json")?
+                .parse()
+                .ok()?;
+            Some((timestamp, entry.into_path()))
+        })
+        .collect();
+
+    if backups.len() <= max_backups {
+        return Ok(());
+    }
+
+    backups.sort_unstable_by_key(|(timestamp, _)| *timestamp);
+    let outdated = backups.len() - max_backups;
+    for (_, path) in backups.into_iter().take(outdated) {
+        std::fs::remove_file(&path)
+            .with_context(|| format!("Failed to remove outdated backup = {path}"))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn keeps_most_recent_backups() {
+        let temp_dir = tempdir().unwrap();
+        let dir = Utf8PathBuf::try_from(temp_dir.path().to_path_buf()).unwrap();
+        for timestamp in [3, 1, 2] {
+            std::fs::write(dir.join(format!("accounts.{timestamp}.json")), "{}").unwrap();
+        }
+        std::fs::write(dir.join("other.1.json"), "{}").unwrap();
+
+        prune_backups(&dir, "accounts", 2).unwrap();
+
+        assert!(!dir.join("accounts.1.json").exists());
+        assert!(dir.join("accounts.2.json").exists());
+        assert!(dir.join("accounts.3.json").exists());
+        assert!(dir.join("other.1.json").exists());
+    }
+
+    #[test]
+    fn backup_to_default_dir() {
+        let temp_dir = tempdir().unwrap();
+        let dir = Utf8PathBuf::try_from(temp_dir.path().to_path_buf()).unwrap();
+        let accounts_file = dir.join("accounts.json");
+        std::fs::write(&accounts_file, "{\"network\": {}}").unwrap();
+
+        let backup_path = backup_accounts_file(
+            &accounts_file,
+            &BackupArgs {
+                backup_dir: None,
+                max_backups: 10,
+            },
+        )
+        .unwrap();
+
+        assert!(backup_path.starts_with(dir.join("backups")));
+        assert_eq!(
+            std::fs::read_to_string(backup_path).unwrap(),
+            "{\"network\": {}}"
+        );
+    }
+}
@@ -12,7 +12,8 @@ use sncast::helpers::constants::{
     ARGENT_CLASS_HASH, BRAAVOS_BASE_ACCOUNT_CLASS_HASH, BRAAVOS_CLASS_HASH,
     CREATE_KEYSTORE_PASSWORD_ENV_VAR, OZ_CLASS_HASH,
 };
-use sncast::response::structs::{AccountCreateResponse, Felt};
+use sncast::helpers::mnemonic::derive_private_key;
+use sncast::response::structs::{AccountCreateResponse, AccountDetailsResponse, Felt};
 use sncast::{
     check_class_hash_exists, check_if_legacy_contract, extract_or_generate_salt, get_chain_id,
     get_keystore_password, handle_account_factory_error,
@@ -47,6 +48,36 @@ pub struct Create {
     /// Custom contract class hash of declared contract
     #[clap(short, long, requires = "account_type")]
     pub class_hash: Option<FieldElement>,
+
+    /// If passed, the deployment fee will not be estimated
+    #[clap(long)]
+    pub skip_fee_estimate: bool,
+
+    /// BIP-39 mnemonic from which the private key is derived, instead of generating a random one
+    #[clap(long, conflicts_with = "mnemonic_file")]
+    pub mnemonic: Option<String>,
+
+    /// Path to a file with a BIP-39 mnemonic from which the private key is derived
+    #[clap(long)]
+    pub mnemonic_file: Option<Utf8PathBuf>,
+
+    /// Index of the key derived from the mnemonic
+    #[clap(long, default_value_t = 0)]
+    pub derivation_index: u32,
+}
+
+impl Create {
+    /// Private key derived from the mnemonic, if one was passed
+    pub fn private_key_from_mnemonic(&self) -> Result<Option<FieldElement>> {
+        let mnemonic = match (&self.mnemonic, &self.mnemonic_file) {
+            (Some(mnemonic), _) => mnemonic.clone(),
+            (None, Some(mnemonic_file)) => std::fs::read_to_string(mnemonic_file)
+                .with_context(|| format!("Failed to read mnemonic file = {mnemonic_file}"))?,
+            (None, None) => return Ok(None),
+        };
+
+        derive_private_key(&mnemonic, self.derivation_index).map(Some)
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -61,8 +92,16 @@ pub async fn create(
     salt: Option<FieldElement>,
     add_profile: Option<String>,
     class_hash: Option<FieldElement>,
+    skip_fee_estimate: bool,
+    private_key: Option<FieldElement>,
 ) -> Result<AccountCreateResponse> {
-    let salt = extract_or_generate_salt(salt);
+    let signing_key =
+        private_key.map_or_else(SigningKey::from_random, SigningKey::from_secret_scalar);
+    // Account of a key derived from a mnemonic gets the same address every time it is created
+    let salt = match salt {
+        None if private_key.is_some() => signing_key.verifying_key().scalar(),
+        _ => extract_or_generate_salt(salt),
+    };
     let class_hash = class_hash.unwrap_or(match account_type {
         AccountType::Oz => OZ_CLASS_HASH,
         AccountType::Argent => ARGENT_CLASS_HASH,
@@ -70,8 +109,15 @@ pub async fn create(
     });
     check_class_hash_exists(provider, class_hash).await?;
 
-    let (account_json, max_fee) =
-        generate_account(provider, salt, class_hash, &account_type).await?;
+    let (account_json, max_fee) = generate_account(
+        provider,
+        signing_key,
+        salt,
+        class_hash,
+        &account_type,
+        skip_fee_estimate,
+    )
+    .await?;
 
     let address = account_json["address"]
         .as_str()
@@ -116,9 +162,11 @@ pub async fn create(
         add_created_profile_to_configuration(&add_profile, &config, &None)?;
     }
 
+    let deployed = account_json["deployed"] == json!(true);
+
     Ok(AccountCreateResponse {
         address: Felt(address),
-        max_fee: Felt(max_fee),
+        max_fee: max_fee.map(Felt),
         add_profile: if add_profile.is_some() {
             format!(
                 "Profile {} successfully added to snfoundry.toml",
@@ -127,29 +175,40 @@ pub async fn create(
         } else {
             "--add-profile flag was not set. No profile added to snfoundry.toml".to_string()
         },
-        message: if account_json["deployed"] == json!(false) {
-            "Account successfully created. Prefund generated address with at least <max_fee> tokens. It is good to send more in the case of higher demand.".to_string()
-        } else {
+        message: if deployed {
             "Account already deployed".to_string()
+        } else if max_fee.is_some() {
+            "Account successfully created. Prefund generated address with at least <max_fee> WEI of ETH. It is good to send more in the case of higher demand.".to_string()
+        } else {
+            "Account successfully created. Deployment fee estimation was skipped, run `sncast account deploy --estimate-only` to check the minimum balance needed for deployment.".to_string()
+        },
+        account: AccountDetailsResponse {
+            name: account.to_string(),
+            address: Felt(address),
+            class_hash: Some(Felt(class_hash)),
+            salt: Some(Felt(salt)),
+            deployed,
+            transaction_hash: None,
         },
     })
 }
 
 async fn generate_account(
     provider: &JsonRpcClient<HttpTransport>,
+    private_key: SigningKey,
     salt: FieldElement,
     class_hash: FieldElement,
     account_type: &AccountType,
-) -> Result<(serde_json::Value, FieldElement)> {
+    skip_fee_estimate: bool,
+) -> Result<(serde_json::Value, Option<FieldElement>)> {
     let chain_id = get_chain_id(provider).await?;
-    let private_key = SigningKey::from_random();
     let signer = LocalWallet::from_signing_key(private_key.clone());
 
     let (address, fee_estimate) = match account_type {
         AccountType::Oz => {
             let factory =
                 OpenZeppelinAccountFactory::new(class_hash, chain_id, signer, provider).await?;
-            get_address_and_deployment_fee(factory, salt).await?
+            get_address_and_deployment_fee(factory, salt, skip_fee_estimate).await?
         }
         AccountType::Argent => {
             let factory = ArgentAccountFactory::new(
@@ -160,7 +219,7 @@ async fn generate_account(
                 provider,
             )
             .await?;
-            get_address_and_deployment_fee(factory, salt).await?
+            get_address_and_deployment_fee(factory, salt, skip_fee_estimate).await?
         }
         AccountType::Braavos => {
             let factory = BraavosAccountFactory::new(
@@ -171,7 +230,7 @@ async fn generate_account(
                 provider,
             )
             .await?;
-            get_address_and_deployment_fee(factory, salt).await?
+            get_address_and_deployment_fee(factory, salt, skip_fee_estimate).await?
         }
     };
 
@@ -187,18 +246,28 @@ async fn generate_account(
         Some(salt),
     );
 
-    Ok((account_json, fee_estimate.overall_fee))
+    Ok((
+        account_json,
+        fee_estimate.map(|fee_estimate| fee_estimate.overall_fee),
+    ))
 }
 
 async fn get_address_and_deployment_fee<T>(
     account_factory: T,
     salt: FieldElement,
-) -> Result<(FieldElement, FeeEstimate)>
+    skip_fee_estimate: bool,
+) -> Result<(FieldElement, Option<FeeEstimate>)>
 where
     T: AccountFactory + Sync,
 {
     let deployment = account_factory.deploy(salt);
-    Ok((deployment.address(), get_deployment_fee(&deployment).await?))
+    let fee_estimate = if skip_fee_estimate {
+        None
+    } else {
+        Some(get_deployment_fee(&deployment).await?)
+    };
+
+    Ok((deployment.address(), fee_estimate))
 }
 
 async fn get_deployment_fee<'a, T>(
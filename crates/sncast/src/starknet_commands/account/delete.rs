@@ -3,7 +3,10 @@ use camino::Utf8PathBuf;
 use clap::Args;
 use promptly::prompt;
 use serde_json::Map;
-use sncast::response::structs::AccountDeleteResponse;
+use sncast::response::structs::{AccountDeleteResponse, AccountDetailsResponse, Felt};
+use sncast::AccountData;
+
+use super::backup::{backup_accounts_file, BackupArgs};
 
 #[derive(Args, Debug)]
 #[command(about = "Delete account information from the accounts file")]
@@ -19,6 +22,9 @@ pub struct Delete {
     /// Assume "yes" as answer to confirmation prompt and run non-interactively
     #[clap(long, default_value = "false")]
     pub yes: bool,
+
+    #[clap(flatten)]
+    pub backup: BackupArgs,
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -27,6 +33,8 @@ pub fn delete(
     path: &Utf8PathBuf,
     network_name: &str,
     yes: bool,
+    dry_run: bool,
+    backup: &BackupArgs,
 ) -> Result<AccountDeleteResponse> {
     let contents = std::fs::read_to_string(path.clone()).context("Failed to read accounts file")?;
     let items: serde_json::Value = serde_json::from_str(&contents)
@@ -39,6 +47,26 @@ pub fn delete(
         bail!("Account with name {name} does not exist")
     }
 
+    let account_data: AccountData = serde_json::from_value(items[&network_name][&name].clone())
+        .with_context(|| format!("Failed to parse account {name} in accounts file at {path}"))?;
+    let account = AccountDetailsResponse {
+        name: name.to_string(),
+        address: Felt(
+            account_data
+                .address
+                .context("Failed to get address from accounts file")?,
+        ),
+        class_hash: account_data.class_hash.map(Felt),
+        salt: account_data.salt.map(Felt),
+        deployed: account_data.deployed.unwrap_or(false),
+        transaction_hash: None,
+    };
+
+    if dry_run {
+        let result = "Account would be removed (dry run)".to_string();
+        return Ok(AccountDeleteResponse { result, account });
+    }
+
     let mut items: Map<String, serde_json::Value> = serde_json::from_str(&contents)
         .unwrap_or_else(|_| panic!("Failed to read file at path = {path}"));
 
@@ -63,7 +91,8 @@ pub fn delete(
     // now remove the child from there
     nested.remove(name);
 
+    backup_accounts_file(path, backup)?;
     std::fs::write(path.clone(), serde_json::to_string_pretty(&items).unwrap())?;
     let result = "Account successfully removed".to_string();
-    Ok(AccountDeleteResponse { result })
+    Ok(AccountDeleteResponse { result, account })
 }
@@ -1,25 +1,34 @@
-use anyhow::{anyhow, bail, Context, Result};
+use anyhow::{anyhow, bail, ensure, Context, Result};
 use camino::Utf8PathBuf;
 use clap::Args;
 use serde_json::Map;
 use sncast::helpers::constants::{BRAAVOS_BASE_ACCOUNT_CLASS_HASH, KEYSTORE_PASSWORD_ENV_VAR};
-use sncast::response::structs::{Felt, InvokeResponse};
+use sncast::response::structs::{
+    AccountDeployResponse, AccountDeployedResponse, AccountDetailsResponse, AccountsDeployResponse,
+    DryRunResponse, FeeEstimateResponse, Felt,
+};
 use starknet::accounts::{AccountFactory, OpenZeppelinAccountFactory};
 use starknet::accounts::{AccountFactoryError, ArgentAccountFactory};
 use starknet::core::types::BlockTag::Pending;
 use starknet::core::types::{BlockId, FieldElement, StarknetError::ClassHashNotFound};
-use starknet::core::utils::get_contract_address;
 use starknet::providers::jsonrpc::HttpTransport;
 use starknet::providers::ProviderError::StarknetError;
 use starknet::providers::{JsonRpcClient, Provider};
 use starknet::signers::{LocalWallet, SigningKey};
 
+use sncast::helpers::account_factory::CustomCalldataAccountFactory;
+use sncast::helpers::address::{
+    account_address, account_address_with_calldata, validate_account_constructor_calldata,
+};
 use sncast::helpers::braavos::BraavosAccountFactory;
+use sncast::helpers::fee::{resolve_max_fee, MaxFee};
+use sncast::response::errors::{handle_starknet_command_error, StarknetCommandError};
 use sncast::{
     chain_id_to_network_name, check_account_file_exists, get_account_data_from_accounts_file,
     get_account_data_from_keystore, get_keystore_password, handle_account_factory_error,
-    handle_rpc_error, handle_wait_for_tx, AccountType, WaitForTx,
+    handle_rpc_error, handle_wait_for_tx, AccountData, AccountType, WaitForTx,
 };
+use std::collections::{BTreeMap, HashMap};
 
 #[derive(Args, Debug)]
 #[command(about = "Deploy an account to the Starknet")]
@@ -28,9 +37,32 @@ pub struct Deploy {
     #[clap(short, long)]
     pub name: Option<String>,
 
-    /// Max fee for the transaction
+    /// Max fee for the transaction, in WEI or as a percentage of the ETH balance of the account, e.g. `80%`
     #[clap(short, long)]
-    pub max_fee: Option<FieldElement>,
+    pub max_fee: Option<MaxFee>,
+
+    /// If passed, only the deployment fee estimate will be printed and no transaction will be sent
+    #[clap(long, conflicts_with = "max_fee")]
+    pub estimate_only: bool,
+
+    /// Calldata for the account constructor, instead of the one of the account type.
+    /// The address of the account depends on the calldata
+    #[clap(short, long, value_delimiter = ' ', num_args = 1..)]
+    pub constructor_calldata: Option<Vec<FieldElement>>,
+
+    /// Deploy all accounts of the network from the accounts file which are not deployed yet
+    #[clap(long, conflicts_with_all = ["name", "estimate_only", "constructor_calldata"])]
+    pub all: bool,
+}
+
+/// What is done with the deployment transaction of the account
+#[derive(Clone, Copy, PartialEq)]
+enum DeploymentMode {
+    Send,
+    /// Only the fee of the deployment is estimated
+    EstimateOnly,
+    /// The signed transaction is printed without sending it
+    DryRun,
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -42,12 +74,23 @@ pub async fn deploy(
     wait_config: WaitForTx,
     account: &str,
     keystore_path: Option<Utf8PathBuf>,
-) -> Result<InvokeResponse> {
+    dry_run: bool,
+) -> Result<AccountDeployResponse> {
+    let mode = if deploy_args.estimate_only {
+        DeploymentMode::EstimateOnly
+    } else if dry_run {
+        DeploymentMode::DryRun
+    } else {
+        DeploymentMode::Send
+    };
+
     if let Some(keystore_path_) = keystore_path {
         deploy_from_keystore(
             provider,
             chain_id,
             deploy_args.max_fee,
+            mode,
+            deploy_args.constructor_calldata,
             wait_config,
             account,
             keystore_path_,
@@ -64,20 +107,103 @@ pub async fn deploy(
             account_name,
             chain_id,
             deploy_args.max_fee,
+            mode,
+            deploy_args.constructor_calldata,
             wait_config,
         )
         .await
     }
 }
 
+/// Deploys the accounts one by one, in the alphabetical order of their names.
+/// Each deployed account is marked as deployed in the accounts file right away,
+/// so the accounts deployed before a failure stay updated.
+pub async fn deploy_all(
+    provider: &JsonRpcClient<HttpTransport>,
+    accounts_file: Utf8PathBuf,
+    max_fee: Option<MaxFee>,
+    chain_id: FieldElement,
+    wait_config: WaitForTx,
+    keystore_path: Option<Utf8PathBuf>,
+    dry_run: bool,
+) -> Result<AccountsDeployResponse> {
+    ensure!(
+        keystore_path.is_none(),
+        "`--all` can't be used with a keystore, accounts are deployed from the accounts file"
+    );
+    ensure!(!dry_run, "`--all` can't be used with `--dry-run`");
+    check_account_file_exists(&accounts_file)?;
+
+    let network_name = chain_id_to_network_name(chain_id);
+    let names = undeployed_account_names(&accounts_file, &network_name)?;
+    ensure!(
+        !names.is_empty(),
+        "No undeployed accounts found under network = {network_name}"
+    );
+
+    let mut response = AccountsDeployResponse {
+        deployed_accounts: vec![],
+        transaction_hashes: vec![],
+        failed_accounts: vec![],
+    };
+    for name in names {
+        let result = deploy_from_accounts_file(
+            provider,
+            accounts_file.clone(),
+            name.clone(),
+            chain_id,
+            max_fee,
+            DeploymentMode::Send,
+            None,
+            wait_config,
+        )
+        .await;
+
+        match result {
+            Ok(AccountDeployResponse::Deployed(deployed)) => {
+                response.deployed_accounts.push(name);
+                response.transaction_hashes.push(deployed.transaction_hash);
+            }
+            Ok(AccountDeployResponse::FeeEstimate(_) | AccountDeployResponse::DryRun(_)) => {
+                unreachable!("Accounts are deployed with transactions being sent")
+            }
+            Err(error) => response.failed_accounts.push(format!("{name}: {error:#}")),
+        }
+    }
+
+    Ok(response)
+}
+
+fn undeployed_account_names(
+    accounts_file: &Utf8PathBuf,
+    network_name: &str,
+) -> Result<Vec<String>> {
+    let contents =
+        std::fs::read_to_string(accounts_file).context("Failed to read accounts file")?;
+    let mut networks: HashMap<String, BTreeMap<String, AccountData>> =
+        serde_json::from_str(&contents)
+            .with_context(|| format!("Failed to parse accounts file at = {accounts_file}"))?;
+
+    Ok(networks
+        .remove(network_name)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|(_, account)| account.deployed == Some(false))
+        .map(|(name, _)| name)
+        .collect())
+}
+
+#[allow(clippy::too_many_arguments)]
 async fn deploy_from_keystore(
     provider: &JsonRpcClient<HttpTransport>,
     chain_id: FieldElement,
-    max_fee: Option<FieldElement>,
+    max_fee: Option<MaxFee>,
+    mode: DeploymentMode,
+    constructor_calldata: Option<Vec<FieldElement>>,
     wait_config: WaitForTx,
     account: &str,
     keystore_path: Utf8PathBuf,
-) -> Result<InvokeResponse> {
+) -> Result<AccountDeployResponse> {
     let account_data = get_account_data_from_keystore(account, &keystore_path)?;
 
     let is_deployed = account_data
@@ -108,35 +234,25 @@ async fn deploy_from_keystore(
         .class_hash
         .context("Failed to get class hash from keystore")?;
 
-    let address = match account_type {
-        AccountType::Argent => get_contract_address(
-            salt,
-            class_hash,
-            &[private_key.verifying_key().scalar(), FieldElement::ZERO],
-            FieldElement::ZERO,
-        ),
-        AccountType::Oz => get_contract_address(
-            salt,
-            class_hash,
-            &[private_key.verifying_key().scalar()],
-            chain_id,
-        ),
-        AccountType::Braavos => get_contract_address(
-            salt,
-            BRAAVOS_BASE_ACCOUNT_CLASS_HASH,
-            &[private_key.verifying_key().scalar()],
-            chain_id,
-        ),
+    let address = match &constructor_calldata {
+        Some(calldata) => address_with_calldata(&account_type, class_hash, salt, calldata)?,
+        None => account_address(&account_type, class_hash, salt, public_key),
     };
 
-    let result = if provider
+    let is_deployed_on_chain = provider
         .get_class_hash_at(BlockId::Tag(Pending), address)
         .await
-        .is_ok()
-    {
-        InvokeResponse {
+        .is_ok();
+
+    if mode != DeploymentMode::Send && is_deployed_on_chain {
+        bail!("Account already deployed");
+    }
+
+    let result = if is_deployed_on_chain {
+        AccountDeployResponse::Deployed(AccountDeployedResponse {
             transaction_hash: Felt(FieldElement::ZERO),
-        }
+            account: None,
+        })
     } else {
         get_deployment_result(
             provider,
@@ -146,49 +262,117 @@ async fn deploy_from_keystore(
             salt,
             chain_id,
             max_fee,
+            mode,
+            constructor_calldata,
             wait_config,
         )
         .await?
     };
 
-    update_keystore_account(account, address)?;
+    if mode == DeploymentMode::Send {
+        update_keystore_account(account, address)?;
+    }
 
-    Ok(result)
+    Ok(with_account_details(
+        result, account, address, class_hash, salt,
+    ))
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn deploy_from_accounts_file(
     provider: &JsonRpcClient<HttpTransport>,
     accounts_file: Utf8PathBuf,
     name: String,
     chain_id: FieldElement,
-    max_fee: Option<FieldElement>,
+    max_fee: Option<MaxFee>,
+    mode: DeploymentMode,
+    constructor_calldata: Option<Vec<FieldElement>>,
     wait_config: WaitForTx,
-) -> Result<InvokeResponse> {
+) -> Result<AccountDeployResponse> {
     let account_data = get_account_data_from_accounts_file(&name, chain_id, &accounts_file)?;
 
     let private_key = SigningKey::from_secret_scalar(account_data.private_key);
+    let class_hash = account_data
+        .class_hash
+        .context("Failed to get class hash from accounts file")?;
+    let salt = account_data
+        .salt
+        .context("Failed to get salt from accounts file")?;
+    let account_type = account_data
+        .account_type
+        .context("Failed to get account type from accounts file")?;
+    let address = match &constructor_calldata {
+        Some(calldata) => address_with_calldata(&account_type, class_hash, salt, calldata)?,
+        None => account_data
+            .address
+            .context("Failed to get address from accounts file")?,
+    };
 
     let result = get_deployment_result(
         provider,
-        account_data
-            .account_type
-            .context("Failed to get account type from accounts file")?,
-        account_data
-            .class_hash
-            .context("Failed to get class hash from accounts file")?,
+        account_type,
+        class_hash,
         private_key,
-        account_data
-            .salt
-            .context("Failed to get salt from accounts file")?,
+        salt,
         chain_id,
         max_fee,
+        mode,
+        constructor_calldata,
         wait_config,
     )
     .await?;
 
-    update_account_in_accounts_file(accounts_file, &name, chain_id)?;
+    if mode == DeploymentMode::Send {
+        update_account_in_accounts_file(accounts_file, &name, chain_id, address)?;
+    }
+
+    Ok(with_account_details(
+        result, &name, address, class_hash, salt,
+    ))
+}
 
-    Ok(result)
+/// Address of an account deployed with custom constructor calldata, which differs from the one
+/// computed when the account was created
+fn address_with_calldata(
+    account_type: &AccountType,
+    class_hash: FieldElement,
+    salt: FieldElement,
+    constructor_calldata: &[FieldElement],
+) -> Result<FieldElement> {
+    validate_account_constructor_calldata(account_type, class_hash, constructor_calldata)?;
+    Ok(account_address_with_calldata(
+        class_hash,
+        salt,
+        constructor_calldata,
+    ))
+}
+
+fn with_account_details(
+    result: AccountDeployResponse,
+    name: &str,
+    address: FieldElement,
+    class_hash: FieldElement,
+    salt: FieldElement,
+) -> AccountDeployResponse {
+    match result {
+        AccountDeployResponse::Deployed(deployed) => {
+            let account = AccountDetailsResponse {
+                name: name.to_string(),
+                address: Felt(address),
+                class_hash: Some(Felt(class_hash)),
+                salt: Some(Felt(salt)),
+                deployed: true,
+                transaction_hash: Some(deployed.transaction_hash.clone()),
+            };
+            AccountDeployResponse::Deployed(AccountDeployedResponse {
+                account: Some(account),
+                ..deployed
+            })
+        }
+        result @ (AccountDeployResponse::FeeEstimate(_) | AccountDeployResponse::DryRun(_)) => {
+            result
+        }
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -199,9 +383,31 @@ async fn get_deployment_result(
     private_key: SigningKey,
     salt: FieldElement,
     chain_id: FieldElement,
-    max_fee: Option<FieldElement>,
+    max_fee: Option<MaxFee>,
+    mode: DeploymentMode,
+    constructor_calldata: Option<Vec<FieldElement>>,
     wait_config: WaitForTx,
-) -> Result<InvokeResponse> {
+) -> Result<AccountDeployResponse> {
+    if let Some(constructor_calldata) = constructor_calldata {
+        let factory = CustomCalldataAccountFactory::new(
+            class_hash,
+            constructor_calldata,
+            chain_id,
+            LocalWallet::from_signing_key(private_key),
+            provider,
+        );
+        return deploy_account(
+            factory,
+            provider,
+            salt,
+            max_fee,
+            mode,
+            wait_config,
+            class_hash,
+        )
+        .await;
+    }
+
     match account_type {
         AccountType::Argent => {
             deploy_argent_account(
@@ -211,6 +417,7 @@ async fn get_deployment_result(
                 salt,
                 chain_id,
                 max_fee,
+                mode,
                 wait_config,
             )
             .await
@@ -223,6 +430,7 @@ async fn get_deployment_result(
                 salt,
                 chain_id,
                 max_fee,
+                mode,
                 wait_config,
             )
             .await
@@ -235,6 +443,7 @@ async fn get_deployment_result(
                 salt,
                 chain_id,
                 max_fee,
+                mode,
                 wait_config,
             )
             .await
@@ -248,9 +457,10 @@ async fn deploy_oz_account(
     private_key: SigningKey,
     salt: FieldElement,
     chain_id: FieldElement,
-    max_fee: Option<FieldElement>,
+    max_fee: Option<MaxFee>,
+    mode: DeploymentMode,
     wait_config: WaitForTx,
-) -> Result<InvokeResponse> {
+) -> Result<AccountDeployResponse> {
     let factory = OpenZeppelinAccountFactory::new(
         class_hash,
         chain_id,
@@ -259,7 +469,16 @@ async fn deploy_oz_account(
     )
     .await?;
 
-    deploy_account(factory, provider, salt, max_fee, wait_config, class_hash).await
+    deploy_account(
+        factory,
+        provider,
+        salt,
+        max_fee,
+        mode,
+        wait_config,
+        class_hash,
+    )
+    .await
 }
 
 async fn deploy_argent_account(
@@ -268,9 +487,10 @@ async fn deploy_argent_account(
     private_key: SigningKey,
     salt: FieldElement,
     chain_id: FieldElement,
-    max_fee: Option<FieldElement>,
+    max_fee: Option<MaxFee>,
+    mode: DeploymentMode,
     wait_config: WaitForTx,
-) -> Result<InvokeResponse> {
+) -> Result<AccountDeployResponse> {
     let factory = ArgentAccountFactory::new(
         class_hash,
         chain_id,
@@ -280,7 +500,16 @@ async fn deploy_argent_account(
     )
     .await?;
 
-    deploy_account(factory, provider, salt, max_fee, wait_config, class_hash).await
+    deploy_account(
+        factory,
+        provider,
+        salt,
+        max_fee,
+        mode,
+        wait_config,
+        class_hash,
+    )
+    .await
 }
 
 async fn deploy_braavos_account(
@@ -289,9 +518,10 @@ async fn deploy_braavos_account(
     private_key: SigningKey,
     salt: FieldElement,
     chain_id: FieldElement,
-    max_fee: Option<FieldElement>,
+    max_fee: Option<MaxFee>,
+    mode: DeploymentMode,
     wait_config: WaitForTx,
-) -> Result<InvokeResponse> {
+) -> Result<AccountDeployResponse> {
     let factory = BraavosAccountFactory::new(
         class_hash,
         BRAAVOS_BASE_ACCOUNT_CLASS_HASH,
@@ -301,22 +531,53 @@ async fn deploy_braavos_account(
     )
     .await?;
 
-    deploy_account(factory, provider, salt, max_fee, wait_config, class_hash).await
+    deploy_account(
+        factory,
+        provider,
+        salt,
+        max_fee,
+        mode,
+        wait_config,
+        class_hash,
+    )
+    .await
 }
 
 async fn deploy_account<T>(
     account_factory: T,
     provider: &JsonRpcClient<HttpTransport>,
     salt: FieldElement,
-    max_fee: Option<FieldElement>,
+    max_fee: Option<MaxFee>,
+    mode: DeploymentMode,
     wait_config: WaitForTx,
     class_hash: FieldElement,
-) -> Result<InvokeResponse>
+) -> Result<AccountDeployResponse>
 where
     T: AccountFactory + Sync,
 {
     let deployment = account_factory.deploy(salt);
 
+    if mode == DeploymentMode::EstimateOnly {
+        return match deployment.estimate_fee().await {
+            Ok(fee_estimate) => Ok(AccountDeployResponse::FeeEstimate(FeeEstimateResponse {
+                overall_fee: Felt(fee_estimate.overall_fee),
+                gas_consumed: Felt(fee_estimate.gas_consumed),
+                gas_price: Felt(fee_estimate.gas_price),
+            })),
+            Err(error) => Err(handle_account_factory_error::<T>(error)),
+        };
+    }
+
+    let max_fee = resolve_max_fee(provider, deployment.address(), max_fee, async {
+        match deployment.estimate_fee().await {
+            Ok(fee_estimate) => Ok(fee_estimate.overall_fee),
+            Err(error) => Err(StarknetCommandError::from(
+                handle_account_factory_error::<T>(error),
+            )),
+        }
+    })
+    .await
+    .map_err(handle_starknet_command_error)?;
     let deploy_max_fee = if let Some(max_fee) = max_fee {
         max_fee
     } else {
@@ -325,7 +586,26 @@ where
             Err(error) => return Err(handle_account_factory_error::<T>(error)),
         }
     };
-    let result = deployment.max_fee(deploy_max_fee).send().await;
+    let deployment = deployment.max_fee(deploy_max_fee);
+
+    if mode == DeploymentMode::DryRun {
+        // Accounts which are not deployed yet have nonce 0
+        let prepared = deployment
+            .nonce(FieldElement::ZERO)
+            .prepared()
+            .expect("Nonce and max fee are set");
+        let transaction = prepared
+            .get_deploy_request()
+            .await
+            .map_err(|error| anyhow!("Failed to sign the transaction: {error}"))?;
+        return Ok(AccountDeployResponse::DryRun(DryRunResponse::new(
+            "DEPLOY_ACCOUNT",
+            prepared.transaction_hash(),
+            &transaction,
+        )?));
+    }
+
+    let result = deployment.send().await;
 
     match result {
         Err(AccountFactoryError::Provider(error)) => match error {
@@ -337,8 +617,9 @@ where
         },
         Err(_) => Err(anyhow!("Unknown AccountFactoryError")),
         Ok(result) => {
-            let return_value = InvokeResponse {
+            let return_value = AccountDeployedResponse {
                 transaction_hash: Felt(result.transaction_hash),
+                account: None,
             };
             if let Err(message) = handle_wait_for_tx(
                 provider,
@@ -351,7 +632,7 @@ where
                 return Err(anyhow!(message));
             }
 
-            Ok(return_value)
+            Ok(AccountDeployResponse::Deployed(return_value))
         }
     }
 }
@@ -360,6 +641,7 @@ fn update_account_in_accounts_file(
     accounts_file: Utf8PathBuf,
     account_name: &str,
     chain_id: FieldElement,
+    address: FieldElement,
 ) -> Result<()> {
     let network_name = chain_id_to_network_name(chain_id);
 
@@ -368,6 +650,7 @@ fn update_account_in_accounts_file(
     let mut items: serde_json::Value = serde_json::from_str(&contents)
         .with_context(|| format!("Failed to parse accounts file at = {accounts_file}"))?;
     items[&network_name][account_name]["deployed"] = serde_json::Value::from(true);
+    items[&network_name][account_name]["address"] = format!("{address:#x}").into();
     std::fs::write(accounts_file, serde_json::to_string_pretty(&items).unwrap())
         .context("Failed to write to accounts file")?;
 
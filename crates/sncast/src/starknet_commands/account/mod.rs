@@ -2,6 +2,7 @@ use crate::starknet_commands::account::add::Add;
 use crate::starknet_commands::account::create::Create;
 use crate::starknet_commands::account::delete::Delete;
 use crate::starknet_commands::account::deploy::Deploy;
+use crate::starknet_commands::account::rename::Rename;
 use anyhow::{anyhow, bail, Context, Result};
 use camino::Utf8PathBuf;
 use clap::{Args, Subcommand, ValueEnum};
@@ -15,9 +16,11 @@ use std::{fmt, fs::OpenOptions, io::Write};
 use toml::Value;
 
 pub mod add;
+pub mod backup;
 pub mod create;
 pub mod delete;
 pub mod deploy;
+pub mod rename;
 
 #[derive(Args)]
 #[command(about = "Creates and deploys an account to the Starknet")]
@@ -32,6 +35,7 @@ pub enum Commands {
     Create(Create),
     Deploy(Deploy),
     Delete(Delete),
+    Rename(Rename),
 }
 
 #[allow(clippy::doc_markdown)]
@@ -55,6 +59,16 @@ impl fmt::Display for AccountType {
     }
 }
 
+impl From<&AccountType> for sncast::AccountType {
+    fn from(account_type: &AccountType) -> Self {
+        match account_type {
+            AccountType::Oz => sncast::AccountType::Oz,
+            AccountType::Argent => sncast::AccountType::Argent,
+            AccountType::Braavos => sncast::AccountType::Braavos,
+        }
+    }
+}
+
 pub fn prepare_account_json(
     private_key: &SigningKey,
     address: FieldElement,
@@ -0,0 +1,165 @@
+use anyhow::{anyhow, bail, Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
+use clap::Args;
+use configuration::{find_config_file, load_global_config};
+use serde_json::Map;
+use sncast::helpers::configuration::CastConfig;
+use sncast::helpers::constants::DEFAULT_ACCOUNTS_FILE;
+use sncast::response::structs::AccountRenameResponse;
+use sncast::{build_provider, chain_id_to_network_name, get_chain_id};
+use std::collections::HashMap;
+use toml_edit::{value, DocumentMut};
+
+use super::backup::{backup_accounts_file, BackupArgs};
+
+#[derive(Args, Debug)]
+#[command(about = "Rename an account in the accounts file")]
+pub struct Rename {
+    /// Current name of the account
+    #[clap(long)]
+    pub from: String,
+
+    /// New name of the account
+    #[clap(long)]
+    pub to: String,
+
+    /// Network where the account exists; defaults to network of rpc node
+    #[clap(long)]
+    pub network: Option<String>,
+
+    #[clap(flatten)]
+    pub backup: BackupArgs,
+}
+
+pub async fn rename(
+    from: &str,
+    to: &str,
+    path: &Utf8PathBuf,
+    network_name: &str,
+    backup: &BackupArgs,
+) -> Result<AccountRenameResponse> {
+    let contents = std::fs::read_to_string(path.clone()).context("Failed to read accounts file")?;
+    let mut items: Map<String, serde_json::Value> = serde_json::from_str(&contents)
+        .map_err(|_| anyhow!("Failed to parse accounts file at {path}"))?;
+
+    let network_accounts = items
+        .get_mut(network_name)
+        .and_then(serde_json::Value::as_object_mut)
+        .ok_or_else(|| anyhow!("No accounts defined for network = {network_name}"))?;
+
+    if !network_accounts.contains_key(from) {
+        bail!("Account with name {from} does not exist");
+    }
+    if network_accounts.contains_key(to) {
+        bail!("Account with name {to} already exists in network = {network_name}");
+    }
+
+    let account = network_accounts
+        .remove(from)
+        .expect("Account existence has been checked");
+    network_accounts.insert(to.to_string(), account);
+
+    backup_accounts_file(path, backup)?;
+    std::fs::write(path.clone(), serde_json::to_string_pretty(&items).unwrap())
+        .context("Failed to write to accounts file")?;
+
+    let updated_profiles = match find_config_file() {
+        Ok(config_path) => {
+            rename_account_in_profiles(&config_path, from, to, path, network_name).await?
+        }
+        Err(_) => vec![],
+    };
+
+    Ok(AccountRenameResponse {
+        result: "Account successfully renamed".to_string(),
+        updated_profiles: if updated_profiles.is_empty() {
+            None
+        } else {
+            Some(updated_profiles)
+        },
+    })
+}
+
+/// Replaces the account name in `snfoundry.toml` profiles using the renamed account,
+/// i.e. profiles with the same account name whose accounts file is `accounts_file`
+/// and whose RPC node is in the network `network_name`. Returns names of the updated profiles.
+async fn rename_account_in_profiles(
+    config_path: &Utf8PathBuf,
+    from: &str,
+    to: &str,
+    accounts_file: &Utf8Path,
+    network_name: &str,
+) -> Result<Vec<String>> {
+    let contents = std::fs::read_to_string(config_path).context("Failed to read snfoundry.toml")?;
+    let mut document = contents
+        .parse::<DocumentMut>()
+        .with_context(|| format!("Failed to parse {config_path}"))?;
+
+    let candidate_profiles: Vec<String> = document
+        .get("sncast")
+        .and_then(toml_edit::Item::as_table_like)
+        .map(|profiles| {
+            profiles
+                .iter()
+                // With keystore, the account is a path to the account file, not a name
+                .filter(|(_, profile)| profile.get("keystore").is_none())
+                .filter(|(_, profile)| {
+                    profile.get("account").and_then(toml_edit::Item::as_str) == Some(from)
+                })
+                .map(|(profile_name, _)| profile_name.to_string())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let config_dir = config_path.parent().map(Utf8Path::to_path_buf);
+    let mut networks_of_urls: HashMap<String, Option<String>> = HashMap::new();
+    let mut updated_profiles = vec![];
+
+    for profile_name in candidate_profiles {
+        let profile = load_global_config::<CastConfig>(&config_dir, &Some(profile_name.clone()))?;
+
+        let profile_accounts_file = if profile.accounts_file == Utf8PathBuf::default() {
+            Utf8PathBuf::from(DEFAULT_ACCOUNTS_FILE)
+        } else {
+            profile.accounts_file.clone()
+        };
+        let profile_accounts_file =
+            Utf8PathBuf::from(shellexpand::tilde(&profile_accounts_file).to_string());
+        if !is_same_file(&profile_accounts_file, accounts_file) {
+            continue;
+        }
+
+        // Network of a profile without url, or with a node which can't be reached, is unknown
+        // and the profile is left unchanged
+        if !networks_of_urls.contains_key(&profile.url) {
+            let network = match build_provider(&profile) {
+                Ok(provider) => get_chain_id(&provider)
+                    .await
+                    .ok()
+                    .map(chain_id_to_network_name),
+                Err(_) => None,
+            };
+            networks_of_urls.insert(profile.url.clone(), network);
+        }
+        if networks_of_urls[&profile.url].as_deref() != Some(network_name) {
+            continue;
+        }
+
+        document["sncast"][profile_name.as_str()]["account"] = value(to);
+        updated_profiles.push(profile_name);
+    }
+
+    if !updated_profiles.is_empty() {
+        std::fs::write(config_path, document.to_string())
+            .context("Failed to write to snfoundry.toml")?;
+    }
+
+    Ok(updated_profiles)
+}
+
+fn is_same_file(first: &Utf8Path, second: &Utf8Path) -> bool {
+    match (first.canonicalize_utf8(), second.canonicalize_utf8()) {
+        (Ok(first), Ok(second)) => first == second,
+        _ => first == second,
+    }
+}
@@ -0,0 +1,78 @@
+use clap::{Args, ValueEnum};
+use sncast::helpers::constants::{ETH_ADDRESS, STRK_ADDRESS};
+use sncast::helpers::token::token_balance;
+use sncast::response::errors::StarknetCommandError;
+use sncast::response::structs::BalanceResponse;
+use starknet::core::types::{BlockId, FieldElement};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::JsonRpcClient;
+
+#[derive(Args)]
+#[command(about = "Get ETH and STRK balances of an account")]
+pub struct Balance {
+    /// Address whose balances are fetched (hex), the address of the configured account is used if not passed
+    #[clap(short = 'a', long)]
+    pub address: Option<FieldElement>,
+
+    /// Token whose balance is shown
+    #[clap(long, value_enum, default_value_t = Token::All)]
+    pub token: Token,
+
+    /// Address of the ETH fee token contract (hex), overrides `eth-address` from the profile.
+    /// Defaults to the address used on mainnet and Sepolia
+    #[clap(long)]
+    pub eth_address: Option<FieldElement>,
+
+    /// Address of the STRK fee token contract (hex), overrides `strk-address` from the profile.
+    /// Defaults to the address used on mainnet and Sepolia
+    #[clap(long)]
+    pub strk_address: Option<FieldElement>,
+
+    /// Block identifier on which balances are fetched.
+    /// Possible values: pending, latest, block hash (0x prefixed string)
+    /// and block number (u64)
+    #[clap(short, long, default_value = "pending")]
+    pub block_id: String,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq)]
+pub enum Token {
+    Eth,
+    Strk,
+    All,
+}
+
+pub async fn balance(
+    provider: &JsonRpcClient<HttpTransport>,
+    address: FieldElement,
+    token: Token,
+    eth_address: Option<FieldElement>,
+    strk_address: Option<FieldElement>,
+    block_id: &BlockId,
+) -> Result<BalanceResponse, StarknetCommandError> {
+    let eth_balance = if matches!(token, Token::Eth | Token::All) {
+        let eth_address = eth_address.unwrap_or(ETH_ADDRESS);
+        Some(
+            token_balance(provider, eth_address, address, block_id)
+                .await?
+                .to_string(),
+        )
+    } else {
+        None
+    };
+    let strk_balance = if matches!(token, Token::Strk | Token::All) {
+        let strk_address = strk_address.unwrap_or(STRK_ADDRESS);
+        Some(
+            token_balance(provider, strk_address, address, block_id)
+                .await?
+                .to_string(),
+        )
+    } else {
+        None
+    };
+
+    Ok(BalanceResponse {
+        eth_balance,
+        strk_balance,
+    })
+}
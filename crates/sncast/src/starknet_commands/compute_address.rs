@@ -0,0 +1,84 @@
+use crate::starknet_commands::account::AccountType;
+use anyhow::Result;
+use clap::Args;
+use sncast::helpers::address::{
+    account_address, account_address_with_calldata, udc_deployed_address,
+    validate_account_constructor_calldata,
+};
+use sncast::response::structs::{ComputeAddressResponse, Felt};
+use starknet::core::types::FieldElement;
+
+#[derive(Args, Debug)]
+#[command(
+    about = "Compute address of a contract deployed with `deploy`, or of an account deployed with `account deploy`"
+)]
+pub struct ComputeAddress {
+    /// Class hash of the contract or the account
+    #[clap(short = 'g', long)]
+    pub class_hash: FieldElement,
+
+    /// Salt of the deployment
+    #[clap(short, long)]
+    pub salt: FieldElement,
+
+    /// Calldata for the contract constructor, or for the account constructor if passed with `--type`
+    #[clap(short, long, value_delimiter = ' ', num_args = 1..)]
+    pub constructor_calldata: Vec<FieldElement>,
+
+    /// Compute address of a contract deployed with `--unique`, whose salt is modified with the deployer address
+    #[clap(
+        short,
+        long,
+        requires = "deployer_address",
+        conflicts_with = "account_type"
+    )]
+    pub unique: bool,
+
+    /// Address of the account deploying the contract
+    #[clap(long)]
+    pub deployer_address: Option<FieldElement>,
+
+    /// Compute address of an account of this type instead of a contract
+    #[clap(value_enum, short = 't', long = "type", requires = "public_key")]
+    pub account_type: Option<AccountType>,
+
+    /// Public key of the account
+    #[clap(long, requires = "account_type")]
+    pub public_key: Option<FieldElement>,
+}
+
+pub fn compute_address(compute_address: &ComputeAddress) -> Result<ComputeAddressResponse> {
+    let address = match (&compute_address.account_type, compute_address.public_key) {
+        (Some(account_type), Some(_)) if !compute_address.constructor_calldata.is_empty() => {
+            validate_account_constructor_calldata(
+                &account_type.into(),
+                compute_address.class_hash,
+                &compute_address.constructor_calldata,
+            )?;
+            account_address_with_calldata(
+                compute_address.class_hash,
+                compute_address.salt,
+                &compute_address.constructor_calldata,
+            )
+        }
+        (Some(account_type), Some(public_key)) => account_address(
+            &account_type.into(),
+            compute_address.class_hash,
+            compute_address.salt,
+            public_key,
+        ),
+        _ => udc_deployed_address(
+            compute_address.salt,
+            compute_address.class_hash,
+            &compute_address.constructor_calldata,
+            compute_address.unique,
+            compute_address
+                .deployer_address
+                .unwrap_or(FieldElement::ZERO),
+        ),
+    };
+
+    Ok(ComputeAddressResponse {
+        contract_address: Felt(address),
+    })
+}
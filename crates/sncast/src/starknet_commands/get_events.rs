@@ -0,0 +1,133 @@
+use crate::starknet_commands::tx_events::decode_events;
+use anyhow::{Context, Result};
+use clap::Args;
+use sncast::response::errors::StarknetCommandError;
+use sncast::response::structs::EventsResponse;
+use sncast::NumbersFormat;
+use starknet::core::types::{Event, EventFilter, FieldElement};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
+
+#[derive(Args)]
+#[command(about = "Get events matching the filter, decoded with ABIs of the emitting contracts")]
+pub struct GetEvents {
+    /// Block from which events are searched.
+    /// Possible values: pending, latest, block hash (0x prefixed string)
+    /// and block number (u64)
+    #[clap(long)]
+    pub from_block: Option<String>,
+
+    /// Block up to which events are searched (inclusive).
+    /// Possible values: pending, latest, block hash (0x prefixed string)
+    /// and block number (u64)
+    #[clap(long)]
+    pub to_block: Option<String>,
+
+    /// Address of the contract emitting the events (hex)
+    #[clap(long)]
+    pub address: Option<FieldElement>,
+
+    /// Keys the events must match, one flag per key position.
+    /// Alternatives for a single position are separated with commas, e.g. `--keys 0x1,0x2`
+    #[clap(long)]
+    pub keys: Vec<String>,
+
+    /// Number of events fetched with a single request
+    #[clap(long, default_value_t = 100)]
+    pub chunk_size: u64,
+
+    /// Maximum number of returned events
+    #[clap(long, default_value_t = 1000)]
+    pub limit: u64,
+}
+
+pub fn parse_keys(keys: &[String]) -> Result<Option<Vec<Vec<FieldElement>>>> {
+    if keys.is_empty() {
+        return Ok(None);
+    }
+
+    keys.iter()
+        .map(|position| {
+            position
+                .split(',')
+                .map(|key| {
+                    FieldElement::from_hex_be(key.trim())
+                        .with_context(|| format!("Failed to parse event key = {key}"))
+                })
+                .collect()
+        })
+        .collect::<Result<_>>()
+        .map(Some)
+}
+
+pub async fn get_events(
+    provider: &JsonRpcClient<HttpTransport>,
+    filter: EventFilter,
+    chunk_size: u64,
+    limit: u64,
+    numbers_format: NumbersFormat,
+) -> Result<EventsResponse, StarknetCommandError> {
+    if chunk_size == 0 {
+        return Err(anyhow::anyhow!("--chunk-size must be greater than 0").into());
+    }
+
+    let mut events = vec![];
+    let mut continuation_token = None;
+
+    while (events.len() as u64) < limit {
+        let remaining = limit - events.len() as u64;
+        let page = provider
+            .get_events(
+                filter.clone(),
+                continuation_token,
+                chunk_size.min(remaining),
+            )
+            .await
+            .map_err(|error| StarknetCommandError::ProviderError(error.into()))?;
+
+        events.extend(page.events.into_iter().map(|event| Event {
+            from_address: event.from_address,
+            keys: event.keys,
+            data: event.data,
+        }));
+
+        continuation_token = page.continuation_token;
+        if continuation_token.is_none() {
+            break;
+        }
+    }
+    // Nodes may return more events than requested in a single chunk
+    events.truncate(usize::try_from(limit).unwrap_or(usize::MAX));
+
+    let events = decode_events(provider, &events, numbers_format).await?;
+
+    Ok(EventsResponse { events })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use starknet::macros::felt;
+
+    #[test]
+    fn parses_key_alternatives() {
+        let keys = parse_keys(&["0x1,0x2".to_string(), "0x3".to_string()]).unwrap();
+
+        assert_eq!(
+            keys,
+            Some(vec![vec![felt!("0x1"), felt!("0x2")], vec![felt!("0x3")]])
+        );
+    }
+
+    #[test]
+    fn no_keys() {
+        assert_eq!(parse_keys(&[]).unwrap(), None);
+    }
+
+    #[test]
+    fn invalid_key() {
+        let error = parse_keys(&["0x1,xyz".to_string()]).unwrap_err();
+
+        assert_eq!(error.to_string(), "Failed to parse event key = xyz");
+    }
+}
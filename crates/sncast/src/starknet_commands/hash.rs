@@ -0,0 +1,77 @@
+use anyhow::{Context, Result};
+use clap::{Args, Subcommand};
+use sncast::response::structs::{Felt, HashResponse, StorageAddressResponse};
+use starknet::core::crypto::compute_hash_on_elements;
+use starknet::core::types::FieldElement;
+use starknet::core::utils::get_storage_var_address;
+use starknet_crypto::poseidon_hash_many;
+
+#[derive(Args)]
+#[command(about = "Compute hashes offline")]
+pub struct Hash {
+    #[command(subcommand)]
+    pub command: Commands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Commands {
+    Pedersen(Pedersen),
+    Poseidon(Poseidon),
+    StorageAddress(StorageAddress),
+}
+
+#[derive(Args, Debug)]
+#[command(about = "Compute Pedersen hash of a list of felts, with the length of the list appended")]
+pub struct Pedersen {
+    /// Felts to be hashed
+    #[clap(required = true)]
+    pub values: Vec<FieldElement>,
+}
+
+#[derive(Args, Debug)]
+#[command(about = "Compute Poseidon hash of a list of felts")]
+pub struct Poseidon {
+    /// Felts to be hashed
+    #[clap(required = true)]
+    pub values: Vec<FieldElement>,
+}
+
+#[derive(Args, Debug)]
+#[command(about = "Compute address of a storage variable")]
+pub struct StorageAddress {
+    /// Name of the storage variable
+    #[clap(short, long)]
+    pub name: String,
+
+    /// Keys of the storage variable, e.g. keys of a mapping
+    #[clap(short, long, value_delimiter = ' ', num_args = 1..)]
+    pub keys: Vec<FieldElement>,
+}
+
+#[must_use]
+pub fn pedersen(pedersen: &Pedersen) -> HashResponse {
+    HashResponse {
+        hash: Felt(compute_hash_on_elements(&pedersen.values)),
+    }
+}
+
+#[must_use]
+pub fn poseidon(poseidon: &Poseidon) -> HashResponse {
+    HashResponse {
+        hash: Felt(poseidon_hash_many(&poseidon.values)),
+    }
+}
+
+pub fn storage_address(storage_address: &StorageAddress) -> Result<StorageAddressResponse> {
+    let address = get_storage_var_address(&storage_address.name, &storage_address.keys)
+        .with_context(|| {
+            format!(
+                "Failed to compute address of storage variable = {}",
+                storage_address.name
+            )
+        })?;
+
+    Ok(StorageAddressResponse {
+        storage_address: Felt(address),
+    })
+}
@@ -1,9 +1,14 @@
+pub use sncast::commands::{call, declare, deploy, invoke};
+
+pub mod abi;
 pub mod account;
-pub mod call;
-pub mod declare;
-pub mod deploy;
-pub mod invoke;
+pub mod balance;
+pub mod compute_address;
+pub mod get_events;
+pub mod hash;
 pub mod multicall;
+pub mod receipt;
 pub mod script;
 pub mod show_config;
+pub mod tx_events;
 pub mod tx_status;
@@ -3,13 +3,15 @@ use anyhow::{Context, Result};
 use camino::Utf8PathBuf;
 use clap::Args;
 use serde::Deserialize;
+use sncast::helpers::address::udc_deployed_address;
 use sncast::helpers::constants::UDC_ADDRESS;
+use sncast::helpers::fee::MaxFee;
 use sncast::response::errors::handle_starknet_command_error;
-use sncast::response::structs::InvokeResponse;
-use sncast::{extract_or_generate_salt, udc_uniqueness, WaitForTx};
+use sncast::response::structs::{InvokeResponse, TransactionResponse};
+use sncast::{extract_or_generate_salt, WaitForTx};
 use starknet::accounts::{Account, Call, SingleOwnerAccount};
 use starknet::core::types::FieldElement;
-use starknet::core::utils::{get_selector_from_name, get_udc_deployed_address};
+use starknet::core::utils::get_selector_from_name;
 use starknet::providers::jsonrpc::HttpTransport;
 use starknet::providers::JsonRpcClient;
 use starknet::signers::LocalWallet;
@@ -22,9 +24,10 @@ pub struct Run {
     #[clap(short = 'p', long = "path")]
     pub path: Utf8PathBuf,
 
-    /// Max fee for the transaction. If not provided, max fee will be automatically estimated
+    /// Max fee for the transaction, in WEI or as a percentage of the ETH balance of the account, e.g. `80%`.
+    /// If not provided, max fee will be automatically estimated
     #[clap(short, long)]
-    pub max_fee: Option<FieldElement>,
+    pub max_fee: Option<MaxFee>,
 }
 
 #[allow(dead_code)]
@@ -50,9 +53,10 @@ struct InvokeCall {
 pub async fn run(
     path: &Utf8PathBuf,
     account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
-    max_fee: Option<FieldElement>,
+    max_fee: Option<MaxFee>,
     wait_config: WaitForTx,
-) -> Result<InvokeResponse> {
+    dry_run: bool,
+) -> Result<TransactionResponse<InvokeResponse>> {
     let contents = std::fs::read_to_string(path)?;
     let items_map: HashMap<String, Vec<toml::Value>> =
         toml::from_str(&contents).with_context(|| format!("Failed to parse {path}"))?;
@@ -88,11 +92,12 @@ pub async fn run(
                     calldata,
                 });
 
-                let contract_address = get_udc_deployed_address(
+                let contract_address = udc_deployed_address(
                     salt,
                     deploy_call.class_hash,
-                    &udc_uniqueness(deploy_call.unique, account.address()),
                     &parsed_inputs,
+                    deploy_call.unique,
+                    account.address(),
                 );
                 contracts.insert(deploy_call.id, contract_address.to_string());
             }
@@ -121,7 +126,7 @@ pub async fn run(
         }
     }
 
-    execute_calls(account, parsed_calls, max_fee, None, wait_config)
+    execute_calls(account, parsed_calls, max_fee, None, wait_config, dry_run)
         .await
         .map_err(handle_starknet_command_error)
 }
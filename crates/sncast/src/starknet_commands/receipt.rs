@@ -0,0 +1,123 @@
+use crate::starknet_commands::tx_events::{decode_events, receipt_events};
+use anyhow::{anyhow, Context};
+use serde_json::Value;
+use sncast::response::errors::StarknetCommandError;
+use sncast::response::structs::{
+    ExecutionStatus, Felt, FinalityStatus, TransactionReceiptResponse,
+};
+use sncast::NumbersFormat;
+use starknet::core::types::FieldElement;
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
+
+/// Gets the receipt of a transaction awaited with `--wait`,
+/// with events decoded with ABIs of the emitting contracts
+pub async fn receipt(
+    provider: &JsonRpcClient<HttpTransport>,
+    transaction_hash: FieldElement,
+    numbers_format: NumbersFormat,
+) -> Result<TransactionReceiptResponse, StarknetCommandError> {
+    let receipt = provider
+        .get_transaction_receipt(transaction_hash)
+        .await
+        .map_err(|error| StarknetCommandError::ProviderError(error.into()))?;
+
+    // Fields shared by receipts of all transaction types are read in their RPC format
+    let fields = serde_json::to_value(&receipt).context("Failed to serialize receipt")?;
+    let events = decode_events(provider, &receipt_events(receipt), numbers_format).await?;
+
+    Ok(build_receipt_response(&fields, events)?)
+}
+
+fn build_receipt_response(
+    fields: &Value,
+    events: Vec<String>,
+) -> anyhow::Result<TransactionReceiptResponse> {
+    let actual_fee = &fields["actual_fee"];
+    let amount = actual_fee["amount"]
+        .as_str()
+        .and_then(|amount| FieldElement::from_hex_be(amount).ok())
+        .ok_or_else(|| anyhow!("Receipt has no valid actual fee"))?;
+
+    let finality_status = match fields["finality_status"].as_str() {
+        Some("ACCEPTED_ON_L1") => FinalityStatus::AcceptedOnL1,
+        // Receipts are returned only for transactions accepted on L2 or L1
+        _ => FinalityStatus::AcceptedOnL2,
+    };
+    let execution_status = match fields["execution_status"].as_str() {
+        Some("REVERTED") => ExecutionStatus::Reverted,
+        _ => ExecutionStatus::Succeeded,
+    };
+
+    Ok(TransactionReceiptResponse {
+        actual_fee: Felt(amount),
+        fee_unit: actual_fee["unit"].as_str().unwrap_or("WEI").to_string(),
+        finality_status,
+        execution_status,
+        revert_reason: fields["revert_reason"].as_str().map(str::to_string),
+        execution_resources: format_execution_resources(&fields["execution_resources"]),
+        events,
+    })
+}
+
+/// Resources are formatted as `<name>: <value>` in alphabetical order,
+/// with the data availability resources flattened
+fn format_execution_resources(resources: &Value) -> Vec<String> {
+    let Some(resources) = resources.as_object() else {
+        return vec![];
+    };
+
+    let mut formatted: Vec<String> = resources
+        .iter()
+        .flat_map(|(name, value)| match value {
+            Value::Object(data_availability) => data_availability.iter().collect(),
+            _ => vec![(name, value)],
+        })
+        .filter_map(|(name, value)| value.as_u64().map(|value| format!("{name}: {value}")))
+        .collect();
+    formatted.sort();
+    formatted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn builds_response_from_receipt_fields() {
+        let fields = json!({
+            "actual_fee": { "amount": "0x2a", "unit": "FRI" },
+            "finality_status": "ACCEPTED_ON_L2",
+            "execution_status": "REVERTED",
+            "revert_reason": "Error in the called contract",
+            "execution_resources": {
+                "steps": 120,
+                "range_check_builtin_applications": 4,
+                "data_availability": { "l1_gas": 0, "l1_data_gas": 128 }
+            }
+        });
+
+        let response = build_receipt_response(&fields, vec![]).unwrap();
+
+        assert_eq!(response.actual_fee, Felt(FieldElement::from(42_u8)));
+        assert_eq!(response.fee_unit, "FRI");
+        assert!(matches!(
+            response.execution_status,
+            ExecutionStatus::Reverted
+        ));
+        assert_eq!(
+            response.revert_reason.as_deref(),
+            Some("Error in the called contract")
+        );
+        assert_eq!(
+            response.execution_resources,
+            vec![
+                "l1_data_gas: 128",
+                "l1_gas: 0",
+                "range_check_builtin_applications: 4",
+                "steps: 120"
+            ]
+        );
+    }
+}
@@ -38,13 +38,15 @@ use shared::print::print_as_warning;
 use shared::utils::build_readable_text;
 use sncast::helpers::configuration::CastConfig;
 use sncast::helpers::constants::SCRIPT_LIB_ARTIFACT_NAME;
-use sncast::response::structs::ScriptRunResponse;
+use sncast::helpers::fee::MaxFee;
+use sncast::response::errors::StarknetCommandError;
+use sncast::response::structs::{ScriptRunResponse, TransactionResponse};
 use sncast::state::hashing::{
     generate_declare_tx_id, generate_deploy_tx_id, generate_invoke_tx_id,
 };
 use sncast::state::state_file::StateManager;
 use starknet::accounts::{Account, SingleOwnerAccount};
-use starknet::core::types::{BlockId, BlockTag::Pending};
+use starknet::core::types::{BlockId, BlockTag::Pending, FieldElement};
 use starknet::providers::jsonrpc::HttpTransport;
 use starknet::providers::JsonRpcClient;
 use starknet::signers::LocalWallet;
@@ -65,6 +67,10 @@ pub struct Run {
     /// Do not use the state file
     #[clap(long)]
     pub no_state_file: bool,
+
+    /// Fetch the account nonce once and increment it locally for every transaction sent by the script
+    #[clap(long)]
+    pub track_nonce: bool,
 }
 
 pub struct CastScriptExtension<'a> {
@@ -74,6 +80,9 @@ pub struct CastScriptExtension<'a> {
     pub config: &'a CastConfig,
     pub artifacts: &'a HashMap<String, StarknetContractArtifacts>,
     pub state: StateManager,
+    pub track_nonce: bool,
+    /// Nonce of the next transaction, known only when nonces are tracked locally
+    pub next_nonce: Option<FieldElement>,
 }
 
 impl<'a> CastScriptExtension<'a> {
@@ -82,6 +91,37 @@ impl<'a> CastScriptExtension<'a> {
     ) -> Result<&SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>> {
         self.account.ok_or_else(|| anyhow!("Account not defined. Please ensure the correct account is passed to `script run` command"))
     }
+
+    /// Returns nonce for the next transaction. Nonce passed in the script always takes precedence.
+    fn resolve_nonce(&mut self, nonce: Option<FieldElement>) -> Result<Option<FieldElement>> {
+        if nonce.is_some() || !self.track_nonce {
+            return Ok(nonce);
+        }
+        if self.next_nonce.is_none() {
+            self.next_nonce = Some(self.tokio_runtime.block_on(get_nonce(
+                self.provider,
+                "pending",
+                self.account()?.address(),
+            ))?);
+        }
+        Ok(self.next_nonce)
+    }
+
+    /// Advances the locally tracked nonce after a successful transaction.
+    /// On failure it is unknown whether the nonce was used, so it is fetched again for the next one.
+    fn update_nonce<T>(
+        &mut self,
+        nonce: Option<FieldElement>,
+        result: &Result<T, StarknetCommandError>,
+    ) {
+        if !self.track_nonce {
+            return;
+        }
+        self.next_nonce = match (nonce, result) {
+            (Some(nonce), Ok(_)) => Some(nonce + FieldElement::ONE),
+            _ => None,
+        };
+    }
 }
 
 impl<'a> ExtensionLogic for CastScriptExtension<'a> {
@@ -106,12 +146,15 @@ impl<'a> ExtensionLogic for CastScriptExtension<'a> {
                     calldata_felts,
                     self.provider,
                     &BlockId::Tag(Pending),
+                    0,
                 ));
                 Ok(CheatcodeHandlingResult::from_serializable(call_result))
             }
             "declare" => {
                 let contract_name: String = input_reader.read::<ByteArray>()?.into();
-                let max_fee = input_reader.read()?;
+                let max_fee = input_reader
+                    .read::<Option<FieldElement>>()?
+                    .map(MaxFee::Amount);
                 let nonce = input_reader.read()?;
 
                 let declare_tx_id = generate_declare_tx_id(contract_name.as_str());
@@ -122,17 +165,23 @@ impl<'a> ExtensionLogic for CastScriptExtension<'a> {
                     return Ok(CheatcodeHandlingResult::from_serializable(success_output));
                 }
 
-                let declare_result = self.tokio_runtime.block_on(declare::declare(
-                    &contract_name,
-                    max_fee,
-                    self.account()?,
-                    nonce,
-                    self.artifacts,
-                    WaitForTx {
-                        wait: true,
-                        wait_params: self.config.wait_params,
-                    },
-                ));
+                let nonce = self.resolve_nonce(nonce)?;
+                let declare_result = self
+                    .tokio_runtime
+                    .block_on(declare::declare(
+                        &contract_name,
+                        max_fee,
+                        self.account()?,
+                        nonce,
+                        self.artifacts,
+                        WaitForTx {
+                            wait: true,
+                            wait_params: self.config.wait_params,
+                        },
+                        false,
+                    ))
+                    .map(TransactionResponse::expect_sent);
+                self.update_nonce(nonce, &declare_result);
 
                 self.state.maybe_insert_tx_entry(
                     declare_tx_id.as_str(),
@@ -147,7 +196,9 @@ impl<'a> ExtensionLogic for CastScriptExtension<'a> {
 
                 let salt = input_reader.read()?;
                 let unique = input_reader.read()?;
-                let max_fee = input_reader.read()?;
+                let max_fee = input_reader
+                    .read::<Option<FieldElement>>()?
+                    .map(MaxFee::Amount);
                 let nonce = input_reader.read()?;
 
                 let deploy_tx_id =
@@ -159,19 +210,25 @@ impl<'a> ExtensionLogic for CastScriptExtension<'a> {
                     return Ok(CheatcodeHandlingResult::from_serializable(success_output));
                 }
 
-                let deploy_result = self.tokio_runtime.block_on(deploy::deploy(
-                    class_hash,
-                    constructor_calldata,
-                    salt,
-                    unique,
-                    max_fee,
-                    self.account()?,
-                    nonce,
-                    WaitForTx {
-                        wait: true,
-                        wait_params: self.config.wait_params,
-                    },
-                ));
+                let nonce = self.resolve_nonce(nonce)?;
+                let deploy_result = self
+                    .tokio_runtime
+                    .block_on(deploy::deploy(
+                        class_hash,
+                        constructor_calldata,
+                        salt,
+                        unique,
+                        max_fee,
+                        self.account()?,
+                        nonce,
+                        WaitForTx {
+                            wait: true,
+                            wait_params: self.config.wait_params,
+                        },
+                        false,
+                    ))
+                    .map(TransactionResponse::expect_sent);
+                self.update_nonce(nonce, &deploy_result);
 
                 self.state.maybe_insert_tx_entry(
                     deploy_tx_id.as_str(),
@@ -185,7 +242,9 @@ impl<'a> ExtensionLogic for CastScriptExtension<'a> {
                 let contract_address = input_reader.read()?;
                 let function_selector = input_reader.read()?;
                 let calldata: Vec<_> = input_reader.read()?;
-                let max_fee = input_reader.read()?;
+                let max_fee = input_reader
+                    .read::<Option<FieldElement>>()?
+                    .map(MaxFee::Amount);
                 let nonce = input_reader.read()?;
 
                 let invoke_tx_id =
@@ -197,18 +256,24 @@ impl<'a> ExtensionLogic for CastScriptExtension<'a> {
                     return Ok(CheatcodeHandlingResult::from_serializable(success_output));
                 }
 
-                let invoke_result = self.tokio_runtime.block_on(invoke::invoke(
-                    contract_address,
-                    function_selector,
-                    calldata,
-                    max_fee,
-                    self.account()?,
-                    nonce,
-                    WaitForTx {
-                        wait: true,
-                        wait_params: self.config.wait_params,
-                    },
-                ));
+                let nonce = self.resolve_nonce(nonce)?;
+                let invoke_result = self
+                    .tokio_runtime
+                    .block_on(invoke::invoke(
+                        contract_address,
+                        function_selector,
+                        calldata,
+                        max_fee,
+                        self.account()?,
+                        nonce,
+                        WaitForTx {
+                            wait: true,
+                            wait_params: self.config.wait_params,
+                        },
+                        false,
+                    ))
+                    .map(TransactionResponse::expect_sent);
+                self.update_nonce(nonce, &invoke_result);
 
                 self.state.maybe_insert_tx_entry(
                     invoke_tx_id.as_str(),
@@ -267,6 +332,7 @@ pub fn run(
     tokio_runtime: Runtime,
     config: &CastConfig,
     state_file_path: Option<Utf8PathBuf>,
+    track_nonce: bool,
 ) -> Result<ScriptRunResponse> {
     warn_if_sncast_std_not_compatible(metadata)?;
     let artifacts = inject_lib_artifact(metadata, package_metadata, artifacts)?;
@@ -335,7 +401,7 @@ pub fn run(
     } else {
         Some(tokio_runtime.block_on(get_account(
             &config.account,
-            &config.accounts_file,
+            &config.accounts_files(),
             provider,
             config.keystore.clone(),
         ))?)
@@ -349,6 +415,8 @@ pub fn run(
         artifacts: &artifacts,
         account: account.as_ref(),
         state,
+        track_nonce,
+        next_nonce: None,
     };
 
     let mut cast_runtime = ExtendedRuntime {
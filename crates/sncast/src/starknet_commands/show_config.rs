@@ -23,9 +23,12 @@ pub async fn show_config(
     let account = Some(cast_config.account).filter(|p| !p.is_empty());
     let mut accounts_file_path =
         Some(cast_config.accounts_file).filter(|p| p != &Utf8PathBuf::default());
+    let mut additional_accounts_files =
+        Some(cast_config.additional_accounts_files).filter(|files| !files.is_empty());
     let keystore = cast_config.keystore;
     if keystore.is_some() {
         accounts_file_path = None;
+        additional_accounts_files = None;
     }
     let wait_timeout = Some(cast_config.wait_params.get_timeout());
     let wait_retry_interval = Some(cast_config.wait_params.get_retry_interval());
@@ -36,6 +39,7 @@ pub async fn show_config(
         rpc_url,
         account,
         accounts_file_path,
+        additional_accounts_files,
         keystore,
         wait_timeout: wait_timeout.map(|x| Decimal(u64::from(x))),
         wait_retry_interval: wait_retry_interval.map(|x| Decimal(u64::from(x))),
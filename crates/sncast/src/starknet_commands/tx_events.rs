@@ -0,0 +1,511 @@
+use clap::Args;
+use sncast::response::errors::StarknetCommandError;
+use sncast::response::structs::TransactionEventsResponse;
+use sncast::NumbersFormat;
+use starknet::core::types::contract::{
+    AbiEntry, AbiEvent, EventField, EventFieldKind, TypedAbiEvent,
+};
+use starknet::core::types::{
+    BlockId, BlockTag, ContractClass, Event, FieldElement, MaybePendingTransactionReceipt,
+    PendingTransactionReceipt, TransactionReceipt,
+};
+use starknet::core::utils::get_selector_from_name;
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
+use std::collections::{HashMap, HashSet};
+use std::slice::Iter;
+
+#[derive(Args)]
+#[command(
+    about = "Get events emitted by a transaction, decoded with ABIs of the emitting contracts"
+)]
+pub struct TxEvents {
+    /// Hash of the transaction
+    pub transaction_hash: FieldElement,
+}
+
+pub async fn tx_events(
+    provider: &JsonRpcClient<HttpTransport>,
+    transaction_hash: FieldElement,
+    numbers_format: NumbersFormat,
+) -> Result<TransactionEventsResponse, StarknetCommandError> {
+    let receipt = provider
+        .get_transaction_receipt(transaction_hash)
+        .await
+        .map_err(|error| StarknetCommandError::ProviderError(error.into()))?;
+
+    let events = decode_events(provider, &receipt_events(receipt), numbers_format).await?;
+
+    Ok(TransactionEventsResponse { events })
+}
+
+/// Formats events, decoding them with ABIs of the emitting contracts where possible
+pub(crate) async fn decode_events(
+    provider: &JsonRpcClient<HttpTransport>,
+    events: &[Event],
+    numbers_format: NumbersFormat,
+) -> Result<Vec<String>, StarknetCommandError> {
+    let mut abis: HashMap<FieldElement, Option<EventsAbi>> = HashMap::new();
+    let mut decoded_events = vec![];
+
+    for event in events {
+        if !abis.contains_key(&event.from_address) {
+            let abi = fetch_events_abi(provider, event.from_address, numbers_format).await?;
+            abis.insert(event.from_address, abi);
+        }
+
+        let decoded = abis[&event.from_address]
+            .as_ref()
+            .and_then(|abi| abi.decode(&event.keys, &event.data));
+        decoded_events.push(format_event(event, decoded, numbers_format));
+    }
+
+    Ok(decoded_events)
+}
+
+pub(crate) fn receipt_events(receipt: MaybePendingTransactionReceipt) -> Vec<Event> {
+    match receipt {
+        MaybePendingTransactionReceipt::Receipt(receipt) => match receipt {
+            TransactionReceipt::Invoke(receipt) => receipt.events,
+            TransactionReceipt::L1Handler(receipt) => receipt.events,
+            TransactionReceipt::Declare(receipt) => receipt.events,
+            TransactionReceipt::Deploy(receipt) => receipt.events,
+            TransactionReceipt::DeployAccount(receipt) => receipt.events,
+        },
+        MaybePendingTransactionReceipt::PendingReceipt(receipt) => match receipt {
+            PendingTransactionReceipt::Invoke(receipt) => receipt.events,
+            PendingTransactionReceipt::L1Handler(receipt) => receipt.events,
+            PendingTransactionReceipt::Declare(receipt) => receipt.events,
+            PendingTransactionReceipt::DeployAccount(receipt) => receipt.events,
+        },
+    }
+}
+
+/// Events of Cairo 0 contracts are not decoded, as their ABI is not available in the same format
+async fn fetch_events_abi(
+    provider: &JsonRpcClient<HttpTransport>,
+    address: FieldElement,
+    numbers_format: NumbersFormat,
+) -> Result<Option<EventsAbi>, StarknetCommandError> {
+    let class = provider
+        .get_class_at(BlockId::Tag(BlockTag::Pending), address)
+        .await
+        .map_err(|error| StarknetCommandError::ProviderError(error.into()))?;
+
+    Ok(match class {
+        ContractClass::Sierra(class) => serde_json::from_str::<Vec<AbiEntry>>(&class.abi)
+            .ok()
+            .map(|abi| EventsAbi::new(abi, numbers_format)),
+        ContractClass::Legacy(_) => None,
+    })
+}
+
+fn format_event(event: &Event, decoded: Option<String>, numbers_format: NumbersFormat) -> String {
+    match decoded {
+        Some(decoded) => format!("{:#x}: {decoded}", event.from_address),
+        None => format!(
+            "{:#x}: keys: [{}], data: [{}]",
+            event.from_address,
+            format_felts(&event.keys, numbers_format),
+            format_felts(&event.data, numbers_format)
+        ),
+    }
+}
+
+fn format_felts(felts: &[FieldElement], numbers_format: NumbersFormat) -> String {
+    felts
+        .iter()
+        .map(|felt| format_felt(*felt, numbers_format))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Felts are displayed as hex unless another format was selected
+fn format_felt(felt: FieldElement, numbers_format: NumbersFormat) -> String {
+    numbers_format
+        .format_felt(felt)
+        .unwrap_or_else(|| format!("{felt:#x}"))
+}
+
+/// Types from the contract ABI needed to decode its events
+struct EventsAbi {
+    events: HashMap<String, TypedAbiEvent>,
+    structs: HashMap<String, Vec<(String, String)>>,
+    enums: HashMap<String, Vec<(String, String)>>,
+    /// Events which are not variants of other events, i.e. `Event` enums of the contract
+    root_events: Vec<Vec<EventField>>,
+    numbers_format: NumbersFormat,
+}
+
+impl EventsAbi {
+    fn new(abi: Vec<AbiEntry>, numbers_format: NumbersFormat) -> Self {
+        let mut events = HashMap::new();
+        let mut structs = HashMap::new();
+        let mut enums = HashMap::new();
+
+        for entry in abi {
+            match entry {
+                AbiEntry::Event(AbiEvent::Typed(event)) => {
+                    let name = match &event {
+                        TypedAbiEvent::Struct(event) => event.name.clone(),
+                        TypedAbiEvent::Enum(event) => event.name.clone(),
+                    };
+                    events.insert(name, event);
+                }
+                AbiEntry::Struct(abi_struct) => {
+                    let members = abi_struct
+                        .members
+                        .into_iter()
+                        .map(|member| (member.name, member.r#type))
+                        .collect();
+                    structs.insert(abi_struct.name, members);
+                }
+                AbiEntry::Enum(abi_enum) => {
+                    let variants = abi_enum
+                        .variants
+                        .into_iter()
+                        .map(|variant| (variant.name, variant.r#type))
+                        .collect();
+                    enums.insert(abi_enum.name, variants);
+                }
+                _ => {}
+            }
+        }
+
+        let nested_events: HashSet<&String> = events
+            .values()
+            .filter_map(|event| match event {
+                TypedAbiEvent::Enum(event) => Some(&event.variants),
+                TypedAbiEvent::Struct(_) => None,
+            })
+            .flatten()
+            .map(|variant| &variant.r#type)
+            .collect();
+        let root_events = events
+            .iter()
+            .filter(|(name, _)| !nested_events.contains(name))
+            .filter_map(|(_, event)| match event {
+                TypedAbiEvent::Enum(event) => Some(event.variants.clone()),
+                TypedAbiEvent::Struct(_) => None,
+            })
+            .collect();
+
+        Self {
+            events,
+            structs,
+            enums,
+            root_events,
+            numbers_format,
+        }
+    }
+
+    /// Returns `None` if the event does not match any event from the ABI
+    fn decode(&self, keys: &[FieldElement], data: &[FieldElement]) -> Option<String> {
+        let (selector, keys) = keys.split_first()?;
+
+        self.root_events.iter().find_map(|variants| {
+            let mut keys = keys.iter();
+            let mut data = data.iter();
+            let decoded = self.decode_enum_event(variants, *selector, &mut keys, &mut data)?;
+
+            // All felts must be consumed, otherwise the event was matched incorrectly
+            (keys.next().is_none() && data.next().is_none()).then_some(decoded)
+        })
+    }
+
+    fn decode_enum_event(
+        &self,
+        variants: &[EventField],
+        selector: FieldElement,
+        keys: &mut Iter<FieldElement>,
+        data: &mut Iter<FieldElement>,
+    ) -> Option<String> {
+        for variant in variants {
+            match variant.kind {
+                EventFieldKind::Nested
+                    if get_selector_from_name(&variant.name).ok() == Some(selector) =>
+                {
+                    return self.decode_event(&variant.name, &variant.r#type, keys, data);
+                }
+                // Variants of flattened enums are matched directly by their selectors
+                EventFieldKind::Flat => {
+                    let Some(TypedAbiEvent::Enum(event)) = self.events.get(&variant.r#type) else {
+                        continue;
+                    };
+                    let (mut flat_keys, mut flat_data) = (keys.clone(), data.clone());
+                    if let Some(decoded) = self.decode_enum_event(
+                        &event.variants,
+                        selector,
+                        &mut flat_keys,
+                        &mut flat_data,
+                    ) {
+                        (*keys, *data) = (flat_keys, flat_data);
+                        return Some(decoded);
+                    }
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    fn decode_event(
+        &self,
+        name: &str,
+        event_type: &str,
+        keys: &mut Iter<FieldElement>,
+        data: &mut Iter<FieldElement>,
+    ) -> Option<String> {
+        match self.events.get(event_type)? {
+            TypedAbiEvent::Struct(event) => {
+                let mut fields = vec![];
+                for member in &event.members {
+                    let felts = match member.kind {
+                        EventFieldKind::Key => &mut *keys,
+                        EventFieldKind::Data => &mut *data,
+                        EventFieldKind::Nested | EventFieldKind::Flat => return None,
+                    };
+                    let value = self.decode_value(&member.r#type, felts)?;
+                    fields.push(format!("{}: {value}", member.name));
+                }
+                Some(format_fields(name, &fields))
+            }
+            TypedAbiEvent::Enum(event) => {
+                let selector = *keys.next()?;
+                let decoded = self.decode_enum_event(&event.variants, selector, keys, data)?;
+                Some(format!("{name}::{decoded}"))
+            }
+        }
+    }
+
+    /// Supports core types, arrays and structs and enums defined in the ABI
+    fn decode_value(&self, value_type: &str, felts: &mut Iter<FieldElement>) -> Option<String> {
+        match value_type {
+            "core::felt252" => Some(format_felt(*felts.next()?, self.numbers_format)),
+            // Addresses and integers are never short strings, so they are not decoded with `auto` format
+            "core::starknet::contract_address::ContractAddress"
+            | "core::starknet::class_hash::ClassHash"
+            | "core::starknet::eth_address::EthAddress" => {
+                let felt = felts.next()?;
+                Some(match self.numbers_format {
+                    NumbersFormat::Decimal => format!("{felt:#}"),
+                    _ => format!("{felt:#x}"),
+                })
+            }
+            "core::integer::u8"
+            | "core::integer::u16"
+            | "core::integer::u32"
+            | "core::integer::u64"
+            | "core::integer::u128" => {
+                let felt = felts.next()?;
+                Some(match self.numbers_format {
+                    NumbersFormat::Hex => format!("{felt:#x}"),
+                    _ => format!("{felt:#}"),
+                })
+            }
+            "core::bool" => match u8::try_from(*felts.next()?).ok()? {
+                0 => Some("false".to_string()),
+                1 => Some("true".to_string()),
+                _ => None,
+            },
+            "core::integer::u256" => {
+                let low = u128::try_from(*felts.next()?).ok()?;
+                let high = u128::try_from(*felts.next()?).ok()?;
+                Some(if high == 0 {
+                    low.to_string()
+                } else {
+                    format!("{high:#x}{low:032x}")
+                })
+            }
+            "core::byte_array::ByteArray" => decode_byte_array(felts),
+            "()" => Some("()".to_string()),
+            _ => {
+                if let Some(item_type) = value_type
+                    .strip_prefix("core::array::Array::<")
+                    .or_else(|| value_type.strip_prefix("core::array::Span::<"))
+                    .and_then(|item_type| item_type.strip_suffix('>'))
+                {
+                    let length = u64::try_from(*felts.next()?).ok()?;
+                    let items = (0..length)
+                        .map(|_| self.decode_value(item_type, felts))
+                        .collect::<Option<Vec<_>>>()?;
+                    return Some(format!("[{}]", items.join(", ")));
+                }
+                if let Some(members) = self.structs.get(value_type) {
+                    let fields = members
+                        .iter()
+                        .map(|(name, member_type)| {
+                            Some(format!(
+                                "{name}: {}",
+                                self.decode_value(member_type, felts)?
+                            ))
+                        })
+                        .collect::<Option<Vec<_>>>()?;
+                    return Some(format_fields(short_name(value_type), &fields));
+                }
+                if let Some(variants) = self.enums.get(value_type) {
+                    let index = usize::try_from(u64::try_from(*felts.next()?).ok()?).ok()?;
+                    let (name, variant_type) = variants.get(index)?;
+                    return Some(if variant_type == "()" {
+                        name.clone()
+                    } else {
+                        format!("{name}({})", self.decode_value(variant_type, felts)?)
+                    });
+                }
+                None
+            }
+        }
+    }
+}
+
+fn decode_byte_array(felts: &mut Iter<FieldElement>) -> Option<String> {
+    let words_count = u64::try_from(*felts.next()?).ok()?;
+    let mut bytes = vec![];
+    for _ in 0..words_count {
+        // Full words store 31 bytes, the first byte of the felt is always zero
+        bytes.extend_from_slice(&felts.next()?.to_bytes_be()[1..]);
+    }
+    let pending_word = felts.next()?.to_bytes_be();
+    let pending_word_len = usize::try_from(u64::try_from(*felts.next()?).ok()?).ok()?;
+    if pending_word_len > 31 {
+        return None;
+    }
+    bytes.extend_from_slice(&pending_word[32 - pending_word_len..]);
+
+    Some(format!("\"{}\"", String::from_utf8_lossy(&bytes)))
+}
+
+fn format_fields(name: &str, fields: &[String]) -> String {
+    if fields.is_empty() {
+        name.to_string()
+    } else {
+        format!("{name} {{ {} }}", fields.join(", "))
+    }
+}
+
+fn short_name(path: &str) -> &str {
+    path.rsplit("::").next().unwrap_or(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use starknet::macros::felt;
+
+    const ABI: &str = r#"[
+        {
+            "type": "struct",
+            "name": "contract::Point",
+            "members": [
+                { "name": "x", "type": "core::integer::u32" },
+                { "name": "y", "type": "core::integer::u32" }
+            ]
+        },
+        {
+            "type": "event",
+            "name": "contract::Transferred",
+            "kind": "struct",
+            "members": [
+                { "name": "from", "type": "core::starknet::contract_address::ContractAddress", "kind": "key" },
+                { "name": "amount", "type": "core::integer::u256", "kind": "data" },
+                { "name": "points", "type": "core::array::Span::<contract::Point>", "kind": "data" }
+            ]
+        },
+        {
+            "type": "event",
+            "name": "component::Renamed",
+            "kind": "struct",
+            "members": [
+                { "name": "name", "type": "core::byte_array::ByteArray", "kind": "data" }
+            ]
+        },
+        {
+            "type": "event",
+            "name": "component::Event",
+            "kind": "enum",
+            "variants": [
+                { "name": "Renamed", "type": "component::Renamed", "kind": "nested" }
+            ]
+        },
+        {
+            "type": "event",
+            "name": "contract::Event",
+            "kind": "enum",
+            "variants": [
+                { "name": "Transferred", "type": "contract::Transferred", "kind": "nested" },
+                { "name": "ComponentEvent", "type": "component::Event", "kind": "flat" }
+            ]
+        }
+    ]"#;
+
+    fn abi() -> EventsAbi {
+        EventsAbi::new(serde_json::from_str(ABI).unwrap(), NumbersFormat::Default)
+    }
+
+    fn selector(name: &str) -> FieldElement {
+        get_selector_from_name(name).unwrap()
+    }
+
+    #[test]
+    fn decodes_struct_event() {
+        let decoded = abi().decode(
+            &[selector("Transferred"), felt!("0x123")],
+            &[
+                felt!("100"),
+                felt!("0"),
+                felt!("2"),
+                felt!("1"),
+                felt!("2"),
+                felt!("3"),
+                felt!("4"),
+            ],
+        );
+
+        assert_eq!(
+            decoded.unwrap(),
+            "Transferred { from: 0x123, amount: 100, points: [Point { x: 1, y: 2 }, Point { x: 3, y: 4 }] }"
+        );
+    }
+
+    #[test]
+    fn decodes_values_in_selected_format() {
+        let abi = EventsAbi::new(serde_json::from_str(ABI).unwrap(), NumbersFormat::Hex);
+
+        let decoded = abi.decode(
+            &[selector("Transferred"), felt!("0x123")],
+            &[
+                felt!("100"),
+                felt!("0"),
+                felt!("1"),
+                felt!("10"),
+                felt!("20"),
+            ],
+        );
+
+        assert_eq!(
+            decoded.unwrap(),
+            "Transferred { from: 0x123, amount: 100, points: [Point { x: 0xa, y: 0x14 }] }"
+        );
+    }
+
+    #[test]
+    fn decodes_flattened_event() {
+        let decoded = abi().decode(
+            &[selector("Renamed")],
+            &[felt!("0"), felt!("0x616263"), felt!("3")],
+        );
+
+        assert_eq!(decoded.unwrap(), "Renamed { name: \"abc\" }");
+    }
+
+    #[test]
+    fn unknown_event_is_not_decoded() {
+        let abi = abi();
+
+        assert!(abi.decode(&[selector("Unknown")], &[]).is_none());
+        // Too few data felts for the matched event
+        assert!(abi
+            .decode(&[selector("Transferred"), felt!("0x123")], &[felt!("100")])
+            .is_none());
+    }
+}
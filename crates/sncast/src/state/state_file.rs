@@ -458,6 +458,7 @@ mod tests {
             name: "invoke".to_string(),
             output: ScriptTransactionOutput::InvokeResponse(InvokeResponse {
                 transaction_hash: Felt("0x3".parse().unwrap()),
+                nonce: Felt("0x1".parse().unwrap()),
             }),
             status: ScriptTransactionStatus::Success,
             timestamp: 1,
@@ -528,6 +529,7 @@ mod tests {
             name: "invoke".to_string(),
             output: ScriptTransactionOutput::InvokeResponse(InvokeResponse {
                 transaction_hash: Felt("0x3".parse().unwrap()),
+                nonce: Felt("0x1".parse().unwrap()),
             }),
             status: ScriptTransactionStatus::Success,
             timestamp: 3,
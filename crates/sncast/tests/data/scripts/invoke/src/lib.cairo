@@ -1,5 +1,6 @@
 mod contract_does_not_exist;
 mod max_fee_too_low;
+mod track_nonce;
 mod wrong_calldata;
 mod wrong_function_name;
 
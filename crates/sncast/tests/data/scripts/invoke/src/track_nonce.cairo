@@ -0,0 +1,30 @@
+use sncast_std::{invoke, InvokeResult};
+use starknet::{ContractAddress, Felt252TryIntoContractAddress};
+use traits::Into;
+
+fn main() {
+    let map_contract_address = 0x07537a17e169c96cf2b0392508b3a66cbc50c9a811a8a7896529004c5e93fdf6
+        .try_into()
+        .expect('Invalid contract address value');
+    let max_fee = 99999999999999999;
+
+    let first_result = invoke(
+        map_contract_address,
+        selector!("put"),
+        array![0x20, 0x1],
+        Option::Some(max_fee),
+        Option::None
+    )
+        .expect('first invoke failed');
+    let second_result = invoke(
+        map_contract_address,
+        selector!("put"),
+        array![0x20, 0x2],
+        Option::Some(max_fee),
+        Option::None
+    )
+        .expect('second invoke failed');
+
+    assert(second_result.nonce == first_result.nonce + 1, 'nonce was not incremented');
+    println!("nonces: {}, {}", first_result.nonce, second_result.nonce);
+}
@@ -0,0 +1,110 @@
+use crate::helpers::constants::{
+    DEVNET_OZ_CLASS_HASH_CAIRO_0, MAP_CONTRACT_ADDRESS_SEPOLIA, MAP_CONTRACT_CLASS_HASH_SEPOLIA,
+    URL,
+};
+use crate::helpers::runner::runner;
+use indoc::indoc;
+use shared::test_utils::output_assert::assert_stderr_contains;
+use std::fs;
+use tempfile::tempdir;
+
+#[tokio::test]
+async fn test_sierra_class_hash() {
+    let temp_dir = tempdir().expect("Unable to create a temporary directory");
+    let args = vec![
+        "--url",
+        URL,
+        "abi",
+        "--class-hash",
+        MAP_CONTRACT_CLASS_HASH_SEPOLIA,
+        "--output",
+        "abi.json",
+    ];
+
+    let snapbox = runner(&args).current_dir(temp_dir.path());
+    snapbox.assert().success().stdout_eq(indoc! {r"
+        command: abi
+        path: abi.json
+    "});
+
+    let abi = fs::read_to_string(temp_dir.path().join("abi.json")).unwrap();
+    let abi: serde_json::Value = serde_json::from_str(&abi).unwrap();
+    assert!(abi
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|entry| entry["type"] == "interface"));
+}
+
+#[tokio::test]
+async fn test_contract_address() {
+    let temp_dir = tempdir().expect("Unable to create a temporary directory");
+    let args = vec![
+        "--url",
+        URL,
+        "abi",
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--output",
+        "abi.json",
+    ];
+
+    let snapbox = runner(&args).current_dir(temp_dir.path());
+    snapbox.assert().success();
+
+    let abi = fs::read_to_string(temp_dir.path().join("abi.json")).unwrap();
+    assert!(serde_json::from_str::<serde_json::Value>(&abi)
+        .unwrap()
+        .is_array());
+}
+
+#[tokio::test]
+async fn test_legacy_class_hash() {
+    let temp_dir = tempdir().expect("Unable to create a temporary directory");
+    let args = vec![
+        "--url",
+        URL,
+        "abi",
+        "--class-hash",
+        DEVNET_OZ_CLASS_HASH_CAIRO_0,
+        "--output",
+        "abi.json",
+    ];
+
+    let snapbox = runner(&args).current_dir(temp_dir.path());
+    snapbox.assert().success();
+
+    let abi = fs::read_to_string(temp_dir.path().join("abi.json")).unwrap();
+    let abi: serde_json::Value = serde_json::from_str(&abi).unwrap();
+    assert!(abi
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|entry| entry["name"] == "__execute__"));
+}
+
+#[tokio::test]
+async fn test_unknown_class_hash() {
+    let temp_dir = tempdir().expect("Unable to create a temporary directory");
+    let args = vec![
+        "--url",
+        URL,
+        "abi",
+        "--class-hash",
+        "0x1",
+        "--output",
+        "abi.json",
+    ];
+
+    let snapbox = runner(&args).current_dir(temp_dir.path());
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: abi
+        error: Failed to get ABI: Provided class hash does not exist
+        "},
+    );
+    assert!(!temp_dir.path().join("abi.json").exists());
+}
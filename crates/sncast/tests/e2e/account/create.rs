@@ -50,7 +50,7 @@ pub async fn test_happy_case(account_type: &str) {
         add_profile: --add-profile flag was not set. No profile added to snfoundry.toml
         address: 0x[..]
         max_fee: [..]
-        message: Account successfully created. Prefund generated address with at least <max_fee> tokens. It is good to send more in the case of higher demand.
+        message: Account successfully created. Prefund generated address with at least <max_fee> WEI of ETH. It is good to send more in the case of higher demand.
         "},
     );
 
@@ -77,6 +77,126 @@ pub async fn test_happy_case(account_type: &str) {
     assert_matches(to_string_pretty(&expected).unwrap(), contents);
 }
 
+#[tokio::test]
+pub async fn test_happy_case_from_mnemonic_file() {
+    let temp_dir = tempdir().expect("Unable to create a temporary directory");
+    let accounts_file = "accounts.json";
+    let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    fs::write(
+        temp_dir.path().join("mnemonic.txt"),
+        format!("{mnemonic}\n"),
+    )
+    .unwrap();
+
+    for name in ["my_account", "my_account_again"] {
+        let args = vec![
+            "--url",
+            URL,
+            "--accounts-file",
+            accounts_file,
+            "account",
+            "create",
+            "--name",
+            name,
+            "--mnemonic-file",
+            "mnemonic.txt",
+            "--derivation-index",
+            "1",
+            "--skip-fee-estimate",
+        ];
+
+        runner(&args)
+            .current_dir(temp_dir.path())
+            .assert()
+            .success();
+    }
+
+    let contents = fs::read_to_string(temp_dir.path().join(accounts_file))
+        .expect("Unable to read created file");
+    assert!(!contents.contains("abandon"));
+
+    let accounts: serde_json::Value = serde_json::from_str(&contents).unwrap();
+    let account = &accounts["alpha-sepolia"]["my_account"];
+    assert_eq!(
+        account["private_key"],
+        "0x6d582b352685f7c37a2faa748536c741c3a8c660cb011bce57457a32cd04d1a"
+    );
+    assert_eq!(account["salt"], account["public_key"]);
+    assert_eq!(account, &accounts["alpha-sepolia"]["my_account_again"]);
+}
+
+#[tokio::test]
+pub async fn test_happy_case_skip_fee_estimate() {
+    let temp_dir = tempdir().expect("Unable to create a temporary directory");
+    let accounts_file = "accounts.json";
+
+    let args = vec![
+        "--url",
+        URL,
+        "--accounts-file",
+        accounts_file,
+        "account",
+        "create",
+        "--name",
+        "my_account",
+        "--skip-fee-estimate",
+    ];
+
+    let snapbox = runner(&args).current_dir(temp_dir.path());
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: account create
+        add_profile: --add-profile flag was not set. No profile added to snfoundry.toml
+        address: 0x[..]
+        message: Account successfully created. Deployment fee estimation was skipped, run `sncast account deploy --estimate-only` to check the minimum balance needed for deployment.
+        "},
+    );
+}
+
+#[tokio::test]
+pub async fn test_happy_case_json() {
+    let temp_dir = tempdir().expect("Unable to create a temporary directory");
+    let accounts_file = "accounts.json";
+
+    let args = vec![
+        "--url",
+        URL,
+        "--accounts-file",
+        accounts_file,
+        "--json",
+        "account",
+        "create",
+        "--name",
+        "my_account",
+        "--salt",
+        "0x1",
+    ];
+
+    let snapbox = runner(&args).current_dir(temp_dir.path());
+    let output = snapbox.assert().success();
+
+    let stdout: serde_json::Value = serde_json::from_slice(&output.get_output().stdout)
+        .expect("Output should be a single JSON object");
+    let contents = fs::read_to_string(temp_dir.path().join(accounts_file))
+        .expect("Unable to read created file");
+    let accounts: serde_json::Value = serde_json::from_str(&contents).unwrap();
+    let account = &accounts["alpha-sepolia"]["my_account"];
+
+    assert_eq!(
+        stdout,
+        json!({
+            "name": "my_account",
+            "address": account["address"],
+            "class_hash": account["class_hash"],
+            "salt": "0x1",
+            "deployed": false,
+        })
+    );
+}
+
 #[tokio::test]
 pub async fn test_invalid_class_hash() {
     let temp_dir = tempdir().expect("Unable to create a temporary directory");
@@ -545,6 +665,35 @@ pub async fn test_happy_case_keystore_hex_format() {
     assert!(contents.contains("\"legacy\": false"));
 }
 
+#[tokio::test]
+pub async fn test_dry_run() {
+    let temp_dir = tempdir().expect("Unable to create a temporary directory");
+    let accounts_file = "accounts.json";
+
+    let args = vec![
+        "--url",
+        URL,
+        "--accounts-file",
+        accounts_file,
+        "--dry-run",
+        "account",
+        "create",
+        "--name",
+        "my_account",
+    ];
+
+    let snapbox = runner(&args).current_dir(temp_dir.path());
+    let output = snapbox.assert().failure();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        Error: --dry-run can't be used with account create, it is supported only by commands sending transactions and account delete
+        "},
+    );
+    assert!(!temp_dir.path().join(accounts_file).exists());
+}
+
 fn get_formatted_account_type(account_type: &str) -> &str {
     match account_type {
         "oz" => "open_zeppelin",
@@ -4,6 +4,7 @@ use crate::helpers::runner::runner;
 use indoc::indoc;
 use shared::test_utils::output_assert::{assert_stderr_contains, AsOutput};
 use tempfile::{tempdir, TempDir};
+use test_case::test_case;
 use tokio::fs::File;
 use tokio::io::AsyncWriteExt;
 
@@ -169,6 +170,69 @@ pub async fn test_happy_case_with_yes_flag() {
     "});
 }
 
+#[test_case(&["--dry-run", "account", "delete"]; "before subcommand")]
+#[test_case(&["account", "delete", "--dry-run"]; "after subcommand")]
+#[tokio::test]
+pub async fn test_dry_run(command: &[&str]) {
+    let accounts_file_name = "temp_accounts.json";
+    let temp_dir = create_tempdir_with_accounts_file(accounts_file_name).await;
+    let accounts_file_path = temp_dir.path().join(accounts_file_name);
+    let contents_before = std::fs::read_to_string(&accounts_file_path).unwrap();
+
+    let mut args = vec!["--url", URL, "--accounts-file", &accounts_file_name];
+    args.extend(command);
+    args.extend(["--name", "user3", "--network", "custom-network"]);
+
+    let snapbox = runner(&args).current_dir(temp_dir.path());
+
+    snapbox.assert().success().stdout_matches(indoc! {r"
+        command: account delete
+        result: Account would be removed (dry run)
+    "});
+    assert_eq!(
+        std::fs::read_to_string(&accounts_file_path).unwrap(),
+        contents_before
+    );
+    assert!(!temp_dir.path().join("backups").exists());
+}
+
+#[tokio::test]
+pub async fn test_backup_created() {
+    let accounts_file_name = "temp_accounts.json";
+    let temp_dir = create_tempdir_with_accounts_file(accounts_file_name).await;
+    let contents_before =
+        std::fs::read_to_string(temp_dir.path().join(accounts_file_name)).unwrap();
+
+    let args = vec![
+        "--url",
+        URL,
+        "--accounts-file",
+        &accounts_file_name,
+        "account",
+        "delete",
+        "--name",
+        "user3",
+        "--network",
+        "custom-network",
+        "--yes",
+        "--backup-dir",
+        "my_backups",
+    ];
+
+    let snapbox = runner(&args).current_dir(temp_dir.path());
+    snapbox.assert().success();
+
+    let backups: Vec<_> = std::fs::read_dir(temp_dir.path().join("my_backups"))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    assert_eq!(backups.len(), 1);
+    assert_eq!(
+        std::fs::read_to_string(&backups[0]).unwrap(),
+        contents_before
+    );
+}
+
 #[must_use]
 async fn create_tempdir_with_accounts_file(file_name: &str) -> TempDir {
     let tempdir = tempdir().expect("Unable to create temporary directory");
@@ -49,9 +49,13 @@ pub async fn test_happy_case(class_hash: &str, account_type: &str) {
 
     assert!(matches!(receipt, DeployAccount(_)));
 
-    let stdout_str = bdg.as_stdout();
-    assert!(stdout_str.contains("account deploy"));
-    assert!(stdout_str.contains("transaction_hash"));
+    let output: Value = serde_json::from_slice(&bdg.get_output().stdout).unwrap();
+    assert_eq!(output["name"], "my_account");
+    assert_eq!(output["deployed"], true);
+    assert_eq!(output["transaction_hash"], format!("{hash:#x}"));
+    assert!(output["address"].is_string());
+    assert!(output["class_hash"].is_string());
+    assert!(output["salt"].is_string());
 
     let contents = fs::read_to_string(tempdir.path().join(accounts_file)).unwrap();
     let items: serde_json::Value =
@@ -87,10 +91,74 @@ pub async fn test_happy_case_add_profile() {
     assert!(matches!(receipt, DeployAccount(_)));
 
     let stdout_str = output.as_stdout();
-    assert!(stdout_str.contains("account deploy"));
+    assert!(stdout_str.contains("\"name\":\"my_account\""));
     assert!(stdout_str.contains("transaction_hash"));
 }
 
+#[tokio::test]
+pub async fn test_estimate_only() {
+    let tempdir = create_account(false, &OZ_CLASS_HASH.into_hex_string(), "oz").await;
+    let accounts_file = "accounts.json";
+
+    let args = vec![
+        "--url",
+        URL,
+        "--accounts-file",
+        accounts_file,
+        "account",
+        "deploy",
+        "--name",
+        "my_account",
+        "--estimate-only",
+    ];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let stdout_str = snapbox.assert().success().as_stdout().to_string();
+
+    assert!(stdout_str.contains("command: account deploy"));
+    assert!(stdout_str.contains("overall_fee: "));
+    assert!(!stdout_str.contains("transaction_hash"));
+
+    let contents = fs::read_to_string(tempdir.path().join(accounts_file)).unwrap();
+    let items: serde_json::Value =
+        serde_json::from_str(&contents).expect("Failed to parse accounts file at ");
+    assert_eq!(items["alpha-sepolia"]["my_account"]["deployed"], false);
+}
+
+#[tokio::test]
+pub async fn test_dry_run() {
+    let tempdir = create_account(false, &OZ_CLASS_HASH.into_hex_string(), "oz").await;
+    let accounts_file = "accounts.json";
+
+    let args = vec![
+        "--url",
+        URL,
+        "--accounts-file",
+        accounts_file,
+        "--dry-run",
+        "account",
+        "deploy",
+        "--name",
+        "my_account",
+        "--max-fee",
+        "10000000000000000",
+    ];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let stdout_str = snapbox.assert().success().as_stdout().to_string();
+
+    assert!(stdout_str.contains("command: account deploy"));
+    assert!(stdout_str.contains("type: DEPLOY_ACCOUNT"));
+    assert!(stdout_str.contains("max_fee: 0x2386f26fc10000"));
+    assert!(stdout_str.contains("nonce: 0x0"));
+    assert!(stdout_str.contains("signature: "));
+
+    let contents = fs::read_to_string(tempdir.path().join(accounts_file)).unwrap();
+    let items: serde_json::Value =
+        serde_json::from_str(&contents).expect("Failed to parse accounts file at ");
+    assert_eq!(items["alpha-sepolia"]["my_account"]["deployed"], false);
+}
+
 #[test_case("{\"alpha-sepolia\": {}}", "error: Account = my_account not found under network = alpha-sepolia" ; "when account name not present")]
 #[test_case("{\"alpha-sepolia\": {\"my_account\" : {}}}", "error: Failed to parse field `alpha-sepolia.my_account` in file 'accounts.json': missing field `private_key`[..]" ; "when private key not present")]
 fn test_account_deploy_error(accounts_content: &str, error: &str) {
@@ -118,6 +186,146 @@ fn test_account_deploy_error(accounts_content: &str, error: &str) {
     assert_stderr_contains(output, error);
 }
 
+#[tokio::test]
+pub async fn test_deploy_all_with_partial_failure() {
+    let tempdir = create_account(false, &OZ_CLASS_HASH.into_hex_string(), "oz").await;
+    let accounts_file = "accounts.json";
+
+    // Account without funds, whose deployment fails
+    runner(&[
+        "--url",
+        URL,
+        "--accounts-file",
+        accounts_file,
+        "account",
+        "create",
+        "--name",
+        "unfunded_account",
+    ])
+    .current_dir(tempdir.path())
+    .assert()
+    .success();
+
+    let args = vec![
+        "--url",
+        URL,
+        "--accounts-file",
+        accounts_file,
+        "--json",
+        "account",
+        "deploy",
+        "--all",
+    ];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let bdg = snapbox.assert().failure();
+
+    let output: Value = serde_json::from_slice(&bdg.get_output().stdout).unwrap();
+    assert_eq!(
+        output["deployed_accounts"],
+        serde_json::json!(["my_account"])
+    );
+    let hash = output["transaction_hashes"][0]
+        .as_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!(matches!(
+        get_transaction_receipt(hash).await,
+        DeployAccount(_)
+    ));
+    assert!(output["failed_accounts"][0]
+        .as_str()
+        .unwrap()
+        .starts_with("unfunded_account: "));
+    assert_stderr_contains(bdg, "Error: Failed to deploy 1 account(s)");
+
+    let contents = fs::read_to_string(tempdir.path().join(accounts_file)).unwrap();
+    let items: Value = serde_json::from_str(&contents).unwrap();
+    assert_eq!(items["alpha-sepolia"]["my_account"]["deployed"], true);
+    assert_eq!(
+        items["alpha-sepolia"]["unfunded_account"]["deployed"],
+        false
+    );
+}
+
+#[test]
+fn test_deploy_all_conflicts_with_name() {
+    let args = vec!["account", "deploy", "--all", "--name", "my_account"];
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().failure();
+
+    assert_stderr_contains(
+        output,
+        "error: the argument '--all' cannot be used with '--name <NAME>'",
+    );
+}
+
+#[tokio::test]
+pub async fn test_happy_case_constructor_calldata() {
+    let tempdir = create_account(false, &OZ_CLASS_HASH.into_hex_string(), "oz").await;
+    let accounts_file = "accounts.json";
+
+    let contents = fs::read_to_string(tempdir.path().join(accounts_file)).unwrap();
+    let items: Value = serde_json::from_str(&contents).unwrap();
+    let account = &items["alpha-sepolia"]["my_account"];
+    let public_key = account["public_key"].as_str().unwrap();
+
+    let args = vec![
+        "--url",
+        URL,
+        "--accounts-file",
+        accounts_file,
+        "--json",
+        "account",
+        "deploy",
+        "--name",
+        "my_account",
+        "--max-fee",
+        "99999999999999999",
+        "--constructor-calldata",
+        public_key,
+    ];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let bdg = snapbox.assert().success();
+
+    let hash = get_transaction_hash(&bdg.get_output().stdout);
+    let receipt = get_transaction_receipt(hash).await;
+    assert!(matches!(receipt, DeployAccount(_)));
+
+    let output: Value = serde_json::from_slice(&bdg.get_output().stdout).unwrap();
+    assert_eq!(output["address"], account["address"]);
+}
+
+#[test_case("oz", &OZ_CLASS_HASH.into_hex_string(), "error: Constructor of the account class = [..] expects 1 argument(s) (public key), got 2"; "oz_account")]
+#[test_case("braavos", &BRAAVOS_CLASS_HASH.into_hex_string(), "error: Constructor calldata can't be passed for Braavos accounts[..]"; "braavos_account")]
+#[tokio::test]
+pub async fn test_invalid_constructor_calldata(account_type: &str, class_hash: &str, error: &str) {
+    let tempdir = create_account(false, class_hash, account_type).await;
+
+    let args = vec![
+        "--url",
+        URL,
+        "--accounts-file",
+        "accounts.json",
+        "account",
+        "deploy",
+        "--name",
+        "my_account",
+        "--max-fee",
+        "99999999999999999",
+        "--constructor-calldata",
+        "0x1",
+        "0x2",
+    ];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+
+    assert_stderr_contains(snapbox.assert(), error);
+}
+
 #[tokio::test]
 async fn test_too_low_max_fee() {
     let tempdir = create_account(false, &OZ_CLASS_HASH.into_hex_string(), "oz").await;
@@ -2,3 +2,4 @@ mod add;
 mod create;
 mod delete;
 mod deploy;
+mod rename;
@@ -0,0 +1,212 @@
+use crate::helpers::constants::URL;
+use crate::helpers::runner::runner;
+use indoc::{formatdoc, indoc};
+use shared::test_utils::output_assert::assert_stderr_contains;
+use std::fs;
+use tempfile::{tempdir, TempDir};
+
+const ACCOUNTS_FILE_NAME: &str = "temp_accounts.json";
+
+#[tokio::test]
+pub async fn test_happy_case() {
+    let temp_dir = create_tempdir_with_accounts_file();
+
+    let args = vec![
+        "--url",
+        URL,
+        "--accounts-file",
+        ACCOUNTS_FILE_NAME,
+        "account",
+        "rename",
+        "--from",
+        "user3",
+        "--to",
+        "renamed_user",
+        "--network",
+        "alpha-sepolia",
+    ];
+
+    let snapbox = runner(&args).current_dir(temp_dir.path());
+
+    snapbox.assert().success().stdout_matches(indoc! {r"
+        command: account rename
+        result: Account successfully renamed
+        updated_profiles: [same_accounts_file]
+    "});
+
+    let contents = fs::read_to_string(temp_dir.path().join(ACCOUNTS_FILE_NAME)).unwrap();
+    let items: serde_json::Value = serde_json::from_str(&contents).unwrap();
+    assert!(items["alpha-sepolia"]["user3"].is_null());
+    assert_eq!(
+        items["alpha-sepolia"]["renamed_user"]["address"],
+        "0x7e00d496e324876bbc8531f2d9a82bf154d1a04a50218ee74cdd372f75a551a"
+    );
+    assert!(!items["custom-network"]["user3"].is_null());
+
+    let config = fs::read_to_string(temp_dir.path().join("snfoundry.toml")).unwrap();
+    let config: toml::Value = toml::from_str(&config).unwrap();
+    assert_eq!(
+        config["sncast"]["same_accounts_file"]["account"].as_str(),
+        Some("renamed_user")
+    );
+    assert_eq!(
+        config["sncast"]["other_accounts_file"]["account"].as_str(),
+        Some("user3")
+    );
+    assert_eq!(
+        config["sncast"]["default_accounts_file"]["account"].as_str(),
+        Some("user3")
+    );
+}
+
+#[tokio::test]
+pub async fn test_profiles_of_other_network_are_not_updated() {
+    let temp_dir = create_tempdir_with_accounts_file();
+
+    let args = vec![
+        "--url",
+        URL,
+        "--accounts-file",
+        ACCOUNTS_FILE_NAME,
+        "account",
+        "rename",
+        "--from",
+        "user3",
+        "--to",
+        "renamed_user",
+        "--network",
+        "custom-network",
+    ];
+
+    let snapbox = runner(&args).current_dir(temp_dir.path());
+
+    // Node of the profiles is in alpha-sepolia network
+    snapbox.assert().success().stdout_matches(indoc! {r"
+        command: account rename
+        result: Account successfully renamed
+    "});
+
+    let contents = fs::read_to_string(temp_dir.path().join(ACCOUNTS_FILE_NAME)).unwrap();
+    let items: serde_json::Value = serde_json::from_str(&contents).unwrap();
+    assert!(items["custom-network"]["user3"].is_null());
+    assert!(!items["custom-network"]["renamed_user"].is_null());
+
+    let config = fs::read_to_string(temp_dir.path().join("snfoundry.toml")).unwrap();
+    assert!(!config.contains("renamed_user"));
+}
+
+#[tokio::test]
+pub async fn test_account_does_not_exist() {
+    let temp_dir = create_tempdir_with_accounts_file();
+
+    let args = vec![
+        "--url",
+        URL,
+        "--accounts-file",
+        ACCOUNTS_FILE_NAME,
+        "account",
+        "rename",
+        "--from",
+        "user99",
+        "--to",
+        "renamed_user",
+        "--network",
+        "custom-network",
+    ];
+
+    let snapbox = runner(&args).current_dir(temp_dir.path());
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: account rename
+        error: Account with name user99 does not exist
+        "},
+    );
+}
+
+#[tokio::test]
+pub async fn test_target_name_already_exists() {
+    let temp_dir = create_tempdir_with_accounts_file();
+
+    let args = vec![
+        "--url",
+        URL,
+        "--accounts-file",
+        ACCOUNTS_FILE_NAME,
+        "account",
+        "rename",
+        "--from",
+        "user3",
+        "--to",
+        "user4",
+        "--network",
+        "custom-network",
+    ];
+
+    let snapbox = runner(&args).current_dir(temp_dir.path());
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: account rename
+        error: Account with name user4 already exists in network = custom-network
+        "},
+    );
+
+    let contents = fs::read_to_string(temp_dir.path().join(ACCOUNTS_FILE_NAME)).unwrap();
+    let items: serde_json::Value = serde_json::from_str(&contents).unwrap();
+    assert!(!items["custom-network"]["user3"].is_null());
+}
+
+fn create_tempdir_with_accounts_file() -> TempDir {
+    let temp_dir = tempdir().unwrap();
+
+    let config = formatdoc! {r#"
+        [sncast.same_accounts_file]
+        url = "{URL}"
+        accounts-file = "{ACCOUNTS_FILE_NAME}"
+        account = "user3"
+
+        [sncast.other_accounts_file]
+        url = "{URL}"
+        accounts-file = "../account-file"
+        account = "user3"
+
+        [sncast.default_accounts_file]
+        url = "{URL}"
+        account = "user3"
+    "#};
+    fs::write(temp_dir.path().join("snfoundry.toml"), config).unwrap();
+
+    let account = indoc! {r#"
+        {
+            "private_key": "0xe3e70682c2094cac629f6fbed82c07cd",
+            "public_key": "0x7e52885445756b313ea16849145363ccb73fb4ab0440dbac333cf9d13de82b9",
+            "address": "0x7e00d496e324876bbc8531f2d9a82bf154d1a04a50218ee74cdd372f75a551a"
+        }
+    "#};
+    let other_account = indoc! {r#"
+        {
+            "private_key": "0x73fbb3c1eff11167598455d0408f3932e42c678bd8f7fbc6028c716867cc01f",
+            "public_key": "0x43a74f86b7e204f1ba081636c9d4015e1f54f5bb03a4ae8741602a15ffbb182",
+            "address": "0x7ccdf182d27c7aaa2e733b94db4a3f7b28ff56336b34abf43c15e3a9edfbe91"
+        }
+    "#};
+    let json_data = formatdoc! {r#"
+        {{
+            "alpha-sepolia": {{
+                "user3": {account}
+            }},
+            "custom-network": {{
+                "user3": {account},
+                "user4": {other_account}
+            }}
+        }}
+    "#};
+    fs::write(temp_dir.path().join(ACCOUNTS_FILE_NAME), json_data).unwrap();
+
+    temp_dir
+}
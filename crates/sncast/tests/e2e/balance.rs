@@ -0,0 +1,96 @@
+use crate::helpers::constants::{DEVNET_PREDEPLOYED_ACCOUNT_ADDRESS, URL};
+use crate::helpers::fixtures::default_cli_args;
+use crate::helpers::runner::runner;
+use configuration::copy_config_to_tempdir;
+use indoc::indoc;
+use shared::test_utils::output_assert::{assert_stderr_contains, assert_stdout_contains};
+
+#[test]
+fn test_happy_case() {
+    let mut args = default_cli_args();
+    args.append(&mut vec!["--account", "user1", "balance"]);
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: balance
+        eth_balance: [..]
+        strk_balance: [..]
+    "},
+    );
+}
+
+#[test]
+fn test_single_token_json() {
+    let args = vec![
+        "--url",
+        URL,
+        "--json",
+        "balance",
+        "--address",
+        DEVNET_PREDEPLOYED_ACCOUNT_ADDRESS,
+        "--token",
+        "strk",
+    ];
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success().get_output().stdout.clone();
+
+    let output: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert!(output["strk_balance"].is_string());
+    assert!(output.get("eth_balance").is_none());
+}
+
+#[test]
+fn test_invalid_token_address() {
+    let args = vec![
+        "--url",
+        URL,
+        "balance",
+        "--address",
+        DEVNET_PREDEPLOYED_ACCOUNT_ADDRESS,
+        "--token",
+        "eth",
+        "--eth-address",
+        "0x1",
+    ];
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().failure();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: balance
+        error: [..]
+    "},
+    );
+}
+
+#[test]
+fn test_token_address_from_snfoundry_toml() {
+    let tempdir = copy_config_to_tempdir("tests/data/files/correct_snfoundry.toml", None).unwrap();
+    let args = vec![
+        "--profile",
+        "profile6",
+        "balance",
+        "--address",
+        DEVNET_PREDEPLOYED_ACCOUNT_ADDRESS,
+        "--token",
+        "eth",
+    ];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().failure();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: balance
+        error: [..]
+    "},
+    );
+}
@@ -56,6 +56,37 @@ async fn test_call_after_storage_changed() {
     "});
 }
 
+#[tokio::test]
+async fn test_felt_format_auto() {
+    invoke_contract(
+        "user14",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "put",
+        None,
+        &["0x66656c74", "0x68656c6c6f"],
+    )
+    .await;
+    let mut args = default_cli_args();
+    args.append(&mut vec![
+        "--felt-format",
+        "auto",
+        "call",
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "get",
+        "--calldata",
+        "0x66656c74",
+    ]);
+
+    let snapbox = runner(&args);
+
+    snapbox.assert().success().stdout_eq(indoc! {r"
+        command: call
+        response: ['hello']
+    "});
+}
+
 #[tokio::test]
 async fn test_contract_does_not_exist() {
     let mut args = default_cli_args();
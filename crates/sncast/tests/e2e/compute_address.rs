@@ -0,0 +1,138 @@
+use crate::helpers::runner::runner;
+use indoc::{formatdoc, indoc};
+use sncast::helpers::constants::UDC_ADDRESS;
+use starknet::core::types::FieldElement;
+use starknet::core::utils::{
+    get_contract_address, get_udc_deployed_address, UdcUniqueSettings, UdcUniqueness,
+};
+use test_case::test_case;
+
+// Accounts created with `account create`, from the accounts file used in tests
+#[test_case(
+    "oz",
+    "0x4d07e40e93398ed3c76981e72dd1fd22557a78ce36c0515f679e27f0bb5bc5f",
+    "0xe35eea9d1b0fa729",
+    "0x6b35e242f65996a44b6cf808b954785926eb89e8f4b6441b65780c4eacb9668",
+    "0x5c8fb90cc7249383cbc83c682565720a6d3df92840c02424a6c230e26464c4c";
+    "oz_account"
+)]
+#[test_case(
+    "argent",
+    "0x29927c8af6bccf3f6fda035981e765a7bdbf18a2dc0d630494f8758aa908e2b",
+    "0x1",
+    "0x6d3a3cb3dee41f7240e0d63e23e8828191090ec50e49b65ddb81bd78fa83979",
+    "0x243054cc47be5adab0c3463c6fdc3c4a4009e31ecfb0d629bda00a9fe7e6a5d";
+    "argent_account"
+)]
+#[test_case(
+    "braavos",
+    "0x816dd0297efc55dc1e7559020a3a825e81ef734b558f03c83325d4da7e6253",
+    "0x5bb823302091cf89",
+    "0x515f196ae6e5c062ed82d3182a6115faf9117450ac3f1b1daeb67bda0580c26",
+    "0x2bcefefb391c0e87170c6200e762f3e6aa8610a2e27fc51cc30d4a403b30549";
+    "braavos_account"
+)]
+fn test_account_address(
+    account_type: &str,
+    class_hash: &str,
+    salt: &str,
+    public_key: &str,
+    address: &str,
+) {
+    let args = vec![
+        "compute-address",
+        "--class-hash",
+        class_hash,
+        "--salt",
+        salt,
+        "--type",
+        account_type,
+        "--public-key",
+        public_key,
+    ];
+
+    runner(&args).assert().success().stdout_eq(formatdoc! {r"
+        command: compute-address
+        contract_address: {address}
+    "});
+}
+
+#[test]
+fn test_account_address_with_constructor_calldata() {
+    let args = vec![
+        "compute-address",
+        "--class-hash",
+        "0x123",
+        "--salt",
+        "0x5",
+        "--type",
+        "argent",
+        "--public-key",
+        "0x6",
+        "--constructor-calldata",
+        "0x6",
+        "0x7",
+    ];
+
+    let expected = get_contract_address(
+        FieldElement::from(5_u8),
+        FieldElement::from(0x123_u16),
+        &[FieldElement::from(6_u8), FieldElement::from(7_u8)],
+        FieldElement::ZERO,
+    );
+
+    runner(&args).assert().success().stdout_eq(formatdoc! {r"
+        command: compute-address
+        contract_address: {expected:#x}
+    "});
+}
+
+#[test]
+fn test_unique_contract_address() {
+    let args = vec![
+        "compute-address",
+        "--class-hash",
+        "0x123",
+        "--salt",
+        "0x5",
+        "--constructor-calldata",
+        "0x1",
+        "0x2",
+        "--unique",
+        "--deployer-address",
+        "0x456",
+    ];
+
+    let expected = get_udc_deployed_address(
+        FieldElement::from(5_u8),
+        FieldElement::from(0x123_u16),
+        &UdcUniqueness::Unique(UdcUniqueSettings {
+            deployer_address: FieldElement::from(0x456_u16),
+            udc_contract_address: UDC_ADDRESS,
+        }),
+        &[FieldElement::ONE, FieldElement::TWO],
+    );
+
+    runner(&args).assert().success().stdout_eq(formatdoc! {r"
+        command: compute-address
+        contract_address: {expected:#x}
+    "});
+}
+
+#[test]
+fn test_unique_without_deployer() {
+    let args = vec![
+        "compute-address",
+        "--class-hash",
+        "0x123",
+        "--salt",
+        "0x5",
+        "--unique",
+    ];
+
+    runner(&args).assert().failure().stderr_matches(indoc! {r"
+        error: the following required arguments were not provided:
+          --deployer-address <DEPLOYER_ADDRESS>
+        ...
+    "});
+}
@@ -6,9 +6,11 @@ use crate::helpers::fixtures::{
 use crate::helpers::runner::runner;
 use configuration::CONFIG_FILENAME;
 use indoc::indoc;
+use scarb_api::ScarbCommand;
 use shared::test_utils::output_assert::{assert_stderr_contains, assert_stdout_contains};
 use starknet::core::types::TransactionReceipt::Declare;
 use std::fs;
+use std::path::Path;
 use test_case::test_case;
 
 #[test_case("oz_cairo_0"; "cairo_0_account")]
@@ -326,6 +328,76 @@ fn test_scarb_no_casm_artifact() {
     );
 }
 
+#[tokio::test]
+async fn test_declare_all_skips_declared_contracts() {
+    let tempdir = copy_directory_to_tempdir(CONTRACTS_DIR.to_string() + "/map");
+    let lib_path = tempdir.path().join("src/lib.cairo");
+    let mut contract_code = fs::read_to_string(&lib_path).unwrap();
+    contract_code.push_str(indoc! {r"
+
+        #[starknet::contract]
+        mod Counter {
+            #[storage]
+            struct Storage {
+                count: felt252,
+            }
+
+            #[external(v0)]
+            fn increase_declare_all(ref self: ContractState) {
+                self.count.write(self.count.read() + 1);
+            }
+        }
+    "});
+    fs::write(&lib_path, contract_code).unwrap();
+    let accounts_json_path = get_accounts_path("tests/data/accounts/accounts.json");
+
+    let args = vec![
+        "--url",
+        URL,
+        "--accounts-file",
+        accounts_json_path.as_str(),
+        "--account",
+        "user2",
+        "--json",
+        "declare",
+        "--all",
+        "--max-fee",
+        "99999999999999999",
+    ];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().success().get_output().stdout.clone();
+
+    let output: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(output["contracts"], serde_json::json!(["Counter", "Map"]));
+    assert_eq!(output["class_hashes"].as_array().unwrap().len(), 2);
+    assert_eq!(
+        output["already_declared_contracts"],
+        serde_json::json!(["Map"])
+    );
+    assert_eq!(output["failed_contracts"], serde_json::json!([]));
+
+    let hash = output["transaction_hashes"][0]
+        .as_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!(matches!(get_transaction_receipt(hash).await, Declare(_)));
+}
+
+#[test]
+fn test_declare_all_conflicts_with_contract_name() {
+    let args = vec!["declare", "--all", "--contract-name", "Map"];
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().failure();
+
+    assert_stderr_contains(
+        output,
+        "error: the argument '--all' cannot be used with '--contract-name <CONTRACT>'",
+    );
+}
+
 #[tokio::test]
 async fn test_many_packages_default() {
     let tempdir = copy_directory_to_tempdir(CONTRACTS_DIR.to_string() + "/multiple_packages");
@@ -452,3 +524,94 @@ async fn test_no_scarb_profile() {
         transaction_hash: [..]
     "});
 }
+
+fn build_map_with_casm(contract_path: &Path) {
+    let manifest_path = contract_path.join("Scarb.toml");
+    let manifest = fs::read_to_string(&manifest_path).unwrap().replace(
+        "[[target.starknet-contract]]",
+        "[[target.starknet-contract]]\ncasm = true",
+    );
+    fs::write(&manifest_path, manifest).unwrap();
+
+    ScarbCommand::new_with_stdio()
+        .current_dir(contract_path)
+        .arg("build")
+        .run()
+        .expect("Failed to build contract");
+}
+
+#[tokio::test]
+async fn test_happy_case_from_files() {
+    let contract_path =
+        duplicate_contract_directory_with_salt(CONTRACTS_DIR.to_string() + "/map", "put", "files");
+    build_map_with_casm(contract_path.path());
+    let accounts_json_path = get_accounts_path("tests/data/accounts/accounts.json");
+    let args = vec![
+        "--url",
+        URL,
+        "--accounts-file",
+        accounts_json_path.as_str(),
+        "--account",
+        "user2",
+        "--json",
+        "declare",
+        "--sierra-file",
+        "target/dev/map_Map.contract_class.json",
+        "--casm-file",
+        "target/dev/map_Map.compiled_contract_class.json",
+        "--max-fee",
+        "99999999999999999",
+    ];
+
+    let snapbox = runner(&args).current_dir(contract_path.path());
+    let output = snapbox.assert().success().get_output().stdout.clone();
+
+    let hash = get_transaction_hash(&output);
+    let receipt = get_transaction_receipt(hash).await;
+
+    assert!(matches!(receipt, Declare(_)));
+}
+
+#[tokio::test]
+async fn test_casm_file_not_matching_sierra_file() {
+    let contract_path = duplicate_contract_directory_with_salt(
+        CONTRACTS_DIR.to_string() + "/map",
+        "put",
+        "mismatch",
+    );
+    build_map_with_casm(contract_path.path());
+
+    let casm_path = contract_path
+        .path()
+        .join("target/dev/map_Map.compiled_contract_class.json");
+    let mut casm: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&casm_path).unwrap()).unwrap();
+    casm["entry_points_by_type"]["EXTERNAL"] = serde_json::json!([]);
+    fs::write(&casm_path, casm.to_string()).unwrap();
+
+    let accounts_json_path = get_accounts_path("tests/data/accounts/accounts.json");
+    let args = vec![
+        "--url",
+        URL,
+        "--accounts-file",
+        accounts_json_path.as_str(),
+        "--account",
+        "user2",
+        "declare",
+        "--sierra-file",
+        "target/dev/map_Map.contract_class.json",
+        "--casm-file",
+        "target/dev/map_Map.compiled_contract_class.json",
+    ];
+
+    let snapbox = runner(&args).current_dir(contract_path.path());
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: declare
+        error: Casm file = target/dev/map_Map.compiled_contract_class.json was not compiled from sierra file = target/dev/map_Map.contract_class.json: Selectors of external entry points differ
+        "},
+    );
+}
@@ -0,0 +1,42 @@
+use crate::helpers::constants::URL;
+use crate::helpers::runner::runner;
+use indoc::indoc;
+use shared::test_utils::output_assert::assert_stderr_contains;
+
+#[tokio::test]
+async fn test_limit_with_pagination() {
+    let args = vec![
+        "--url",
+        URL,
+        "--json",
+        "get-events",
+        "--from-block",
+        "0",
+        "--to-block",
+        "latest",
+        "--chunk-size",
+        "1",
+        "--limit",
+        "2",
+    ];
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success().get_output().stdout.clone();
+
+    let output: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(output["command"], "get-events");
+    assert_eq!(output["events"].as_array().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_invalid_key() {
+    let args = vec!["--url", URL, "get-events", "--keys", "0x1,xyz"];
+    let snapbox = runner(&args);
+    let output = snapbox.assert().failure();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        Error: Failed to parse event key = xyz
+        "},
+    );
+}
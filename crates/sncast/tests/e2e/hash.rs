@@ -0,0 +1,79 @@
+use crate::helpers::runner::runner;
+use indoc::formatdoc;
+use starknet::core::crypto::compute_hash_on_elements;
+use starknet::core::types::FieldElement;
+use starknet::core::utils::get_storage_var_address;
+use starknet_crypto::poseidon_hash_many;
+
+fn felts(values: &[u8]) -> Vec<FieldElement> {
+    values
+        .iter()
+        .map(|value| FieldElement::from(*value))
+        .collect()
+}
+
+#[test]
+fn test_pedersen() {
+    let args = vec!["hash", "pedersen", "0x1", "0x2", "3"];
+
+    let expected = compute_hash_on_elements(&felts(&[1, 2, 3]));
+
+    runner(&args).assert().success().stdout_eq(formatdoc! {r"
+        command: hash pedersen
+        hash: {expected:#x}
+    "});
+}
+
+#[test]
+fn test_poseidon() {
+    let args = vec!["--int-format", "hash", "poseidon", "0x1", "0x2", "3"];
+
+    let expected = poseidon_hash_many(&felts(&[1, 2, 3]));
+
+    runner(&args).assert().success().stdout_eq(formatdoc! {r"
+        command: hash poseidon
+        hash: {expected:#}
+    "});
+}
+
+#[test]
+fn test_storage_address() {
+    let args = vec![
+        "--json",
+        "hash",
+        "storage-address",
+        "--name",
+        "balances",
+        "--keys",
+        "0x1",
+        "0x2",
+    ];
+
+    let expected = get_storage_var_address("balances", &felts(&[1, 2])).unwrap();
+
+    let output = runner(&args).assert().success();
+    let output: serde_json::Value = serde_json::from_slice(&output.get_output().stdout).unwrap();
+
+    assert_eq!(output["command"], "hash storage-address");
+    assert_eq!(output["storage_address"], format!("{expected:#x}"));
+}
+
+#[test]
+fn test_storage_address_non_ascii_name() {
+    let args = vec!["hash", "storage-address", "--name", "bałances"];
+
+    runner(&args)
+        .assert()
+        .success()
+        .stderr_matches(formatdoc! {r"
+        command: hash storage-address
+        error: Failed to compute address of storage variable = bałances: [..]
+    "});
+}
+
+#[test]
+fn test_pedersen_no_values() {
+    let args = vec!["hash", "pedersen"];
+
+    runner(&args).assert().failure();
+}
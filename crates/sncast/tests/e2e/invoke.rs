@@ -2,8 +2,10 @@ use crate::helpers::constants::{ACCOUNT, MAP_CONTRACT_ADDRESS_SEPOLIA};
 use crate::helpers::fixtures::{default_cli_args, get_transaction_hash, get_transaction_receipt};
 use crate::helpers::runner::runner;
 use indoc::indoc;
-use shared::test_utils::output_assert::assert_stderr_contains;
+use shared::test_utils::output_assert::{assert_stderr_contains, assert_stdout_contains};
 use starknet::core::types::TransactionReceipt::Invoke;
+use std::fs;
+use tempfile::tempdir;
 use test_case::test_case;
 
 #[test_case("oz_cairo_0"; "cairo_0_account")]
@@ -38,6 +40,37 @@ async fn test_happy_case(account: &str) {
     assert!(matches!(receipt, Invoke(_)));
 }
 
+#[tokio::test]
+async fn test_invalid_nonce() {
+    let mut args = default_cli_args();
+    args.append(&mut vec![
+        "--account",
+        ACCOUNT,
+        "invoke",
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "put",
+        "--calldata",
+        "0x1 0x2",
+        "--max-fee",
+        "99999999999999999",
+        "--nonce",
+        "12345",
+    ]);
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: invoke
+        error: Invalid transaction nonce = 0x3039, the node expects nonce = 0x[..]
+        "},
+    );
+}
+
 #[tokio::test]
 async fn test_contract_does_not_exist() {
     let mut args = default_cli_args();
@@ -115,6 +148,62 @@ fn test_wrong_calldata() {
     );
 }
 
+#[tokio::test]
+async fn test_happy_case_max_fee_percentage_of_balance() {
+    let mut args = default_cli_args();
+    args.append(&mut vec![
+        "--account",
+        "user16",
+        "--int-format",
+        "--json",
+        "invoke",
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "put",
+        "--calldata",
+        "0x1 0x2",
+        "--max-fee",
+        "50%",
+    ]);
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success().get_output().stdout.clone();
+
+    let hash = get_transaction_hash(&output);
+    let receipt = get_transaction_receipt(hash).await;
+
+    assert!(matches!(receipt, Invoke(_)));
+}
+
+#[test]
+fn test_invalid_max_fee_percentage() {
+    let mut args = default_cli_args();
+    args.append(&mut vec![
+        "--account",
+        ACCOUNT,
+        "invoke",
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "put",
+        "--calldata",
+        "0x1 0x2",
+        "--max-fee",
+        "101%",
+    ]);
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().failure();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        error: invalid value '101%' for '--max-fee <MAX_FEE>': Percentage of balance must be between 1% and 100%, got 101%
+        "},
+    );
+}
+
 #[test]
 fn test_too_low_max_fee() {
     let mut args = default_cli_args();
@@ -145,3 +234,246 @@ fn test_too_low_max_fee() {
         "},
     );
 }
+
+#[test]
+fn test_happy_case_with_wait_includes_receipt() {
+    let mut args = default_cli_args();
+    args.append(&mut vec![
+        "--account",
+        "user15",
+        "--wait",
+        "invoke",
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "put",
+        "--calldata",
+        "0x1 0x2",
+        "--max-fee",
+        "99999999999999999",
+    ]);
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: invoke
+        actual_fee: [..]
+        events: [[..]]
+        execution_resources: [[..]steps: [..]]
+        execution_status: Succeeded
+        fee_unit: WEI
+        finality_status: AcceptedOnL2
+        nonce: 0x[..]
+        transaction_hash: 0x[..]
+        "},
+    );
+}
+
+#[test]
+fn test_happy_case_with_wait_json_prints_single_document() {
+    let mut args = default_cli_args();
+    args.append(&mut vec![
+        "--account",
+        "user15",
+        "--json",
+        "--wait",
+        "invoke",
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "put",
+        "--calldata",
+        "0x1 0x2",
+        "--max-fee",
+        "99999999999999999",
+    ]);
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success().get_output().stdout.clone();
+
+    let documents: Vec<serde_json::Value> = std::str::from_utf8(&output)
+        .unwrap()
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect();
+    assert_eq!(documents.len(), 1);
+    assert_eq!(documents[0]["command"], "invoke");
+    assert!(documents[0]["transaction_hash"].is_string());
+    assert!(documents[0]["actual_fee"].is_string());
+}
+
+#[test]
+fn test_simulate() {
+    let mut args = default_cli_args();
+    args.append(&mut vec![
+        "--account",
+        "user3",
+        "invoke",
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "put",
+        "--calldata",
+        "0x1 0x2",
+        "--simulate",
+    ]);
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: invoke
+        call_trace: [[..]]
+        gas_consumed: [..]
+        gas_price: [..]
+        overall_fee: [..]
+        "},
+    );
+}
+
+fn export_invoke(account: &str, path: &str, nonce: Option<&str>) {
+    let mut args = default_cli_args();
+    args.append(&mut vec![
+        "--account",
+        account,
+        "invoke",
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "put",
+        "--calldata",
+        "0x1 0x2",
+        "--max-fee",
+        "99999999999999999",
+        "--export",
+        path,
+    ]);
+    if let Some(nonce) = nonce {
+        args.append(&mut vec!["--nonce", nonce]);
+    }
+
+    let snapbox = runner(&args);
+    snapbox.assert().success().stdout_eq(indoc! {r"
+        command: invoke
+        nonce: 0x[..]
+        path: [..]invoke.json
+        transaction_hash: 0x[..]
+    "});
+}
+
+#[test]
+fn test_dry_run() {
+    let mut args = default_cli_args();
+    args.append(&mut vec![
+        "--account",
+        ACCOUNT,
+        "--dry-run",
+        "invoke",
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "put",
+        "--calldata",
+        "0x1 0x2",
+        "--max-fee",
+        "99999999999999999",
+        "--nonce",
+        "12345",
+    ]);
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: invoke
+        calldata: [..]
+        max_fee: 0x16345785d89ffff
+        nonce: 0x3039
+        sender_address: 0x[..]
+        signature: [..]
+        transaction_hash: 0x[..]
+        type: INVOKE
+        version: 0x1
+        "},
+    );
+}
+
+#[tokio::test]
+async fn test_export_and_send_from_file() {
+    let temp_dir = tempdir().expect("Unable to create a temporary directory");
+    let path = temp_dir.path().join("invoke.json");
+    let path = path.to_str().unwrap();
+    export_invoke("user12", path, None);
+
+    let transaction: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+    assert_eq!(transaction["type"], "INVOKE");
+    assert!(!transaction["signature"].as_array().unwrap().is_empty());
+
+    let mut args = default_cli_args();
+    args.append(&mut vec!["--json", "invoke", "--from-file", path]);
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success().get_output().stdout.clone();
+
+    let hash = get_transaction_hash(&output);
+    let receipt = get_transaction_receipt(hash).await;
+
+    assert!(matches!(receipt, Invoke(_)));
+}
+
+#[test]
+fn test_from_file_invalid_nonce() {
+    let temp_dir = tempdir().expect("Unable to create a temporary directory");
+    let path = temp_dir.path().join("invoke.json");
+    let path = path.to_str().unwrap();
+    export_invoke("user12", path, Some("12345"));
+
+    let mut args = default_cli_args();
+    args.append(&mut vec!["invoke", "--from-file", path]);
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: invoke
+        error: Invalid transaction nonce = 0x3039, the node expects nonce = 0x[..]
+        "},
+    );
+}
+
+#[test]
+fn test_from_file_invalid_signature() {
+    let temp_dir = tempdir().expect("Unable to create a temporary directory");
+    let path = temp_dir.path().join("invoke.json");
+    let path = path.to_str().unwrap();
+    export_invoke("user12", path, None);
+
+    let mut transaction: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+    transaction["signature"][0] = "0x1".into();
+    fs::write(path, transaction.to_string()).unwrap();
+
+    let mut args = default_cli_args();
+    args.append(&mut vec!["invoke", "--from-file", path]);
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: invoke
+        error: Invalid signature of transaction = 0x[..], it was not signed by account = 0x[..] for chain = 0x[..]
+        "},
+    );
+}
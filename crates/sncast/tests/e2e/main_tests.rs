@@ -6,8 +6,8 @@ use crate::helpers::fixtures::{
 };
 use crate::helpers::runner::runner;
 use configuration::copy_config_to_tempdir;
-use indoc::indoc;
-use shared::test_utils::output_assert::assert_stderr_contains;
+use indoc::{formatdoc, indoc};
+use shared::test_utils::output_assert::{assert_stderr_contains, assert_stdout_contains};
 use sncast::helpers::constants::KEYSTORE_PASSWORD_ENV_VAR;
 use std::env;
 
@@ -190,6 +190,47 @@ async fn test_missing_url() {
     );
 }
 
+#[tokio::test]
+async fn test_network_mismatch() {
+    let args = vec![
+        "--url",
+        URL,
+        "--network",
+        "mainnet",
+        "call",
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "get",
+        "--calldata",
+        "0x0",
+    ];
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().failure();
+
+    assert_stderr_contains(
+        output,
+        "Error: RPC node is connected to network = alpha-sepolia, but --network mainnet was passed",
+    );
+}
+
+#[tokio::test]
+async fn test_unknown_network() {
+    let args = vec!["--network", "goerli", "show-config"];
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().failure();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        error: invalid value 'goerli' for '--network <NETWORK>'
+          [possible values: mainnet, sepolia]
+        "},
+    );
+}
+
 #[tokio::test]
 async fn test_inexistent_keystore() {
     let args = vec![
@@ -302,3 +343,57 @@ async fn test_keystore_declare() {
 
     assert!(snapbox.assert().success().get_output().stderr.is_empty());
 }
+
+#[tokio::test]
+async fn test_multiple_accounts_files() {
+    let faulty_accounts_path = get_accounts_path("tests/data/accounts/faulty_accounts.json");
+    let args = vec![
+        "--accounts-file",
+        faulty_accounts_path.as_str(),
+        "--accounts-file",
+        ACCOUNT_FILE_PATH,
+        "--account",
+        ACCOUNT,
+        "--url",
+        URL,
+        "balance",
+    ];
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: balance
+        eth_balance: [..]
+        strk_balance: [..]
+        "},
+    );
+}
+
+#[tokio::test]
+async fn test_account_in_multiple_accounts_files() {
+    let accounts_path = get_accounts_path(ACCOUNT_FILE_PATH);
+    let args = vec![
+        "--accounts-file",
+        ACCOUNT_FILE_PATH,
+        "--accounts-file",
+        accounts_path.as_str(),
+        "--account",
+        ACCOUNT,
+        "--url",
+        URL,
+        "balance",
+    ];
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().failure();
+
+    assert_stderr_contains(
+        output,
+        formatdoc! {r"
+        Error: Account = {ACCOUNT} is defined under network = alpha-sepolia in multiple accounts files = {ACCOUNT_FILE_PATH}, {accounts_path}
+        "},
+    );
+}
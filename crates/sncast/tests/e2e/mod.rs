@@ -1,7 +1,12 @@
+mod abi;
 mod account;
+mod balance;
 mod call;
+mod compute_address;
 mod declare;
 mod deploy;
+mod get_events;
+mod hash;
 mod invoke;
 mod main_tests;
 mod multicall;
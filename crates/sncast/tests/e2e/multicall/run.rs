@@ -34,6 +34,7 @@ async fn test_happy_case(account: &str) {
 
     output.stdout_matches(indoc! {r"
         command: multicall run
+        nonce: 0x[..]
         transaction_hash: 0x[..]
     "});
 }
@@ -62,6 +63,7 @@ async fn test_calldata_ids() {
 
     output.stdout_matches(indoc! {r"
         command: multicall run
+        nonce: 0x[..]
         transaction_hash: 0x[..]
     "});
 }
@@ -243,8 +243,8 @@ async fn test_run_script_display_debug_traits() {
         deploy_result: contract_address: [..], transaction_hash: [..]
         debug deploy_result: DeployResult { contract_address: [..], transaction_hash: [..] }
         Transaction hash = 0x[..]
-        invoke_result: [..]
-        debug invoke_result: InvokeResult { transaction_hash: [..] }
+        invoke_result: transaction_hash: [..], nonce: [..]
+        debug invoke_result: InvokeResult { transaction_hash: [..], nonce: [..] }
         call_result: [2]
         debug call_result: CallResult { data: [2] }
         command: script run
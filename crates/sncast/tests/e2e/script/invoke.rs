@@ -139,3 +139,37 @@ fn test_wrong_calldata() {
         "#},
     );
 }
+
+#[tokio::test]
+async fn test_track_nonce() {
+    let script_dir =
+        copy_script_directory_to_tempdir(SCRIPTS_DIR.to_owned() + "/invoke", Vec::<String>::new());
+    let accounts_json_path = get_accounts_path(ACCOUNT_FILE_PATH);
+
+    let script_name = "track_nonce";
+    let args = vec![
+        "--accounts-file",
+        accounts_json_path.as_str(),
+        "--account",
+        "user13",
+        "--url",
+        URL,
+        "script",
+        "run",
+        &script_name,
+        "--track-nonce",
+        "--no-state-file",
+    ];
+
+    let snapbox = runner(&args).current_dir(script_dir.path());
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        nonces: [..], [..]
+        command: script run
+        status: success
+        "},
+    );
+}
@@ -1,6 +1,7 @@
 use crate::helpers::runner::runner;
 use configuration::copy_config_to_tempdir;
 use indoc::indoc;
+use shared::test_utils::output_assert::assert_stderr_contains;
 
 #[tokio::test]
 async fn test_show_config_from_snfoundry_toml() {
@@ -105,3 +106,22 @@ async fn test_show_config_when_keystore() {
         wait_timeout: 300
     "});
 }
+
+#[tokio::test]
+async fn test_show_config_verbose() {
+    let tempdir = copy_config_to_tempdir("tests/data/files/correct_snfoundry.toml", None).unwrap();
+    let args = vec!["--verbose", "show-config"];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        Looking for snfoundry.toml in [..] and its parents
+        Using config file = [..]snfoundry.toml
+        Using profile = default
+        Values passed as flags override values from the profile, which override default values
+        "},
+    );
+}
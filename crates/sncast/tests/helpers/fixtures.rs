@@ -179,7 +179,7 @@ pub async fn invoke_contract(
     let provider = get_provider(URL).expect("Could not get the provider");
     let account = get_account(
         account,
-        &Utf8PathBuf::from(ACCOUNT_FILE_PATH),
+        &[Utf8PathBuf::from(ACCOUNT_FILE_PATH)],
         &provider,
         None,
     )
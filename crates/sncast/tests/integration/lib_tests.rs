@@ -5,7 +5,13 @@ use crate::helpers::fixtures::create_test_provider;
 
 use camino::Utf8PathBuf;
 use shared::rpc::{get_rpc_version, is_expected_version};
-use sncast::{check_if_legacy_contract, get_account, get_provider};
+use sncast::helpers::configuration::CastConfig;
+use sncast::{
+    build_provider, check_if_legacy_contract, get_account, get_provider, max_fee_from_estimate,
+};
+use starknet::core::types::FieldElement;
+use starknet::providers::Provider;
+use std::collections::BTreeMap;
 use std::fs;
 use url::ParseError;
 
@@ -31,12 +37,40 @@ async fn test_get_provider_empty_url() {
         .contains("RPC url not passed nor found in snfoundry.toml"));
 }
 
+#[tokio::test]
+async fn test_build_provider_with_timeouts_and_headers() {
+    let config = CastConfig {
+        url: URL.to_string(),
+        connect_timeout: Some(5),
+        request_timeout: Some(30),
+        rpc_headers: BTreeMap::from([("x-api-key".to_string(), "key".to_string())]),
+        ..Default::default()
+    };
+
+    let provider = build_provider(&config).unwrap();
+
+    assert!(provider.chain_id().await.is_ok());
+}
+
+#[tokio::test]
+async fn test_build_provider_invalid_rpc_header() {
+    let config = CastConfig {
+        url: URL.to_string(),
+        rpc_headers: BTreeMap::from([("invalid header".to_string(), "key".to_string())]),
+        ..Default::default()
+    };
+
+    let err = build_provider(&config).unwrap_err();
+
+    assert_eq!(err.to_string(), "Invalid RPC header name = invalid header");
+}
+
 #[tokio::test]
 async fn test_get_account() {
     let provider = create_test_provider();
     let account = get_account(
         "user1",
-        &Utf8PathBuf::from("tests/data/accounts/accounts.json"),
+        &[Utf8PathBuf::from("tests/data/accounts/accounts.json")],
         &provider,
         None,
     )
@@ -55,7 +89,9 @@ async fn test_get_account_no_file() {
     let provider = create_test_provider();
     let account = get_account(
         "user1",
-        &Utf8PathBuf::from("tests/data/accounts/nonexistentfile.json"),
+        &[Utf8PathBuf::from(
+            "tests/data/accounts/nonexistentfile.json",
+        )],
         &provider,
         None,
     )
@@ -71,7 +107,7 @@ async fn test_get_account_invalid_file() {
     let provider = create_test_provider();
     let account = get_account(
         "user1",
-        &Utf8PathBuf::from("tests/data/accounts/invalid_format.json"),
+        &[Utf8PathBuf::from("tests/data/accounts/invalid_format.json")],
         &provider,
         None,
     )
@@ -88,7 +124,7 @@ async fn test_get_account_no_account() {
     let provider = create_test_provider();
     let account = get_account(
         "",
-        &Utf8PathBuf::from("tests/data/accounts/accounts.json"),
+        &[Utf8PathBuf::from("tests/data/accounts/accounts.json")],
         &provider,
         None,
     )
@@ -104,7 +140,7 @@ async fn test_get_account_no_user_for_network() {
     let provider = create_test_provider();
     let account = get_account(
         "user100",
-        &Utf8PathBuf::from("tests/data/accounts/accounts.json"),
+        &[Utf8PathBuf::from("tests/data/accounts/accounts.json")],
         &provider,
         None,
     )
@@ -120,7 +156,9 @@ async fn test_get_account_failed_to_convert_field_elements() {
     let provider = create_test_provider();
     let account1 = get_account(
         "with_invalid_private_key",
-        &Utf8PathBuf::from("tests/data/accounts/faulty_accounts_invalid_felt.json"),
+        &[Utf8PathBuf::from(
+            "tests/data/accounts/faulty_accounts_invalid_felt.json",
+        )],
         &provider,
         None,
     )
@@ -163,3 +201,15 @@ async fn test_check_if_legacy_contract_by_address() {
         .unwrap();
     assert!(!is_legacy);
 }
+
+#[test]
+fn test_max_fee_from_estimate() {
+    assert_eq!(
+        max_fee_from_estimate(FieldElement::from(1000_u32)),
+        FieldElement::from(1100_u32)
+    );
+    assert_eq!(
+        max_fee_from_estimate(FieldElement::from(15_u32)),
+        FieldElement::from(16_u32)
+    );
+}
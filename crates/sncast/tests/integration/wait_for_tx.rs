@@ -34,7 +34,7 @@ async fn test_rejected_transaction() {
     let provider = create_test_provider();
     let account = get_account(
         ACCOUNT,
-        &Utf8PathBuf::from(ACCOUNT_FILE_PATH),
+        &[Utf8PathBuf::from(ACCOUNT_FILE_PATH)],
         &provider,
         None,
     )
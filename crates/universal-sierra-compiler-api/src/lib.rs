@@ -1,4 +1,4 @@
-use anyhow::{Context, Result};
+use anyhow::{ensure, Context, Result};
 use cairo_lang_casm::hints::Hint;
 use cairo_lang_sierra::program::Program;
 use num_bigint::BigInt;
@@ -48,6 +48,53 @@ pub fn compile_sierra_to_casm(sierra_program: &Program) -> Result<AssembledProgr
     Ok(assembled_with_info)
 }
 
+/// Compiles the program like [`compile_sierra_to_casm`], but instead of printing to standard error,
+/// returns the non-empty lines which `universal-sierra-compiler` printed there as warnings
+pub fn compile_sierra_to_casm_with_warnings(
+    sierra_program: &Program,
+) -> Result<(AssembledProgramWithDebugInfo, Vec<String>)> {
+    let mut temp_sierra_file = Builder::new().tempfile()?;
+    temp_sierra_file.write_all(&serde_json::to_vec(sierra_program)?)?;
+
+    let usc_output = UniversalSierraCompilerCommand::new()
+        .args(vec![
+            &("compile-".to_string() + &SierraType::Raw.to_string()),
+            "--sierra-path",
+            temp_sierra_file.path().to_str().unwrap(),
+        ])
+        .command()
+        .output()
+        .context("Failed to run universal-sierra-compiler")?;
+
+    let stderr = from_utf8(&usc_output.stderr)?;
+    ensure!(
+        usc_output.status.success(),
+        "Error while compiling Sierra. \
+        Make sure you have the latest universal-sierra-compiler binary installed. \
+        Contact us if it doesn't help\n{stderr}"
+    );
+
+    let warnings = stderr
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(ToString::to_string)
+        .collect();
+    let assembled_with_info = serde_json::from_slice(&usc_output.stdout)?;
+
+    Ok((assembled_with_info, warnings))
+}
+
+/// Version of the `universal-sierra-compiler` binary, as printed by `--version`
+pub fn compiler_version() -> Result<String> {
+    let output = UniversalSierraCompilerCommand::new()
+        .arg("--version")
+        .command()
+        .output_checked()
+        .context("Failed to read the version of universal-sierra-compiler")?;
+
+    Ok(from_utf8(&output.stdout)?.trim().to_string())
+}
+
 pub fn compile_sierra(
     sierra_contract_class: &Value,
     current_dir: Option<&Path>,
@@ -234,11 +234,12 @@ pub fn deploy(
 #[derive(Drop, Clone, Debug, Serde)]
 pub struct InvokeResult {
     pub transaction_hash: felt252,
+    pub nonce: felt252,
 }
 
 impl DisplayInvokeResult of Display<InvokeResult> {
     fn fmt(self: @InvokeResult, ref f: Formatter) -> Result<(), Error> {
-        write!(f, "{}", *self.transaction_hash)
+        write!(f, "transaction_hash: {}, nonce: {}", *self.transaction_hash, *self.nonce)
     }
 }
 
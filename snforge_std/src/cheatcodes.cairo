@@ -30,6 +30,17 @@ fn test_address() -> ContractAddress {
     contract_address_const::<469394814521890341860918960550914>()
 }
 
+/// Rejects the current run of a fuzz test if `condition` is false.
+/// A rejected run does not count toward fuzzer runs and it is repeated with new arguments.
+/// The test fails if too many consecutive runs are rejected.
+/// Outside fuzz tests, a rejection fails the test.
+/// - `condition` - precondition the arguments of the test must meet
+fn assume(condition: bool) {
+    if !condition {
+        panic(array!['snforge assume rejected']);
+    }
+}
+
 /// Mocks contract call to a `function_selector` of a contract at the given address, for `n_times`
 /// first calls that are made to the contract.
 /// A call to function `function_selector` will return data provided in `ret_data` argument.
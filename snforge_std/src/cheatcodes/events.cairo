@@ -1,4 +1,5 @@
 use core::array::ArrayTrait;
+use core::fmt::{Debug, Formatter};
 use core::option::OptionTrait;
 use starknet::testing::cheatcode;
 use starknet::ContractAddress;
@@ -87,8 +88,13 @@ impl EventSpyAssertionsTraitImpl<
             let emitted = is_emitted(@received_events, from, event);
 
             if !emitted {
+                let description = describe_expected_and_emitted(@received_events, from, event);
                 let from: felt252 = (*from).into();
-                panic!("Event with matching data and keys was not emitted from {}", from);
+                panic!(
+                    "Event with matching data and keys was not emitted from {}\n{}",
+                    from,
+                    description
+                );
             }
 
             i += 1;
@@ -137,6 +143,42 @@ fn is_emitted<T, impl TEvent: starknet::Event<T>, impl TDrop: Drop<T>>(
     return is_emitted;
 }
 
+/// Lists keys and data of the expected event and of the events emitted by the contract it was expected from
+fn describe_expected_and_emitted<T, impl TEvent: starknet::Event<T>, impl TDrop: Drop<T>>(
+    self: @Events, expected_emitted_by: @ContractAddress, expected_event: @T
+) -> ByteArray {
+    let mut expected_keys = array![];
+    let mut expected_data = array![];
+    expected_event.append_keys_and_data(ref expected_keys, ref expected_data);
+
+    let mut f: Formatter = Default::default();
+    write!(f, "Expected event:\n    ").unwrap();
+    write_event(ref f, @expected_keys, @expected_data);
+    write!(f, "\nEvents emitted from the contract:").unwrap();
+
+    let emitted = self.emitted_by(*expected_emitted_by);
+    if emitted.events.len() == 0 {
+        write!(f, " none").unwrap();
+    }
+
+    let mut i = 0;
+    while i < emitted.events.len() {
+        let (_, event) = emitted.events.at(i);
+        write!(f, "\n    ").unwrap();
+        write_event(ref f, event.keys, event.data);
+
+        i += 1;
+    };
+    f.buffer
+}
+
+fn write_event(ref f: Formatter, keys: @Array<felt252>, data: @Array<felt252>) {
+    write!(f, "keys: ").unwrap();
+    Debug::fmt(keys, ref f).unwrap();
+    write!(f, ", data: ").unwrap();
+    Debug::fmt(data, ref f).unwrap();
+}
+
 impl EventTraitImpl of starknet::Event<Event> {
     fn append_keys_and_data(self: @Event, ref keys: Array<felt252>, ref data: Array<felt252>) {
         keys.append_span(self.keys.span());
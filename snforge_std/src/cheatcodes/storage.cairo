@@ -61,3 +61,14 @@ fn map_entry_address(map_selector: felt252, keys: Span<felt252>) -> felt252 {
     keys.serialize(ref inputs);
     *handle_cheatcode(cheatcode::<'map_entry_address'>(inputs.span())).at(0)
 }
+
+/// Registers storage slots of `target` contract, whose values at the end of the test are compared
+/// with the snapshot saved in the `snapshots` directory of the package.
+/// The snapshot is created on the first run, use `--update-snapshots` flag to update it.
+/// - `target` - address of the contract, which storage is snapshotted
+/// - `storage_addresses` - addresses of the snapshotted felts in the contract's storage
+fn snapshot_storage(target: ContractAddress, storage_addresses: Span<felt252>) {
+    let mut inputs = array![target.into()];
+    storage_addresses.serialize(ref inputs);
+    handle_cheatcode(cheatcode::<'snapshot_storage'>(inputs.span()));
+}
@@ -21,11 +21,13 @@ use cheatcodes::events::spy_events;
 use cheatcodes::storage::store;
 use cheatcodes::storage::load;
 use cheatcodes::storage::map_entry_address;
+use cheatcodes::storage::snapshot_storage;
 
 use cheatcodes::CheatSpan;
 use cheatcodes::ReplaceBytecodeError;
 use cheatcodes::test_address;
 use cheatcodes::test_selector;
+use cheatcodes::assume;
 use cheatcodes::mock_call;
 use cheatcodes::start_mock_call;
 use cheatcodes::stop_mock_call;